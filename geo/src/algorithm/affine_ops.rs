@@ -0,0 +1,348 @@
+use std::fmt;
+
+use crate::algorithm::map_coords::{MapCoords, MapCoordsInplace};
+use crate::{CoordFloat, CoordNum, Coordinate};
+
+/// Apply an [`AffineTransform`] to a geometry: a composition of
+/// rotations, scalings, skews and translations, applied to all
+/// its coordinates in a single pass.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::affine_ops::{AffineOps, AffineTransform};
+/// use geo::{line_string, Coordinate};
+///
+/// let ls = line_string![(x: 0., y: 0.), (x: 1., y: 1.)];
+///
+/// // double the size of `ls`, then move it to the right
+/// let transform = AffineTransform::scale(2., 2., Coordinate { x: 0., y: 0. })
+///     .translated(10., 0.);
+///
+/// assert_eq!(
+///     ls.affine_transform(&transform),
+///     line_string![(x: 10., y: 0.), (x: 12., y: 2.)]
+/// );
+/// ```
+pub trait AffineOps<T: CoordNum> {
+    /// Apply `transform`, returning a new geometry.
+    fn affine_transform(&self, transform: &AffineTransform<T>) -> Self;
+
+    /// Apply `transform`, modifying the geometry in place.
+    fn affine_transform_mut(&mut self, transform: &AffineTransform<T>);
+}
+
+impl<T, G> AffineOps<T> for G
+where
+    T: CoordNum,
+    G: MapCoords<T, T, Output = G> + MapCoordsInplace<T>,
+{
+    fn affine_transform(&self, transform: &AffineTransform<T>) -> Self {
+        self.map_coords(|&(x, y)| transform.apply_xy(x, y))
+    }
+
+    fn affine_transform_mut(&mut self, transform: &AffineTransform<T>) {
+        self.map_coords_inplace(|&(x, y)| transform.apply_xy(x, y))
+    }
+}
+
+/// A 2D affine transform, stored as the first two rows of its
+/// 3×3 matrix:
+///
+/// ```text
+/// | a b xoff |
+/// | d e yoff |
+/// | 0 0 1    |
+/// ```
+///
+/// Transforms are built with the [`rotate`](Self::rotate),
+/// [`scale`](Self::scale), [`skew`](Self::skew) and
+/// [`translate`](Self::translate) constructors, and chained
+/// with the builder methods of the same names in the past tense,
+/// which apply the new transform after the existing one.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::affine_ops::AffineTransform;
+/// use geo::Coordinate;
+///
+/// let origin = Coordinate { x: 1., y: 1. };
+/// let transform = AffineTransform::translate(2., 0.).scaled(3., 3., origin);
+///
+/// let coord = transform.apply(Coordinate { x: 0., y: 1. });
+/// assert_eq!(coord, Coordinate { x: 4., y: 1. });
+///
+/// let inverse = transform.inverse().unwrap();
+/// assert_eq!(inverse.apply(coord), Coordinate { x: 0., y: 1. });
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct AffineTransform<T: CoordNum = f64>([[T; 3]; 2]);
+
+impl<T: CoordNum> AffineTransform<T> {
+    /// Create a transform from the coefficients of its matrix.
+    pub fn new(a: T, b: T, xoff: T, d: T, e: T, yoff: T) -> Self {
+        Self([[a, b, xoff], [d, e, yoff]])
+    }
+
+    /// The transform leaving every coordinate unchanged.
+    pub fn identity() -> Self {
+        Self::new(
+            T::one(),
+            T::zero(),
+            T::zero(),
+            T::zero(),
+            T::one(),
+            T::zero(),
+        )
+    }
+
+    /// Whether the transform leaves every coordinate unchanged.
+    pub fn is_identity(&self) -> bool {
+        *self == Self::identity()
+    }
+
+    /// A translation by `xoff` and `yoff`.
+    pub fn translate(xoff: T, yoff: T) -> Self {
+        Self::new(T::one(), T::zero(), xoff, T::zero(), T::one(), yoff)
+    }
+
+    /// A scaling by `xfact` and `yfact` around `origin`.
+    pub fn scale(xfact: T, yfact: T, origin: impl Into<Coordinate<T>>) -> Self {
+        let Coordinate { x: x0, y: y0 } = origin.into();
+        Self::new(
+            xfact,
+            T::zero(),
+            x0 - x0 * xfact,
+            T::zero(),
+            yfact,
+            y0 - y0 * yfact,
+        )
+    }
+
+    /// Compose two transforms: the result applies `self`, then
+    /// `other`.
+    #[must_use]
+    pub fn compose(&self, other: &Self) -> Self {
+        let [[a, b, c], [d, e, f]] = other.0;
+        let [[g, h, i], [j, k, l]] = self.0;
+        Self::new(
+            a * g + b * j,
+            a * h + b * k,
+            a * i + b * l + c,
+            d * g + e * j,
+            d * h + e * k,
+            d * i + e * l + f,
+        )
+    }
+
+    /// Apply a translation after `self`.
+    #[must_use]
+    pub fn translated(&self, xoff: T, yoff: T) -> Self {
+        self.compose(&Self::translate(xoff, yoff))
+    }
+
+    /// Apply a scaling after `self`.
+    #[must_use]
+    pub fn scaled(&self, xfact: T, yfact: T, origin: impl Into<Coordinate<T>>) -> Self {
+        self.compose(&Self::scale(xfact, yfact, origin))
+    }
+
+    /// Apply the transform to a coordinate.
+    pub fn apply(&self, coord: Coordinate<T>) -> Coordinate<T> {
+        let (x, y) = self.apply_xy(coord.x, coord.y);
+        Coordinate { x, y }
+    }
+
+    fn apply_xy(&self, x: T, y: T) -> (T, T) {
+        let [[a, b, xoff], [d, e, yoff]] = self.0;
+        (a * x + b * y + xoff, d * x + e * y + yoff)
+    }
+
+    pub fn a(&self) -> T {
+        self.0[0][0]
+    }
+    pub fn b(&self) -> T {
+        self.0[0][1]
+    }
+    pub fn xoff(&self) -> T {
+        self.0[0][2]
+    }
+    pub fn d(&self) -> T {
+        self.0[1][0]
+    }
+    pub fn e(&self) -> T {
+        self.0[1][1]
+    }
+    pub fn yoff(&self) -> T {
+        self.0[1][2]
+    }
+}
+
+impl<T: CoordFloat> AffineTransform<T> {
+    /// A counter-clockwise rotation by `degrees` around `origin`.
+    pub fn rotate(degrees: T, origin: impl Into<Coordinate<T>>) -> Self {
+        let Coordinate { x: x0, y: y0 } = origin.into();
+        let (sin, cos) = degrees.to_radians().sin_cos();
+        Self::new(
+            cos,
+            -sin,
+            x0 - x0 * cos + y0 * sin,
+            sin,
+            cos,
+            y0 - x0 * sin - y0 * cos,
+        )
+    }
+
+    /// A skew around `origin`, shearing the x axis by
+    /// `xdegrees` and the y axis by `ydegrees`.
+    pub fn skew(xdegrees: T, ydegrees: T, origin: impl Into<Coordinate<T>>) -> Self {
+        let Coordinate { x: x0, y: y0 } = origin.into();
+        let (tan_x, tan_y) = (xdegrees.to_radians().tan(), ydegrees.to_radians().tan());
+        Self::new(T::one(), tan_x, -y0 * tan_x, tan_y, T::one(), -x0 * tan_y)
+    }
+
+    /// Apply a rotation after `self`.
+    #[must_use]
+    pub fn rotated(&self, degrees: T, origin: impl Into<Coordinate<T>>) -> Self {
+        self.compose(&Self::rotate(degrees, origin))
+    }
+
+    /// Apply a skew after `self`.
+    #[must_use]
+    pub fn skewed(&self, xdegrees: T, ydegrees: T, origin: impl Into<Coordinate<T>>) -> Self {
+        self.compose(&Self::skew(xdegrees, ydegrees, origin))
+    }
+
+    /// The transform undoing `self`, or `None` if it is not
+    /// invertible, as when it scales by zero.
+    pub fn inverse(&self) -> Option<Self> {
+        let [[a, b, xoff], [d, e, yoff]] = self.0;
+        let det = a * e - b * d;
+        if det == T::zero() || !det.is_finite() {
+            return None;
+        }
+        Some(Self::new(
+            e / det,
+            -b / det,
+            (b * yoff - e * xoff) / det,
+            -d / det,
+            a / det,
+            (d * xoff - a * yoff) / det,
+        ))
+    }
+}
+
+impl<T: CoordNum> Default for AffineTransform<T> {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl<T: CoordNum> fmt::Debug for AffineTransform<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AffineTransform")
+            .field("a", &self.0[0][0])
+            .field("b", &self.0[0][1])
+            .field("xoff", &self.0[0][2])
+            .field("d", &self.0[1][0])
+            .field("e", &self.0[1][1])
+            .field("yoff", &self.0[1][2])
+            .finish()
+    }
+}
+
+impl<T: CoordNum> From<[T; 6]> for AffineTransform<T> {
+    fn from([a, b, xoff, d, e, yoff]: [T; 6]) -> Self {
+        Self::new(a, b, xoff, d, e, yoff)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::rotate::RotatePoint;
+    use crate::{line_string, point, polygon};
+
+    fn assert_transform_eq(left: AffineTransform<f64>, right: AffineTransform<f64>) {
+        for (l, r) in left.0.iter().flatten().zip(right.0.iter().flatten()) {
+            assert_relative_eq!(l, r, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn identity() {
+        let p = point!(x: 3., y: 4.);
+        assert!(AffineTransform::<f64>::identity().is_identity());
+        assert_eq!(p.affine_transform(&AffineTransform::default()), p);
+        assert_eq!(
+            AffineTransform::<i32>::default(),
+            AffineTransform::identity()
+        );
+    }
+
+    #[test]
+    fn integer_translation_and_scale() {
+        let transform = AffineTransform::translate(1, 2).scaled(2, 3, (0, 0));
+        let mut ls = line_string![(x: 0, y: 0), (x: 1, y: 1)];
+        ls.affine_transform_mut(&transform);
+        assert_eq!(ls, line_string![(x: 2, y: 6), (x: 4, y: 9)]);
+    }
+
+    #[test]
+    fn rotation_matches_rotate_point() {
+        let poly = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 2.), (x: 0., y: 0.)];
+        let origin = point!(x: 1., y: 5.);
+        let rotated = poly.affine_transform(&AffineTransform::rotate(30., origin));
+        let expected = poly.rotate_around_point(30., origin);
+        for (a, b) in rotated.exterior().0.iter().zip(&expected.exterior().0) {
+            assert_relative_eq!(a.x, b.x, epsilon = 1e-12);
+            assert_relative_eq!(a.y, b.y, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn skew() {
+        let transform = AffineTransform::skew(45., 0., Coordinate { x: 0., y: 1. });
+        let coord = transform.apply(Coordinate { x: 0., y: 3. });
+        assert_relative_eq!(coord.x, 2.);
+        assert_relative_eq!(coord.y, 3.);
+    }
+
+    #[test]
+    fn composition_order() {
+        let origin = Coordinate { x: 0., y: 0. };
+        let transform = AffineTransform::rotate(90., origin).translated(1., 0.);
+        let coord = transform.apply(Coordinate { x: 1., y: 0. });
+        assert_relative_eq!(coord.x, 1.);
+        assert_relative_eq!(coord.y, 1.);
+
+        let transform = AffineTransform::translate(1., 0.).rotated(90., origin);
+        let coord = transform.apply(Coordinate { x: 1., y: 0. });
+        assert_relative_eq!(coord.x, 0.);
+        assert_relative_eq!(coord.y, 2.);
+    }
+
+    #[test]
+    fn inverse() {
+        let transform = AffineTransform::rotate(33., (1., 2.))
+            .scaled(2., 0.5, (3., -1.))
+            .skewed(10., 20., (0., 0.))
+            .translated(4., 5.);
+        let inverse = transform.inverse().unwrap();
+        assert_transform_eq(transform.compose(&inverse), AffineTransform::identity());
+        assert_transform_eq(inverse.compose(&transform), AffineTransform::identity());
+
+        assert!(AffineTransform::scale(0., 1., (0., 0.)).inverse().is_none());
+    }
+
+    #[test]
+    fn from_coefficients() {
+        let transform: AffineTransform<f64> = [1., 2., 3., 4., 5., 6.].into();
+        assert_eq!(transform, AffineTransform::new(1., 2., 3., 4., 5., 6.));
+        assert_eq!(
+            (transform.a(), transform.xoff(), transform.e()),
+            (1., 3., 5.)
+        );
+    }
+}
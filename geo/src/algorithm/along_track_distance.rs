@@ -0,0 +1,150 @@
+use crate::algorithm::cross_track_distance::{geodesic_track, haversine_track, line_string_track};
+use crate::algorithm::geodesic_distance::GeodesicDistance;
+use crate::algorithm::haversine_distance::HaversineDistance;
+use crate::{CoordFloat, Line, LineString, Point};
+use num_traits::FromPrimitive;
+
+/// Determine the distance along a great-circle route to the point closest
+/// to a `Point`.
+///
+/// For a `Line`, the route is the whole great circle through its endpoints,
+/// and the distance is measured from its start: it is negative if the
+/// closest point lies behind the start. For a `LineString`, the distance is
+/// measured along its segments to its closest point, so it lies between
+/// zero and the length of the `LineString`.
+///
+/// *Note*: this implementation uses a mean earth radius of 6371.088 km, based on the [recommendation of
+/// the IUGG](ftp://athena.fsv.cvut.cz/ZFG/grs80-Moritz.pdf)
+pub trait AlongTrackDistance<T, Rhs = Self> {
+    /// Determine the distance along a great-circle route to the point
+    /// closest to a `Point`.
+    ///
+    /// # Units
+    ///
+    /// - return value: meters
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// #
+    /// use geo::algorithm::along_track_distance::AlongTrackDistance;
+    /// use geo::{line_string, point};
+    ///
+    /// let route = line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.)];
+    ///
+    /// // abeam the equator at 5°E
+    /// let p = point!(x: 5., y: 1.);
+    /// assert_relative_eq!(p.along_track_distance(&route), 555_975.40, epsilon = 1.0e-2);
+    /// ```
+    fn along_track_distance(&self, rhs: &Rhs) -> T;
+}
+
+/// Determine the distance along a geodesic route to the point closest to a
+/// `Point`, on an ellipsoidal model of the earth.
+///
+/// This is the ellipsoidal counterpart of [`AlongTrackDistance`], on the
+/// WGS84 ellipsoid, using the geodesic methods given by [Karney (2013)].
+///
+/// [Karney (2013)]:  https://arxiv.org/pdf/1109.4448.pdf
+pub trait GeodesicAlongTrackDistance<T, Rhs = Self> {
+    /// Determine the distance along a geodesic route to the point closest
+    /// to a `Point`.
+    ///
+    /// # Units
+    ///
+    /// - return value: meters
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// #
+    /// use geo::algorithm::along_track_distance::GeodesicAlongTrackDistance;
+    /// use geo::{point, Line};
+    ///
+    /// let route = Line::new((0., 0.), (10., 0.));
+    ///
+    /// let p = point!(x: 5., y: 1.);
+    /// assert_relative_eq!(p.geodesic_along_track_distance(&route), 556_597.45, epsilon = 1.0e-2);
+    /// ```
+    fn geodesic_along_track_distance(&self, rhs: &Rhs) -> T;
+}
+
+impl<T> AlongTrackDistance<T, Line<T>> for Point<T>
+where
+    T: CoordFloat + FromPrimitive,
+{
+    fn along_track_distance(&self, rhs: &Line<T>) -> T {
+        haversine_track(*self, *rhs).along
+    }
+}
+
+impl<T> AlongTrackDistance<T, LineString<T>> for Point<T>
+where
+    T: CoordFloat + FromPrimitive,
+{
+    fn along_track_distance(&self, rhs: &LineString<T>) -> T {
+        line_string_track(*self, rhs, haversine_track, |p, q| p.haversine_distance(&q)).1
+    }
+}
+
+impl GeodesicAlongTrackDistance<f64, Line<f64>> for Point<f64> {
+    fn geodesic_along_track_distance(&self, rhs: &Line<f64>) -> f64 {
+        geodesic_track(*self, *rhs).along
+    }
+}
+
+impl GeodesicAlongTrackDistance<f64, LineString<f64>> for Point<f64> {
+    fn geodesic_along_track_distance(&self, rhs: &LineString<f64>) -> f64 {
+        line_string_track(*self, rhs, geodesic_track, |p, q| p.geodesic_distance(&q)).1
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::haversine_length::HaversineLength;
+    use crate::{line_string, point};
+
+    #[test]
+    fn line() {
+        let route = Line::new((0., 0.), (10., 0.));
+        assert_relative_eq!(
+            point!(x: 5., y: 1.).along_track_distance(&route),
+            point!(x: 0., y: 0.).haversine_distance(&point!(x: 5., y: 0.)),
+            epsilon = 1.0e-6
+        );
+        // behind the start
+        assert!(point!(x: -1., y: 1.).along_track_distance(&route) < 0.);
+        // beyond the end
+        assert!(point!(x: 12., y: 0.).along_track_distance(&route) > route.haversine_length());
+    }
+
+    #[test]
+    fn line_string() {
+        let route = line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.)];
+        let first = Line::new((0., 0.), (10., 0.)).haversine_length();
+
+        let p = point!(x: 11., y: 5.);
+        let expected = first + p.along_track_distance(&Line::new((10., 0.), (10., 10.)));
+        assert_relative_eq!(p.along_track_distance(&route), expected);
+
+        // clamped to the route
+        assert_eq!(point!(x: -1., y: 0.).along_track_distance(&route), 0.);
+        assert_relative_eq!(
+            point!(x: 10., y: 12.).along_track_distance(&route),
+            route.haversine_length()
+        );
+    }
+
+    #[test]
+    fn geodesic() {
+        let route = line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.)];
+        let first = point!(x: 0., y: 0.).geodesic_distance(&point!(x: 10., y: 0.));
+        let p = point!(x: 9., y: 5.);
+        let along = p.geodesic_along_track_distance(&route);
+        let meridian = point!(x: 10., y: 0.).geodesic_distance(&point!(x: 10., y: 5.));
+        assert!((along - first - meridian).abs() < 1_000.);
+    }
+}
@@ -0,0 +1,644 @@
+use crate::algorithm::bool_ops::BooleanOps;
+use crate::algorithm::bounding_rect::BoundingRect;
+use crate::algorithm::map_coords::MapCoords;
+use crate::{
+    CoordFloat, Coordinate, GeoFloat, Geometry, GeometryCollection, Line, LineString,
+    MultiLineString, MultiPoint, MultiPolygon, Point, Polygon, Rect, Triangle,
+};
+use rstar::RTreeNum;
+
+/// Split a geometry in longitude/latitude coordinates where it crosses
+/// the antimeridian, at ±180° longitude, so that each part lies within
+/// `[-180, 180]`.
+///
+/// A segment crosses the antimeridian if the longitudes of its ends
+/// differ by more than 180°: it takes the shortest way around the
+/// earth. The latitude of the crossing is interpolated linearly.
+///
+/// Polygons which go around a pole cannot be split, and are returned
+/// as they are.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::antimeridian::SplitAntimeridian;
+/// use geo::{line_string, MultiLineString};
+///
+/// // across the Pacific, from Japan to Alaska
+/// let route = line_string![(x: 170., y: 40.), (x: -170., y: 50.)];
+/// assert_eq!(
+///     route.split_antimeridian(),
+///     MultiLineString(vec![
+///         line_string![(x: 170., y: 40.), (x: 180., y: 45.)],
+///         line_string![(x: -180., y: 45.), (x: -170., y: 50.)],
+///     ])
+/// );
+/// ```
+pub trait SplitAntimeridian {
+    type Output;
+
+    fn split_antimeridian(&self) -> Self::Output;
+}
+
+/// The range of longitudes and latitudes covered by a geometry, which
+/// may wrap around the antimeridian.
+///
+/// If the range crosses the antimeridian, `min_lng` is greater than
+/// `max_lng`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WrappedRect<T: CoordFloat> {
+    pub min_lng: T,
+    pub max_lng: T,
+    pub min_lat: T,
+    pub max_lat: T,
+}
+
+impl<T: CoordFloat> WrappedRect<T> {
+    /// Whether the range of longitudes crosses the antimeridian.
+    pub fn crosses_antimeridian(&self) -> bool {
+        self.min_lng > self.max_lng
+    }
+
+    /// The range as one `Rect`, or as two on each side of the
+    /// antimeridian if it crosses it.
+    pub fn to_rects(&self) -> Vec<Rect<T>> {
+        let rect = |min_lng, max_lng| {
+            Rect::new(
+                Coordinate {
+                    x: min_lng,
+                    y: self.min_lat,
+                },
+                Coordinate {
+                    x: max_lng,
+                    y: self.max_lat,
+                },
+            )
+        };
+        let half_turn = T::from(180).unwrap();
+        if self.crosses_antimeridian() {
+            vec![
+                rect(self.min_lng, half_turn),
+                rect(-half_turn, self.max_lng),
+            ]
+        } else {
+            vec![rect(self.min_lng, self.max_lng)]
+        }
+    }
+}
+
+/// Calculate the smallest range of longitudes and latitudes covering a
+/// geometry, which may wrap around the antimeridian.
+///
+/// Unlike [`BoundingRect`](crate::algorithm::bounding_rect::BoundingRect),
+/// a geometry crossing the antimeridian gets a narrow range around it,
+/// rather than one spanning the whole earth. As for
+/// [`SplitAntimeridian`], each segment covers the longitudes between its
+/// ends the shortest way around the earth.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::antimeridian::WrappedBoundingRect;
+/// use geo::line_string;
+///
+/// let route = line_string![(x: 170., y: 40.), (x: -170., y: 50.)];
+/// let rect = route.wrapped_bounding_rect().unwrap();
+/// assert!(rect.crosses_antimeridian());
+/// assert_eq!((rect.min_lng, rect.max_lng), (170., -170.));
+/// assert_eq!((rect.min_lat, rect.max_lat), (40., 50.));
+/// ```
+pub trait WrappedBoundingRect<T: CoordFloat> {
+    /// Return the range covered by the geometry, or `None` if it is
+    /// empty. Non-finite coordinates are ignored.
+    fn wrapped_bounding_rect(&self) -> Option<WrappedRect<T>>;
+}
+
+macro_rules! wrapped_bounding_rect_impl {
+    ($($t:ident),* $(,)?) => {
+        $(
+            impl<T: CoordFloat> WrappedBoundingRect<T> for $t<T> {
+                fn wrapped_bounding_rect(&self) -> Option<WrappedRect<T>> {
+                    let mut extent = Extent::new();
+                    self.add_extent(&mut extent);
+                    extent.wrapped_rect()
+                }
+            }
+        )*
+    };
+}
+
+wrapped_bounding_rect_impl![
+    Point,
+    Line,
+    LineString,
+    Polygon,
+    MultiPoint,
+    MultiLineString,
+    MultiPolygon,
+    Rect,
+    Triangle,
+    GeometryCollection,
+    Geometry,
+];
+
+// The longitudes and latitudes covered by the vertices and
+// segments of a geometry.
+struct Extent<T: CoordFloat> {
+    // Ranges of longitudes, each going east from its first to its
+    // second element without crossing the antimeridian.
+    lngs: Vec<(T, T)>,
+    min_lat: T,
+    max_lat: T,
+}
+
+impl<T: CoordFloat> Extent<T> {
+    fn new() -> Self {
+        Extent {
+            lngs: vec![],
+            min_lat: T::infinity(),
+            max_lat: T::neg_infinity(),
+        }
+    }
+
+    fn add_coord(&mut self, coord: Coordinate<T>) {
+        if coord.x.is_finite() && coord.y.is_finite() {
+            self.lngs.push((coord.x, coord.x));
+            self.min_lat = self.min_lat.min(coord.y);
+            self.max_lat = self.max_lat.max(coord.y);
+        }
+    }
+
+    // Add a segment, which covers the longitudes between its ends
+    // the shortest way around the earth.
+    fn add_line(&mut self, line: Line<T>) {
+        self.add_coord(line.start);
+        self.add_coord(line.end);
+        if ![line.start, line.end]
+            .iter()
+            .all(|c| c.x.is_finite() && c.y.is_finite())
+        {
+            return;
+        }
+        let half_turn = T::from(180).unwrap();
+        let west = line.start.x.min(line.end.x);
+        let east = line.start.x.max(line.end.x);
+        if east - west > half_turn {
+            self.lngs.push((east, east.max(half_turn)));
+            self.lngs.push((west.min(-half_turn), west));
+        } else {
+            self.lngs.push((west, east));
+        }
+    }
+
+    fn add_line_string(&mut self, line_string: &LineString<T>) {
+        if let [coord] = line_string.0[..] {
+            self.add_coord(coord);
+        }
+        for line in line_string.lines() {
+            self.add_line(line);
+        }
+    }
+
+    // The range is the complement of the largest gap between the
+    // ranges of longitudes, going around the earth.
+    fn wrapped_rect(mut self) -> Option<WrappedRect<T>> {
+        self.lngs.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+        let first = *self.lngs.first()?;
+        let last = self
+            .lngs
+            .iter()
+            .fold(first.1, |last, &(_, east)| last.max(east));
+
+        let turn = T::from(360).unwrap();
+        let (mut min_lng, mut max_lng) = (first.0, last);
+        let mut largest_gap = first.0 + turn - last;
+        let mut covered = first.1;
+        for &(west, east) in &self.lngs[1..] {
+            if west - covered > largest_gap {
+                largest_gap = west - covered;
+                min_lng = west;
+                max_lng = covered;
+            }
+            covered = covered.max(east);
+        }
+        Some(WrappedRect {
+            min_lng,
+            max_lng,
+            min_lat: self.min_lat,
+            max_lat: self.max_lat,
+        })
+    }
+}
+
+trait AddExtent<T: CoordFloat> {
+    fn add_extent(&self, extent: &mut Extent<T>);
+}
+
+impl<T: CoordFloat> AddExtent<T> for Point<T> {
+    fn add_extent(&self, extent: &mut Extent<T>) {
+        extent.add_coord(self.0);
+    }
+}
+
+impl<T: CoordFloat> AddExtent<T> for Line<T> {
+    fn add_extent(&self, extent: &mut Extent<T>) {
+        extent.add_line(*self);
+    }
+}
+
+impl<T: CoordFloat> AddExtent<T> for LineString<T> {
+    fn add_extent(&self, extent: &mut Extent<T>) {
+        extent.add_line_string(self);
+    }
+}
+
+impl<T: CoordFloat> AddExtent<T> for Polygon<T> {
+    fn add_extent(&self, extent: &mut Extent<T>) {
+        extent.add_line_string(self.exterior());
+        for interior in self.interiors() {
+            extent.add_line_string(interior);
+        }
+    }
+}
+
+impl<T: CoordFloat> AddExtent<T> for MultiPoint<T> {
+    fn add_extent(&self, extent: &mut Extent<T>) {
+        for point in self {
+            point.add_extent(extent);
+        }
+    }
+}
+
+impl<T: CoordFloat> AddExtent<T> for MultiLineString<T> {
+    fn add_extent(&self, extent: &mut Extent<T>) {
+        for line_string in self {
+            line_string.add_extent(extent);
+        }
+    }
+}
+
+impl<T: CoordFloat> AddExtent<T> for MultiPolygon<T> {
+    fn add_extent(&self, extent: &mut Extent<T>) {
+        for polygon in self {
+            polygon.add_extent(extent);
+        }
+    }
+}
+
+impl<T: CoordFloat> AddExtent<T> for Rect<T> {
+    fn add_extent(&self, extent: &mut Extent<T>) {
+        self.to_polygon().add_extent(extent);
+    }
+}
+
+impl<T: CoordFloat> AddExtent<T> for Triangle<T> {
+    fn add_extent(&self, extent: &mut Extent<T>) {
+        self.to_polygon().add_extent(extent);
+    }
+}
+
+impl<T: CoordFloat> AddExtent<T> for GeometryCollection<T> {
+    fn add_extent(&self, extent: &mut Extent<T>) {
+        for geometry in self {
+            geometry.add_extent(extent);
+        }
+    }
+}
+
+impl<T: CoordFloat> AddExtent<T> for Geometry<T> {
+    fn add_extent(&self, extent: &mut Extent<T>) {
+        match self {
+            Geometry::Point(g) => g.add_extent(extent),
+            Geometry::Line(g) => g.add_extent(extent),
+            Geometry::LineString(g) => g.add_extent(extent),
+            Geometry::Polygon(g) => g.add_extent(extent),
+            Geometry::MultiPoint(g) => g.add_extent(extent),
+            Geometry::MultiLineString(g) => g.add_extent(extent),
+            Geometry::MultiPolygon(g) => g.add_extent(extent),
+            Geometry::GeometryCollection(g) => g.add_extent(extent),
+            Geometry::Rect(g) => g.add_extent(extent),
+            Geometry::Triangle(g) => g.add_extent(extent),
+        }
+    }
+}
+
+impl<T: CoordFloat> SplitAntimeridian for LineString<T> {
+    type Output = MultiLineString<T>;
+
+    fn split_antimeridian(&self) -> MultiLineString<T> {
+        let half_turn = T::from(180).unwrap();
+        let mut parts = vec![];
+        let mut part: Vec<Coordinate<T>> = vec![];
+        for coord in self.0.iter().copied() {
+            if let Some(&prev) = part.last() {
+                let delta = coord.x - prev.x;
+                if delta.abs() > half_turn {
+                    // Leave through the side the segment is heading to.
+                    let side = if delta < T::zero() {
+                        half_turn
+                    } else {
+                        -half_turn
+                    };
+                    let unwrapped = coord.x + side + side;
+                    // A segment running along the antimeridian is cut
+                    // at its end.
+                    let lat = if unwrapped == prev.x {
+                        coord.y
+                    } else {
+                        let t = (side - prev.x) / (unwrapped - prev.x);
+                        prev.y + (coord.y - prev.y) * t
+                    };
+                    let exit = Coordinate { x: side, y: lat };
+                    if exit != prev {
+                        part.push(exit);
+                    }
+                    parts.push(LineString(std::mem::take(&mut part)));
+                    part.push(Coordinate { x: -side, y: lat });
+                }
+            }
+            if part.last() != Some(&coord) {
+                part.push(coord);
+            }
+        }
+        parts.push(LineString(part));
+        MultiLineString(parts.into_iter().filter(|ls| ls.0.len() > 1).collect())
+    }
+}
+
+impl<T: CoordFloat> SplitAntimeridian for MultiLineString<T> {
+    type Output = MultiLineString<T>;
+
+    fn split_antimeridian(&self) -> MultiLineString<T> {
+        MultiLineString(
+            self.iter()
+                .flat_map(|ls| ls.split_antimeridian().0)
+                .collect(),
+        )
+    }
+}
+
+impl<T: GeoFloat + RTreeNum> SplitAntimeridian for Polygon<T> {
+    type Output = MultiPolygon<T>;
+
+    fn split_antimeridian(&self) -> MultiPolygon<T> {
+        let half_turn = T::from(180).unwrap();
+        let turn = half_turn + half_turn;
+        let exterior = match unwrap_ring(self.exterior()) {
+            Some(exterior) => exterior,
+            None => return MultiPolygon(vec![self.clone()]),
+        };
+        let rect = match exterior.bounding_rect() {
+            Some(rect) if rect.min().x < -half_turn || rect.max().x > half_turn => rect,
+            _ => return MultiPolygon(vec![self.clone()]),
+        };
+
+        // Move the holes next to the unwrapped exterior.
+        let mut interiors = vec![];
+        for interior in self.interiors() {
+            let interior = match unwrap_ring(interior) {
+                Some(interior) => interior,
+                None => return MultiPolygon(vec![self.clone()]),
+            };
+            let first = match interior.0.first() {
+                Some(first) => first.x,
+                None => continue,
+            };
+            let offset = ((rect.center().x - first) / turn).round() * turn;
+            interiors.push(interior.map_coords(|&(x, y)| (x + offset, y)));
+        }
+        let unwrapped = Polygon::new(exterior, interiors);
+
+        // Clip the unwrapped polygon to each copy of the map, and move
+        // the parts back.
+        let mut parts = vec![];
+        let mut offset = ((rect.min().x + half_turn) / turn).floor() * turn;
+        while offset - half_turn < rect.max().x {
+            let window = Rect::new(
+                Coordinate {
+                    x: offset - half_turn,
+                    y: -turn,
+                },
+                Coordinate {
+                    x: offset + half_turn,
+                    y: turn,
+                },
+            )
+            .to_polygon();
+            for part in unwrapped.intersection(&window) {
+                parts.push(part.map_coords(|&(x, y)| (x - offset, y)));
+            }
+            offset = offset + turn;
+        }
+        MultiPolygon(parts)
+    }
+}
+
+impl<T: GeoFloat + RTreeNum> SplitAntimeridian for MultiPolygon<T> {
+    type Output = MultiPolygon<T>;
+
+    fn split_antimeridian(&self) -> MultiPolygon<T> {
+        MultiPolygon(
+            self.iter()
+                .flat_map(|polygon| polygon.split_antimeridian().0)
+                .collect(),
+        )
+    }
+}
+
+/// Shift the longitudes of `ring` by whole turns so that none of its
+/// segments crosses the antimeridian, or return `None` if it goes
+/// around a pole.
+fn unwrap_ring<T: CoordFloat>(ring: &LineString<T>) -> Option<LineString<T>> {
+    let half_turn = T::from(180).unwrap();
+    let turn = half_turn + half_turn;
+    let mut offset = T::zero();
+    let mut coords: Vec<Coordinate<T>> = Vec::with_capacity(ring.0.len());
+    for coord in ring.0.iter() {
+        let mut x = coord.x + offset;
+        if let Some(prev) = coords.last() {
+            if x - prev.x > half_turn {
+                offset = offset - turn;
+                x = x - turn;
+            } else if x - prev.x < -half_turn {
+                offset = offset + turn;
+                x = x + turn;
+            }
+        }
+        coords.push(Coordinate { x, y: coord.y });
+    }
+    if offset != T::zero() {
+        return None;
+    }
+    Some(LineString(coords))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::area::Area;
+    use crate::{line_string, point, polygon, MultiPoint};
+
+    #[test]
+    fn line_string_not_crossing() {
+        let ls = line_string![(x: -10., y: 0.), (x: 10., y: 5.), (x: 170., y: 5.)];
+        assert_eq!(ls.split_antimeridian(), MultiLineString(vec![ls.clone()]));
+    }
+
+    #[test]
+    fn line_string_crossing_back_and_forth() {
+        let ls = line_string![
+            (x: 170., y: 0.), (x: -170., y: 10.), (x: -175., y: 20.), (x: 175., y: 20.),
+        ];
+        assert_eq!(
+            ls.split_antimeridian(),
+            MultiLineString(vec![
+                line_string![(x: 170., y: 0.), (x: 180., y: 5.)],
+                line_string![(x: -180., y: 5.), (x: -170., y: 10.), (x: -175., y: 20.), (x: -180., y: 20.)],
+                line_string![(x: 180., y: 20.), (x: 175., y: 20.)],
+            ])
+        );
+    }
+
+    #[test]
+    fn line_string_on_the_antimeridian() {
+        let ls = line_string![(x: 170., y: 0.), (x: 180., y: 0.), (x: -170., y: 0.)];
+        assert_eq!(
+            ls.split_antimeridian(),
+            MultiLineString(vec![
+                line_string![(x: 170., y: 0.), (x: 180., y: 0.)],
+                line_string![(x: -180., y: 0.), (x: -170., y: 0.)],
+            ])
+        );
+
+        // a segment along the antimeridian, from one side to the other
+        let ls = line_string![
+            (x: 170., y: 0.),
+            (x: 180., y: 0.),
+            (x: -180., y: 10.),
+            (x: -170., y: 10.),
+        ];
+        assert_eq!(
+            ls.split_antimeridian(),
+            MultiLineString(vec![
+                line_string![(x: 170., y: 0.), (x: 180., y: 0.), (x: 180., y: 10.)],
+                line_string![(x: -180., y: 10.), (x: -170., y: 10.)],
+            ])
+        );
+    }
+
+    #[test]
+    fn polygon_crossing() {
+        // a square around the antimeridian, with a hole on the west side
+        let polygon = polygon![
+            exterior: [(x: 170., y: -10.), (x: -170., y: -10.), (x: -170., y: 10.), (x: 170., y: 10.)],
+            interiors: [[(x: -178., y: -2.), (x: -172., y: -2.), (x: -172., y: 2.), (x: -178., y: 2.)]],
+        ];
+        let split = polygon.split_antimeridian();
+        assert_eq!(split.0.len(), 2);
+        let areas: Vec<f64> = split.iter().map(|p| p.unsigned_area()).collect();
+        assert_relative_eq!(areas.iter().sum::<f64>(), 400. - 24.);
+        for part in &split {
+            let rect = part.bounding_rect().unwrap();
+            assert!(rect.min().x >= -180. && rect.max().x <= 180.);
+        }
+        let east = split
+            .iter()
+            .find(|p| p.bounding_rect().unwrap().min().x == -180.)
+            .unwrap();
+        assert_eq!(east.interiors().len(), 1);
+    }
+
+    #[test]
+    fn polygon_not_crossing_or_around_a_pole() {
+        let polygon = polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.)];
+        assert_eq!(polygon.split_antimeridian(), MultiPolygon(vec![polygon]));
+
+        let around_pole = polygon![
+            (x: -180., y: 80.), (x: -60., y: 80.), (x: 60., y: 80.), (x: 179., y: 80.),
+        ];
+        assert_eq!(
+            around_pole.split_antimeridian(),
+            MultiPolygon(vec![around_pole.clone()])
+        );
+    }
+
+    #[test]
+    fn wrapped_bounding_rect() {
+        let ls = line_string![(x: 170., y: 0.), (x: -170., y: 10.), (x: 175., y: -5.)];
+        let rect = ls.wrapped_bounding_rect().unwrap();
+        assert_eq!(
+            rect,
+            WrappedRect {
+                min_lng: 170.,
+                max_lng: -170.,
+                min_lat: -5.,
+                max_lat: 10.,
+            }
+        );
+        assert_eq!(
+            rect.to_rects(),
+            vec![
+                Rect::new(
+                    Coordinate { x: 170., y: -5. },
+                    Coordinate { x: 180., y: 10. }
+                ),
+                Rect::new(
+                    Coordinate { x: -180., y: -5. },
+                    Coordinate { x: -170., y: 10. }
+                ),
+            ]
+        );
+
+        let mp: MultiPoint<f64> = vec![(-10., 0.), (20., 5.)].into();
+        let rect = mp.wrapped_bounding_rect().unwrap();
+        assert!(!rect.crosses_antimeridian());
+        assert_eq!((rect.min_lng, rect.max_lng), (-10., 20.));
+
+        assert!(point!(x: 1., y: 2.).wrapped_bounding_rect().is_some());
+        assert!(MultiPoint::<f64>(vec![]).wrapped_bounding_rect().is_none());
+    }
+
+    #[test]
+    fn wrapped_bounding_rect_covers_segments() {
+        // the middle segment crosses the antimeridian, so the range
+        // goes all around the earth
+        let ls = line_string![(x: 0., y: 0.), (x: 120., y: 0.), (x: -120., y: 0.), (x: 0., y: 0.)];
+        let rect = ls.wrapped_bounding_rect().unwrap();
+        assert_eq!((rect.min_lng, rect.max_lng), (-180., 180.));
+
+        // while its vertices alone leave a gap around it
+        let mp: MultiPoint<f64> = vec![(0., 0.), (120., 0.), (-120., 0.)].into();
+        let rect = mp.wrapped_bounding_rect().unwrap();
+        assert_eq!((rect.min_lng, rect.max_lng), (-120., 120.));
+
+        let geometry = Geometry::GeometryCollection(GeometryCollection(vec![
+            Geometry::Point(point!(x: 0., y: 5.)),
+            Geometry::Line(Line::new((170., 0.), (-170., 0.))),
+        ]));
+        let rect = geometry.wrapped_bounding_rect().unwrap();
+        assert_eq!(
+            rect,
+            WrappedRect {
+                min_lng: 0.,
+                max_lng: -170.,
+                min_lat: 0.,
+                max_lat: 5.,
+            }
+        );
+    }
+
+    #[test]
+    fn wrapped_bounding_rect_skips_non_finite() {
+        let ls = line_string![(x: 170., y: 0.), (x: f64::NAN, y: 50.), (x: -170., y: 10.)];
+        assert_eq!(
+            ls.wrapped_bounding_rect(),
+            Some(WrappedRect {
+                min_lng: 170.,
+                max_lng: -170.,
+                min_lat: 0.,
+                max_lat: 10.,
+            })
+        );
+        assert!(point!(x: f64::NAN, y: 2.).wrapped_bounding_rect().is_none());
+    }
+}
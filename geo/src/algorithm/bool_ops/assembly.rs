@@ -0,0 +1,181 @@
+use crate::algorithm::area::twice_signed_ring_area;
+use crate::algorithm::coordinate_position::{coord_pos_relative_to_ring, CoordPos};
+use crate::kernels::{Kernel, Orientation};
+use crate::utils::lex_cmp;
+use crate::{Coordinate, GeoFloat, Line, LineString, MultiPolygon, Polygon};
+use std::cmp::Ordering;
+
+/// Link directed edges into rings, and the rings into
+/// polygons.
+///
+/// The edges must be noded (only meet at their endpoints),
+/// and have the area they bound on their left. Rings are
+/// traced so that they do not cross themselves, and split
+/// wherever they return to a vertex, so that no ring visits
+/// a vertex twice. Counter-clockwise rings become
+/// exteriors, and each clockwise ring becomes a hole of the
+/// smallest exterior around it.
+pub(crate) fn assemble_polygons<T: GeoFloat>(edges: Vec<Line<T>>) -> MultiPolygon<T> {
+    let mut shells = vec![];
+    let mut holes = vec![];
+    for ring in trace_rings(&edges) {
+        let area = twice_signed_ring_area(&ring);
+        if area > T::zero() {
+            shells.push((ring, area));
+        } else if area < T::zero() {
+            holes.push(ring);
+        }
+    }
+
+    let mut interiors: Vec<Vec<LineString<T>>> = vec![vec![]; shells.len()];
+    for hole in holes {
+        let enclosing = shells
+            .iter()
+            .enumerate()
+            .filter(|(_, (shell, _))| encloses(shell, &hole))
+            .min_by(|(_, (_, a)), (_, (_, b))| a.partial_cmp(b).unwrap());
+        if let Some((idx, _)) = enclosing {
+            interiors[idx].push(hole);
+        }
+    }
+
+    MultiPolygon(
+        shells
+            .into_iter()
+            .zip(interiors)
+            .map(|((shell, _), interiors)| Polygon::new(shell, interiors))
+            .collect(),
+    )
+}
+
+// A ray leaving a vertex along one of the edges.
+#[derive(Debug, Clone, Copy)]
+struct Ray<T: GeoFloat> {
+    edge: usize,
+    outgoing: bool,
+    towards: Coordinate<T>,
+}
+
+fn trace_rings<T: GeoFloat>(edges: &[Line<T>]) -> Vec<LineString<T>> {
+    let mut vertices: Vec<Coordinate<T>> = edges
+        .iter()
+        .map(|e| e.start)
+        .chain(edges.iter().map(|e| e.end))
+        .collect();
+    vertices.sort_by(lex_cmp);
+    vertices.dedup();
+    let vertex_index = |c: &Coordinate<T>| vertices.binary_search_by(|v| lex_cmp(v, c)).unwrap();
+
+    let mut rays: Vec<Vec<Ray<T>>> = vec![vec![]; vertices.len()];
+    for (edge, line) in edges.iter().enumerate() {
+        rays[vertex_index(&line.start)].push(Ray {
+            edge,
+            outgoing: true,
+            towards: line.end,
+        });
+        rays[vertex_index(&line.end)].push(Ray {
+            edge,
+            outgoing: false,
+            towards: line.start,
+        });
+    }
+
+    // Around each vertex, the area to the left of an incoming
+    // edge is bounded by the next outgoing edge clockwise.
+    let mut next = vec![None; edges.len()];
+    for (vertex, rays) in vertices.iter().zip(rays.iter_mut()) {
+        rays.sort_by(|a, b| cmp_angle(*vertex, a.towards, b.towards));
+        for (idx, ray) in rays.iter().enumerate().filter(|(_, ray)| !ray.outgoing) {
+            next[ray.edge] = (1..rays.len())
+                .map(|offset| &rays[(idx + rays.len() - offset) % rays.len()])
+                .find(|candidate| candidate.outgoing)
+                .map(|candidate| candidate.edge);
+        }
+    }
+
+    // Walk the edges, and split off the loop walked since a
+    // vertex whenever the walk returns to it.
+    let mut used = vec![false; edges.len()];
+    let mut position = vec![None; vertices.len()];
+    let mut rings = vec![];
+    for first in 0..edges.len() {
+        if used[first] {
+            continue;
+        }
+        let mut path: Vec<usize> = vec![];
+        let mut vertex = vertex_index(&edges[first].start);
+        let mut current = Some(first);
+        loop {
+            if let Some(start) = position[vertex] {
+                rings.push(close_path(path.drain(start..), &vertices, &mut position));
+            }
+            position[vertex] = Some(path.len());
+            path.push(vertex);
+            match current.filter(|&edge| !used[edge]) {
+                Some(edge) => {
+                    used[edge] = true;
+                    vertex = vertex_index(&edges[edge].end);
+                    current = next[edge];
+                }
+                None => break,
+            }
+        }
+        let rest = close_path(path.drain(..), &vertices, &mut position);
+        if rest.0.len() > 2 {
+            rings.push(rest);
+        }
+    }
+    rings
+}
+
+// Close the walked `path` of vertex indices into a ring, and
+// take its vertices off the walk.
+fn close_path<T: GeoFloat>(
+    path: impl Iterator<Item = usize>,
+    vertices: &[Coordinate<T>],
+    position: &mut [Option<usize>],
+) -> LineString<T> {
+    let mut ring: LineString<T> = path
+        .map(|vertex| {
+            position[vertex] = None;
+            vertices[vertex]
+        })
+        .collect();
+    ring.close();
+    ring
+}
+
+// Order the directions from `center` towards `p` and `q`
+// counter-clockwise, starting from the positive x-axis.
+fn cmp_angle<T: GeoFloat>(center: Coordinate<T>, p: Coordinate<T>, q: Coordinate<T>) -> Ordering {
+    let upper_half = |c: Coordinate<T>| c.y > center.y || (c.y == center.y && c.x > center.x);
+    match (upper_half(p), upper_half(q)) {
+        (true, false) => Ordering::Less,
+        (false, true) => Ordering::Greater,
+        _ => match T::Ker::orient2d(center, p, q) {
+            Orientation::CounterClockwise => Ordering::Less,
+            Orientation::Clockwise => Ordering::Greater,
+            Orientation::Collinear => Ordering::Equal,
+        },
+    }
+}
+
+// Whether the ring `hole` lies inside the ring `shell`. The
+// rings may touch, but must not cross.
+fn encloses<T: GeoFloat>(shell: &LineString<T>, hole: &LineString<T>) -> bool {
+    hole.0
+        .iter()
+        .map(|c| coord_pos_relative_to_ring(*c, shell))
+        .find(|pos| *pos != CoordPos::OnBoundary)
+        .unwrap_or_else(|| {
+            // Every vertex of the hole is on the shell; check
+            // the middle of one of its edges instead.
+            let mid = hole
+                .lines()
+                .next()
+                .map(|line| (line.start + line.end) / (T::one() + T::one()))
+                .unwrap_or_else(|| hole.0[0]);
+            coord_pos_relative_to_ring(mid, shell)
+        })
+        == CoordPos::Inside
+}
@@ -0,0 +1,150 @@
+use crate::{GeoFloat, MultiPolygon, Polygon};
+use rstar::RTreeNum;
+
+/// Boolean operations on the areas covered by geometries:
+/// intersection, union, difference and symmetric difference
+/// (xor).
+///
+/// The result is always a `MultiPolygon`, with exteriors
+/// oriented counter-clockwise and interiors oriented
+/// clockwise. Edges shared by both operands, and vertices
+/// of one operand lying on the boundary of the other, are
+/// detected exactly using the robust orientation predicates
+/// of the [`Kernel`](crate::algorithm::kernels::Kernel), so
+/// such degenerate inputs give predictable results. New
+/// vertices are only computed where edges of the operands
+/// properly cross.
+///
+/// The operands are expected to be valid: rings must not
+/// cross each other or themselves, and the polygons of a
+/// `MultiPolygon` must not overlap. Rings with a non-finite
+/// coordinate are ignored.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::area::Area;
+/// use geo::algorithm::bool_ops::BooleanOps;
+/// use geo::polygon;
+///
+/// let parcel = polygon![
+///     (x: 0., y: 0.),
+///     (x: 4., y: 0.),
+///     (x: 4., y: 4.),
+///     (x: 0., y: 4.),
+/// ];
+/// let zone = polygon![
+///     (x: 2., y: 2.),
+///     (x: 6., y: 2.),
+///     (x: 6., y: 6.),
+///     (x: 2., y: 6.),
+/// ];
+///
+/// assert_eq!(parcel.intersection(&zone).unsigned_area(), 4.);
+/// assert_eq!(parcel.union(&zone).unsigned_area(), 28.);
+/// assert_eq!(parcel.difference(&zone).unsigned_area(), 12.);
+/// assert_eq!(parcel.xor(&zone).unsigned_area(), 24.);
+/// ```
+pub trait BooleanOps: Sized {
+    type Scalar: GeoFloat;
+
+    /// Compute the boolean operation `op` on `self` and
+    /// `other`.
+    fn boolean_op(&self, other: &Self, op: OpType) -> MultiPolygon<Self::Scalar>;
+
+    /// The area covered by both `self` and `other`.
+    fn intersection(&self, other: &Self) -> MultiPolygon<Self::Scalar> {
+        self.boolean_op(other, OpType::Intersection)
+    }
+
+    /// The area covered by `self`, `other`, or both.
+    fn union(&self, other: &Self) -> MultiPolygon<Self::Scalar> {
+        self.boolean_op(other, OpType::Union)
+    }
+
+    /// The area covered by `self` or `other`, but not by
+    /// both.
+    fn xor(&self, other: &Self) -> MultiPolygon<Self::Scalar> {
+        self.boolean_op(other, OpType::Xor)
+    }
+
+    /// The area covered by `self`, but not by `other`.
+    fn difference(&self, other: &Self) -> MultiPolygon<Self::Scalar> {
+        self.boolean_op(other, OpType::Difference)
+    }
+}
+
+/// The boolean operations supported by [`BooleanOps`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OpType {
+    Intersection,
+    Union,
+    Difference,
+    Xor,
+}
+
+impl OpType {
+    // Whether a point belongs to the result, given whether it
+    // belongs to either operand.
+    fn apply(self, in_a: bool, in_b: bool) -> bool {
+        match self {
+            OpType::Intersection => in_a && in_b,
+            OpType::Union => in_a || in_b,
+            OpType::Difference => in_a && !in_b,
+            OpType::Xor => in_a != in_b,
+        }
+    }
+}
+
+impl<T> BooleanOps for Polygon<T>
+where
+    T: GeoFloat + RTreeNum,
+{
+    type Scalar = T;
+
+    fn boolean_op(&self, other: &Self, op: OpType) -> MultiPolygon<T> {
+        overlay::overlay(std::slice::from_ref(self), std::slice::from_ref(other), op)
+    }
+}
+
+impl<T> BooleanOps for MultiPolygon<T>
+where
+    T: GeoFloat + RTreeNum,
+{
+    type Scalar = T;
+
+    fn boolean_op(&self, other: &Self, op: OpType) -> MultiPolygon<T> {
+        overlay::overlay(&self.0, &other.0, op)
+    }
+}
+
+/// Apply `op` to all operands, combining pairs of operands at a
+/// time so that most operations are on small operands. `op`
+/// must be associative. Even a single operand goes through one
+/// operation, which normalises its orientation.
+pub(crate) fn cascade<T: GeoFloat + RTreeNum>(
+    mut operands: Vec<MultiPolygon<T>>,
+    op: OpType,
+) -> MultiPolygon<T> {
+    if operands.is_empty() {
+        return MultiPolygon(vec![]);
+    }
+    loop {
+        let mut iter = operands.into_iter();
+        let mut merged = vec![];
+        while let Some(first) = iter.next() {
+            let second = iter.next().unwrap_or_else(|| MultiPolygon(vec![]));
+            merged.push(first.boolean_op(&second, op));
+        }
+        if merged.len() == 1 {
+            return merged.pop().unwrap();
+        }
+        operands = merged;
+    }
+}
+
+mod assembly;
+mod overlay;
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,150 @@
+use super::assembly::assemble_polygons;
+use super::OpType;
+use crate::algorithm::coordinate_position::{CoordPos, CoordinatePosition};
+use crate::algorithm::noding::node_segments;
+use crate::algorithm::winding_order::{Winding, WindingOrder};
+use crate::utils::lex_cmp;
+use crate::{Coordinate, GeoFloat, Line, LineString, MultiPolygon, Polygon};
+use rstar::RTreeNum;
+use std::cmp::Ordering;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Operand {
+    A,
+    B,
+}
+
+/// Compute `op` on the areas covered by two sets of
+/// polygons.
+///
+/// The boundaries of both operands are split at all their
+/// intersections. Each of the resulting edges is then
+/// labelled with whether the areas on its left and on its
+/// right belong to either operand, and kept iff exactly one
+/// of those sides belongs to the result. Finally the kept
+/// edges are linked back up into rings.
+pub(crate) fn overlay<T: GeoFloat + RTreeNum>(
+    a: &[Polygon<T>],
+    b: &[Polygon<T>],
+    op: OpType,
+) -> MultiPolygon<T> {
+    let mut segments = vec![];
+    push_boundary_segments(a, Operand::A, &mut segments);
+    push_boundary_segments(b, Operand::B, &mut segments);
+
+    let mut edges = vec![];
+    for_each_edge(&node_segments(&segments), |line, count_a, count_b| {
+        let mid = (line.start + line.end) / (T::one() + T::one());
+        let (a_left, a_right) = sides(count_a, || covers(a, &mid));
+        let (b_left, b_right) = sides(count_b, || covers(b, &mid));
+        match (op.apply(a_left, b_left), op.apply(a_right, b_right)) {
+            (true, false) => edges.push(line),
+            (false, true) => edges.push(Line::new(line.end, line.start)),
+            _ => {}
+        }
+    });
+
+    assemble_polygons(edges)
+}
+
+// Push all ring segments, each labelled with its operand and
+// `+1` if the polygon's interior lies to the left of the
+// segment, or `-1` if it lies to the right.
+fn push_boundary_segments<T: GeoFloat>(
+    polygons: &[Polygon<T>],
+    operand: Operand,
+    segments: &mut Vec<(Line<T>, (Operand, i32))>,
+) {
+    for polygon in polygons {
+        push_ring_segments(
+            polygon.exterior(),
+            WindingOrder::CounterClockwise,
+            operand,
+            segments,
+        );
+        for interior in polygon.interiors() {
+            push_ring_segments(interior, WindingOrder::Clockwise, operand, segments);
+        }
+    }
+}
+
+fn push_ring_segments<T: GeoFloat>(
+    ring: &LineString<T>,
+    interior_on_left: WindingOrder,
+    operand: Operand,
+    segments: &mut Vec<(Line<T>, (Operand, i32))>,
+) {
+    // Rings with a non-finite coordinate, or without a
+    // winding order, enclose no area.
+    if !ring.0.iter().all(|c| c.x.is_finite() && c.y.is_finite()) {
+        return;
+    }
+    let sign = match ring.winding_order() {
+        Some(winding_order) if winding_order == interior_on_left => 1,
+        Some(_) => -1,
+        None => return,
+    };
+    segments.extend(ring.lines().map(|line| (line, (operand, sign))));
+}
+
+// Merge identical noded segments into edges, directed from
+// their lexicographically lesser to their greater endpoint,
+// and call `f` with the net count of each operand's
+// segments running along them.
+fn for_each_edge<T, F>(noded: &[(Line<T>, (Operand, i32))], mut f: F)
+where
+    T: GeoFloat,
+    F: FnMut(Line<T>, i32, i32),
+{
+    let mut directed: Vec<(Line<T>, Operand, i32)> = noded
+        .iter()
+        .map(|&(line, (operand, sign))| {
+            if lex_cmp(&line.start, &line.end) == Ordering::Greater {
+                (Line::new(line.end, line.start), operand, -sign)
+            } else {
+                (line, operand, sign)
+            }
+        })
+        .collect();
+    directed.sort_by(|(p, ..), (q, ..)| {
+        lex_cmp(&p.start, &q.start).then_with(|| lex_cmp(&p.end, &q.end))
+    });
+
+    let mut iter = directed.into_iter().peekable();
+    while let Some((line, operand, sign)) = iter.next() {
+        let mut counts = (0, 0);
+        add_count(&mut counts, operand, sign);
+        while let Some((_, operand, sign)) = iter.next_if(|(next, ..)| *next == line) {
+            add_count(&mut counts, operand, sign);
+        }
+        f(line, counts.0, counts.1);
+    }
+}
+
+fn add_count(counts: &mut (i32, i32), operand: Operand, sign: i32) {
+    match operand {
+        Operand::A => counts.0 += sign,
+        Operand::B => counts.1 += sign,
+    }
+}
+
+// Whether the areas to the (left, right) of an edge belong
+// to an operand, given the net count of the operand's
+// boundary segments along the edge. If the edge is not on
+// the boundary, both sides lie on the same side of it.
+fn sides(count: i32, covers_edge: impl FnOnce() -> bool) -> (bool, bool) {
+    match count.cmp(&0) {
+        Ordering::Greater => (true, false),
+        Ordering::Less => (false, true),
+        Ordering::Equal => {
+            let covered = covers_edge();
+            (covered, covered)
+        }
+    }
+}
+
+fn covers<T: GeoFloat>(polygons: &[Polygon<T>], coord: &Coordinate<T>) -> bool {
+    polygons
+        .iter()
+        .any(|polygon| polygon.coordinate_position(coord) == CoordPos::Inside)
+}
@@ -0,0 +1,262 @@
+use super::{BooleanOps, OpType};
+use crate::algorithm::area::Area;
+use crate::algorithm::map_coords::MapCoords;
+use crate::algorithm::validation::Validation;
+use crate::algorithm::winding_order::Winding;
+use crate::*;
+
+fn assert_oriented(mp: &MultiPolygon<f64>) {
+    for polygon in mp {
+        assert!(polygon.exterior().is_ccw());
+        for interior in polygon.interiors() {
+            assert!(interior.is_cw());
+        }
+    }
+}
+
+#[test]
+fn overlapping_squares() {
+    let a = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)];
+    let b = polygon![(x: 2., y: 2.), (x: 6., y: 2.), (x: 6., y: 6.), (x: 2., y: 6.)];
+
+    let intersection = a.intersection(&b);
+    assert_eq!(intersection.0.len(), 1);
+    assert_eq!(intersection.unsigned_area(), 4.);
+
+    let union = a.union(&b);
+    assert_eq!(union.0.len(), 1);
+    assert_eq!(union.0[0].exterior().0.len(), 9);
+    assert_eq!(union.unsigned_area(), 28.);
+
+    let difference = a.difference(&b);
+    assert_eq!(difference.0.len(), 1);
+    assert_eq!(difference.unsigned_area(), 12.);
+
+    let xor = a.xor(&b);
+    assert_eq!(xor.0.len(), 2);
+    assert_eq!(xor.unsigned_area(), 24.);
+
+    for op in &[
+        OpType::Intersection,
+        OpType::Union,
+        OpType::Difference,
+        OpType::Xor,
+    ] {
+        assert_oriented(&a.boolean_op(&b, *op));
+    }
+}
+
+#[test]
+fn disjoint_squares() {
+    let a = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)];
+    let b = polygon![(x: 5., y: 5.), (x: 6., y: 5.), (x: 6., y: 6.), (x: 5., y: 6.)];
+    assert!(a.intersection(&b).0.is_empty());
+    assert_eq!(a.union(&b).0.len(), 2);
+    assert_eq!(a.difference(&b).0.len(), 1);
+    assert_eq!(a.difference(&b).unsigned_area(), 1.);
+}
+
+#[test]
+fn adjacent_squares_share_an_edge() {
+    let a = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)];
+    let b = polygon![(x: 1., y: 0.), (x: 2., y: 0.), (x: 2., y: 1.), (x: 1., y: 1.)];
+
+    let union = a.union(&b);
+    assert_eq!(union.0.len(), 1);
+    assert_eq!(union.unsigned_area(), 2.);
+    assert!(union.0[0].interiors().is_empty());
+
+    assert_eq!(a.intersection(&b).unsigned_area(), 0.);
+    assert_eq!(a.difference(&b).unsigned_area(), 1.);
+    assert_eq!(a.xor(&b).unsigned_area(), 2.);
+}
+
+#[test]
+fn adjacent_squares_share_part_of_an_edge() {
+    let a = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)];
+    let b = polygon![(x: 2., y: 1.), (x: 4., y: 1.), (x: 4., y: 3.), (x: 2., y: 3.)];
+
+    let union = a.union(&b);
+    assert_eq!(union.0.len(), 1);
+    assert_eq!(union.unsigned_area(), 8.);
+    assert!(a.intersection(&b).0.is_empty());
+}
+
+#[test]
+fn squares_touching_at_a_corner_stay_apart() {
+    let a = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)];
+    let b = polygon![(x: 1., y: 1.), (x: 2., y: 1.), (x: 2., y: 2.), (x: 1., y: 2.)];
+    let union = a.union(&b);
+    assert_eq!(union.0.len(), 2);
+    assert_eq!(union.unsigned_area(), 2.);
+    assert!(a.intersection(&b).0.is_empty());
+}
+
+#[test]
+fn identical_polygons() {
+    let a = polygon![(x: 0., y: 0.), (x: 3., y: 0.), (x: 3., y: 3.), (x: 0., y: 3.)];
+    assert_eq!(a.intersection(&a).unsigned_area(), 9.);
+    assert_eq!(a.union(&a).unsigned_area(), 9.);
+    assert!(a.difference(&a).0.is_empty());
+    assert!(a.xor(&a).0.is_empty());
+}
+
+#[test]
+fn difference_creates_a_hole() {
+    let a = polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)];
+    let b = polygon![(x: 4., y: 4.), (x: 6., y: 4.), (x: 6., y: 6.), (x: 4., y: 6.)];
+
+    let difference = a.difference(&b);
+    assert_eq!(difference.0.len(), 1);
+    assert_eq!(difference.0[0].interiors().len(), 1);
+    assert_eq!(difference.unsigned_area(), 96.);
+    assert_oriented(&difference);
+
+    // filling the hole again gives back the original square
+    let filled = difference.union(&MultiPolygon(vec![b]));
+    assert_eq!(filled.0.len(), 1);
+    assert!(filled.0[0].interiors().is_empty());
+    assert_eq!(filled.unsigned_area(), 100.);
+}
+
+#[test]
+fn hole_touching_the_exterior() {
+    let a = polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)];
+    let b = polygon![(x: 0., y: 5.), (x: 5., y: 3.), (x: 5., y: 7.)];
+    let difference = a.difference(&b);
+    assert_eq!(difference.0.len(), 1);
+    assert_eq!(difference.0[0].interiors().len(), 1);
+    assert_eq!(difference.unsigned_area(), 90.);
+    assert!(difference.is_valid());
+
+    // a hole touching the exterior in a single point
+    let b = polygon![(x: 0., y: 5.), (x: 5., y: 3.), (x: 5., y: 7.)];
+    let b = b.map_coords(|&(x, y)| (x + 1., y));
+    let c = polygon![(x: 0., y: 5.), (x: 1., y: 4.), (x: 1., y: 6.)];
+    let difference = a.difference(&b).difference(&MultiPolygon(vec![c]));
+    assert_eq!(difference.unsigned_area(), 89.);
+    assert!(difference.is_valid());
+    assert_oriented(&difference);
+}
+
+#[test]
+fn operands_with_holes() {
+    let a = polygon![
+        exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+        interiors: [[(x: 2., y: 2.), (x: 8., y: 2.), (x: 8., y: 8.), (x: 2., y: 8.)]],
+    ];
+    let b = polygon![(x: 4., y: -1.), (x: 6., y: -1.), (x: 6., y: 1.), (x: 4., y: 1.)];
+
+    // b crosses the exterior of a, but does not reach its hole
+    let intersection = a.intersection(&b);
+    assert_eq!(intersection.unsigned_area(), 2.);
+
+    let c = polygon![(x: 1., y: 1.), (x: 9., y: 1.), (x: 9., y: 9.), (x: 1., y: 9.)];
+    let intersection = a.intersection(&c);
+    assert_eq!(intersection.0.len(), 1);
+    assert_eq!(intersection.0[0].interiors().len(), 1);
+    assert_eq!(intersection.unsigned_area(), 64. - 36.);
+
+    let union = a.union(&polygon![(x: 3., y: 3.), (x: 7., y: 3.), (x: 7., y: 7.), (x: 3., y: 7.)]);
+    assert_eq!(union.0.len(), 2);
+    assert_eq!(union.unsigned_area(), 64. + 16.);
+}
+
+#[test]
+fn orientation_of_operands_does_not_matter() {
+    let a = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)];
+    let mut b = polygon![(x: 2., y: 2.), (x: 6., y: 2.), (x: 6., y: 6.), (x: 2., y: 6.)];
+    b.exterior_mut(|ring| ring.0.reverse());
+    assert!(b.exterior().is_cw());
+    assert_eq!(a.union(&b).unsigned_area(), 28.);
+    assert_eq!(a.intersection(&b).unsigned_area(), 4.);
+}
+
+#[test]
+fn collinear_vertices() {
+    // `a` has redundant collinear vertices along edges
+    // shared with `b`
+    let a = polygon![
+        (x: 0., y: 0.),
+        (x: 1., y: 0.),
+        (x: 2., y: 0.),
+        (x: 2., y: 1.),
+        (x: 2., y: 2.),
+        (x: 0., y: 2.),
+    ];
+    let b = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.)];
+    assert_eq!(a.intersection(&b).unsigned_area(), 2.);
+    assert_eq!(a.union(&b).unsigned_area(), 4.);
+    assert_eq!(a.difference(&b).unsigned_area(), 2.);
+}
+
+#[test]
+fn multi_polygons() {
+    let a = MultiPolygon(vec![
+        polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)],
+        polygon![(x: 4., y: 0.), (x: 6., y: 0.), (x: 6., y: 2.), (x: 4., y: 2.)],
+    ]);
+    let b = MultiPolygon(vec![
+        polygon![(x: 1., y: 1.), (x: 5., y: 1.), (x: 5., y: 5.), (x: 1., y: 5.)],
+    ]);
+
+    assert_eq!(a.intersection(&b).0.len(), 2);
+    assert_eq!(a.intersection(&b).unsigned_area(), 2.);
+    assert_eq!(a.union(&b).0.len(), 1);
+    assert_eq!(a.union(&b).unsigned_area(), 8. + 16. - 2.);
+    assert_eq!(a.difference(&b).unsigned_area(), 6.);
+    assert_eq!(b.difference(&a).unsigned_area(), 14.);
+}
+
+#[test]
+fn empty_operands() {
+    let empty = MultiPolygon::<f64>(vec![]);
+    let a = MultiPolygon(vec![
+        polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)],
+    ]);
+    assert!(empty.union(&empty).0.is_empty());
+    assert!(a.intersection(&empty).0.is_empty());
+    assert_eq!(a.union(&empty).unsigned_area(), 1.);
+    assert!(empty.difference(&a).0.is_empty());
+}
+
+#[test]
+fn crossing_edges() {
+    // a star-like cross of two thin rectangles
+    let a = polygon![(x: -3., y: -1.), (x: 3., y: -1.), (x: 3., y: 1.), (x: -3., y: 1.)];
+    let b = polygon![(x: -1., y: -3.), (x: 1., y: -3.), (x: 1., y: 3.), (x: -1., y: 3.)];
+    let union = a.union(&b);
+    assert_eq!(union.0.len(), 1);
+    assert_eq!(union.0[0].exterior().0.len(), 13);
+    assert_eq!(union.unsigned_area(), 20.);
+    assert_eq!(a.xor(&b).0.len(), 4);
+
+    // non axis-aligned edges crossing at computed points
+    let c = polygon![(x: 0., y: -2.), (x: 2., y: 0.), (x: 0., y: 2.), (x: -2., y: 0.)];
+    let intersection = a.intersection(&c);
+    assert_relative_eq!(intersection.unsigned_area(), 6.);
+    assert_relative_eq!(a.union(&c).unsigned_area(), 12. + 8. - 6.);
+}
+
+#[test]
+fn single_precision() {
+    let a: Polygon<f32> = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)];
+    let b: Polygon<f32> = polygon![(x: 1., y: 1.), (x: 3., y: 1.), (x: 3., y: 3.), (x: 1., y: 3.)];
+    assert_eq!(a.intersection(&b).unsigned_area(), 1.);
+    assert_eq!(a.union(&b).unsigned_area(), 7.);
+}
+
+#[test]
+fn non_finite_vertices() {
+    let a = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)];
+    let b = polygon![(x: 1., y: 1.), (x: f64::NAN, y: 2.), (x: 2., y: 2.)];
+    let c = polygon![(x: 1., y: 1.), (x: 3., y: f64::INFINITY), (x: 2., y: 2.)];
+    // rings with a non-finite vertex are ignored
+    for other in &[b, c] {
+        assert_eq!(a.union(other).unsigned_area(), 16.);
+        assert!(a.intersection(other).0.is_empty());
+        assert_eq!(a.difference(other).unsigned_area(), 16.);
+        assert_eq!(a.xor(other).unsigned_area(), 16.);
+        assert!(other.difference(&a).0.is_empty());
+    }
+}
@@ -0,0 +1,648 @@
+use crate::algorithm::bool_ops::{cascade, BooleanOps, OpType};
+use crate::kernels::{Kernel, Orientation};
+use crate::{
+    Coordinate, GeoFloat, Geometry, GeometryCollection, Line, LineString, MultiLineString,
+    MultiPoint, MultiPolygon, Point, Polygon, Rect, Triangle,
+};
+use rstar::RTreeNum;
+
+/// How the ends of a buffered `Line` or open `LineString`
+/// are shaped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LineCap {
+    /// A half circle around the endpoint.
+    Round,
+    /// The buffer stops at the endpoint.
+    Flat,
+    /// A half square around the endpoint.
+    Square,
+}
+
+/// How the outside corners of a buffer are shaped, where two
+/// segments of a line or ring meet.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LineJoin<T> {
+    /// An arc around the vertex.
+    Round,
+    /// The offset segments are extended until they meet, as long
+    /// as the corner lies within the given multiple of the
+    /// buffer distance from the vertex. Sharper corners are
+    /// bevelled.
+    Mitre(T),
+    /// The offset segments are connected by a straight line.
+    Bevel,
+}
+
+/// The shape of a buffer: its caps and joins, and how finely
+/// arcs are approximated.
+///
+/// The default style has round caps and joins, with 8 segments
+/// per quarter circle.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BufferStyle<T> {
+    pub line_cap: LineCap,
+    pub line_join: LineJoin<T>,
+    /// The number of segments used to approximate a quarter
+    /// circle.
+    pub quadrant_segments: usize,
+}
+
+impl<T> Default for BufferStyle<T> {
+    fn default() -> Self {
+        BufferStyle {
+            line_cap: LineCap::Round,
+            line_join: LineJoin::Round,
+            quadrant_segments: 8,
+        }
+    }
+}
+
+/// Compute the area within a given distance of a geometry.
+///
+/// A positive distance grows the geometry. Points and lines
+/// have no area, so their buffer is empty unless the distance
+/// is positive. A negative distance shrinks polygons, which
+/// may split or disappear altogether.
+///
+/// Arcs are approximated by segments whose vertices lie on the
+/// arc, so the buffer is slightly smaller than the exact one.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::area::Area;
+/// use geo::algorithm::buffer::{Buffer, BufferStyle, LineCap};
+/// use geo::{line_string, polygon};
+///
+/// let road = line_string![(x: 0., y: 0.), (x: 10., y: 0.)];
+/// let style = BufferStyle {
+///     line_cap: LineCap::Flat,
+///     ..BufferStyle::default()
+/// };
+/// let corridor = road.buffer_with_style(1., &style);
+/// assert_eq!(corridor.unsigned_area(), 20.);
+///
+/// let square = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)];
+/// assert_eq!(square.buffer(-1.).unsigned_area(), 4.);
+/// ```
+pub trait Buffer {
+    type Scalar: GeoFloat;
+
+    /// Buffer with the default [`BufferStyle`].
+    fn buffer(&self, distance: Self::Scalar) -> MultiPolygon<Self::Scalar> {
+        self.buffer_with_style(distance, &BufferStyle::default())
+    }
+
+    fn buffer_with_style(
+        &self,
+        distance: Self::Scalar,
+        style: &BufferStyle<Self::Scalar>,
+    ) -> MultiPolygon<Self::Scalar>;
+}
+
+impl<T: GeoFloat> Buffer for Point<T> {
+    type Scalar = T;
+
+    fn buffer_with_style(&self, distance: T, style: &BufferStyle<T>) -> MultiPolygon<T> {
+        if distance <= T::zero() {
+            return MultiPolygon(vec![]);
+        }
+        MultiPolygon(vec![disc(self.0, distance, style.quadrant_segments)])
+    }
+}
+
+impl<T: GeoFloat + RTreeNum> Buffer for Line<T> {
+    type Scalar = T;
+
+    fn buffer_with_style(&self, distance: T, style: &BufferStyle<T>) -> MultiPolygon<T> {
+        LineString(vec![self.start, self.end]).buffer_with_style(distance, style)
+    }
+}
+
+impl<T: GeoFloat + RTreeNum> Buffer for LineString<T> {
+    type Scalar = T;
+
+    fn buffer_with_style(&self, distance: T, style: &BufferStyle<T>) -> MultiPolygon<T> {
+        if distance <= T::zero() {
+            return MultiPolygon(vec![]);
+        }
+        let mut pieces = vec![];
+        push_line_string_pieces(self, distance, style, &mut pieces);
+        union_all(pieces)
+    }
+}
+
+impl<T: GeoFloat + RTreeNum> Buffer for Polygon<T> {
+    type Scalar = T;
+
+    fn buffer_with_style(&self, distance: T, style: &BufferStyle<T>) -> MultiPolygon<T> {
+        // The boundary, buffered by the absolute distance, is
+        // added to or cut from the polygon.
+        let mut pieces = vec![];
+        let width = distance.abs();
+        if width > T::zero() {
+            for ring in std::iter::once(self.exterior()).chain(self.interiors()) {
+                push_line_string_pieces(ring, width, style, &mut pieces);
+            }
+        }
+        let boundary = union_all(pieces);
+        let polygon = MultiPolygon(vec![self.clone()]);
+        if distance < T::zero() {
+            polygon.difference(&boundary)
+        } else {
+            polygon.union(&boundary)
+        }
+    }
+}
+
+impl<T: GeoFloat + RTreeNum> Buffer for Rect<T> {
+    type Scalar = T;
+
+    fn buffer_with_style(&self, distance: T, style: &BufferStyle<T>) -> MultiPolygon<T> {
+        self.to_polygon().buffer_with_style(distance, style)
+    }
+}
+
+impl<T: GeoFloat + RTreeNum> Buffer for Triangle<T> {
+    type Scalar = T;
+
+    fn buffer_with_style(&self, distance: T, style: &BufferStyle<T>) -> MultiPolygon<T> {
+        self.to_polygon().buffer_with_style(distance, style)
+    }
+}
+
+macro_rules! buffer_collection_impl {
+    ($t:ident) => {
+        impl<T: GeoFloat + RTreeNum> Buffer for $t<T> {
+            type Scalar = T;
+
+            fn buffer_with_style(&self, distance: T, style: &BufferStyle<T>) -> MultiPolygon<T> {
+                cascade(
+                    self.iter()
+                        .map(|g| g.buffer_with_style(distance, style))
+                        .collect(),
+                    OpType::Union,
+                )
+            }
+        }
+    };
+}
+
+buffer_collection_impl!(MultiPoint);
+buffer_collection_impl!(MultiLineString);
+buffer_collection_impl!(MultiPolygon);
+buffer_collection_impl!(GeometryCollection);
+
+impl<T: GeoFloat + RTreeNum> Buffer for Geometry<T> {
+    type Scalar = T;
+
+    fn buffer_with_style(&self, distance: T, style: &BufferStyle<T>) -> MultiPolygon<T> {
+        match self {
+            Geometry::Point(g) => g.buffer_with_style(distance, style),
+            Geometry::Line(g) => g.buffer_with_style(distance, style),
+            Geometry::LineString(g) => g.buffer_with_style(distance, style),
+            Geometry::Polygon(g) => g.buffer_with_style(distance, style),
+            Geometry::MultiPoint(g) => g.buffer_with_style(distance, style),
+            Geometry::MultiLineString(g) => g.buffer_with_style(distance, style),
+            Geometry::MultiPolygon(g) => g.buffer_with_style(distance, style),
+            Geometry::GeometryCollection(g) => g.buffer_with_style(distance, style),
+            Geometry::Rect(g) => g.buffer_with_style(distance, style),
+            Geometry::Triangle(g) => g.buffer_with_style(distance, style),
+        }
+    }
+}
+
+// Push the polygons whose union is the buffer of a line
+// string: a rectangle along each segment, a cap at each end
+// of an open line string, and a join at each vertex where it
+// turns.
+fn push_line_string_pieces<T: GeoFloat>(
+    line_string: &LineString<T>,
+    distance: T,
+    style: &BufferStyle<T>,
+    pieces: &mut Vec<Polygon<T>>,
+) {
+    let mut coords = line_string.0.clone();
+    coords.dedup();
+    match coords.len() {
+        0 => return,
+        1 => {
+            if style.line_cap == LineCap::Round {
+                pieces.push(disc(coords[0], distance, style.quadrant_segments));
+            } else if style.line_cap == LineCap::Square {
+                let offset = Coordinate {
+                    x: distance,
+                    y: distance,
+                };
+                pieces.push(Rect::new(coords[0] - offset, coords[0] + offset).to_polygon());
+            }
+            return;
+        }
+        _ => {}
+    }
+
+    for window in coords.windows(2) {
+        let line = Line::new(window[0], window[1]);
+        let normal = unit_normal(line) * distance;
+        pieces.push(Polygon::new(
+            LineString(vec![
+                line.start - normal,
+                line.end - normal,
+                line.end + normal,
+                line.start + normal,
+            ]),
+            vec![],
+        ));
+    }
+
+    let closed = coords.len() > 2 && coords.first() == coords.last();
+    if closed {
+        let last = coords.len() - 1;
+        push_join(
+            coords[last - 1],
+            coords[0],
+            coords[1],
+            distance,
+            style,
+            pieces,
+        );
+    } else {
+        let last = coords.len() - 1;
+        push_cap(coords[1], coords[0], distance, style, pieces);
+        push_cap(coords[last - 1], coords[last], distance, style, pieces);
+    }
+    for window in coords.windows(3) {
+        push_join(window[0], window[1], window[2], distance, style, pieces);
+    }
+}
+
+fn push_cap<T: GeoFloat>(
+    previous: Coordinate<T>,
+    end: Coordinate<T>,
+    distance: T,
+    style: &BufferStyle<T>,
+    pieces: &mut Vec<Polygon<T>>,
+) {
+    match style.line_cap {
+        LineCap::Round => pieces.push(disc(end, distance, style.quadrant_segments)),
+        LineCap::Flat => {}
+        LineCap::Square => {
+            let line = Line::new(previous, end);
+            let normal = unit_normal(line) * distance;
+            let extension = unit_direction(line) * distance;
+            pieces.push(Polygon::new(
+                LineString(vec![
+                    end - normal,
+                    end + extension - normal,
+                    end + extension + normal,
+                    end + normal,
+                ]),
+                vec![],
+            ));
+        }
+    }
+}
+
+fn push_join<T: GeoFloat>(
+    previous: Coordinate<T>,
+    vertex: Coordinate<T>,
+    next: Coordinate<T>,
+    distance: T,
+    style: &BufferStyle<T>,
+    pieces: &mut Vec<Polygon<T>>,
+) {
+    // The rectangles of both segments overlap on the inside of
+    // the turn; only the outside needs filling.
+    let side = match T::Ker::orient2d(previous, vertex, next) {
+        Orientation::Collinear => {
+            let incoming = unit_direction(Line::new(previous, vertex));
+            let outgoing = unit_direction(Line::new(vertex, next));
+            if incoming.x * outgoing.x + incoming.y * outgoing.y > T::zero() {
+                // Going straight on, the rectangles already meet.
+                return;
+            }
+            // Turning back, the join covers the end of the line
+            // beyond the vertex, as a cap would.
+            push_turn_back(previous, vertex, distance, style, pieces);
+            return;
+        }
+        // Turning left, the outside is on the right.
+        Orientation::CounterClockwise => -distance,
+        Orientation::Clockwise => distance,
+    };
+    let incoming = unit_normal(Line::new(previous, vertex));
+    let outgoing = unit_normal(Line::new(vertex, next));
+    let start = vertex + incoming * side;
+    let end = vertex + outgoing * side;
+
+    let bevel = || LineString(vec![vertex, start, end]);
+    let ring = match style.line_join {
+        LineJoin::Round => {
+            pieces.push(disc(vertex, distance, style.quadrant_segments));
+            return;
+        }
+        LineJoin::Bevel => bevel(),
+        LineJoin::Mitre(limit) => {
+            // The corner lies along the bisector of the normals,
+            // `2 / |n1 + n2|` times the distance away.
+            let bisector = incoming + outgoing;
+            let norm_squared = bisector.x * bisector.x + bisector.y * bisector.y;
+            let two = T::one() + T::one();
+            if norm_squared > T::zero() && two / norm_squared.sqrt() <= limit {
+                let corner = vertex + bisector * (side * two / norm_squared);
+                LineString(vec![vertex, start, corner, end])
+            } else {
+                bevel()
+            }
+        }
+    };
+    pieces.push(Polygon::new(ring, vec![]));
+}
+
+// The join of a line turning back on itself at `vertex`: a disc
+// for round joins, and a square extension beyond the vertex
+// otherwise.
+fn push_turn_back<T: GeoFloat>(
+    previous: Coordinate<T>,
+    vertex: Coordinate<T>,
+    distance: T,
+    style: &BufferStyle<T>,
+    pieces: &mut Vec<Polygon<T>>,
+) {
+    let cap = match style.line_join {
+        LineJoin::Round => LineCap::Round,
+        LineJoin::Mitre(_) | LineJoin::Bevel => LineCap::Square,
+    };
+    let style = BufferStyle {
+        line_cap: cap,
+        ..*style
+    };
+    push_cap(previous, vertex, distance, &style, pieces);
+}
+
+// The unit vector along `line`, which must not be degenerate.
+fn unit_direction<T: GeoFloat>(line: Line<T>) -> Coordinate<T> {
+    let delta = line.delta();
+    delta / delta.x.hypot(delta.y)
+}
+
+// The unit vector pointing to the left of `line`.
+fn unit_normal<T: GeoFloat>(line: Line<T>) -> Coordinate<T> {
+    let direction = unit_direction(line);
+    Coordinate {
+        x: -direction.y,
+        y: direction.x,
+    }
+}
+
+// A regular polygon inscribed in the circle around `center`.
+fn disc<T: GeoFloat>(center: Coordinate<T>, radius: T, quadrant_segments: usize) -> Polygon<T> {
+    let segments = 4 * quadrant_segments.max(1);
+    let step = T::from(2. * std::f64::consts::PI / segments as f64).unwrap();
+    let coords = (0..segments)
+        .map(|idx| {
+            let (sin, cos) = (step * T::from(idx).unwrap()).sin_cos();
+            Coordinate {
+                x: center.x + radius * cos,
+                y: center.y + radius * sin,
+            }
+        })
+        .collect();
+    Polygon::new(LineString(coords), vec![])
+}
+
+fn union_all<T: GeoFloat + RTreeNum>(pieces: Vec<Polygon<T>>) -> MultiPolygon<T> {
+    cascade(
+        pieces
+            .into_iter()
+            .map(|piece| MultiPolygon(vec![piece]))
+            .collect(),
+        OpType::Union,
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::area::Area;
+    use crate::algorithm::coordinate_position::{CoordPos, CoordinatePosition};
+    use crate::algorithm::validation::Validation;
+    use crate::{line_string, point, polygon};
+
+    fn flat() -> BufferStyle<f64> {
+        BufferStyle {
+            line_cap: LineCap::Flat,
+            line_join: LineJoin::Mitre(5.),
+            quadrant_segments: 8,
+        }
+    }
+
+    #[test]
+    fn point_buffer_is_a_disc() {
+        let disc = point!(x: 1., y: 1.).buffer(2.);
+        assert_eq!(disc.0.len(), 1);
+        assert_eq!(disc.0[0].exterior().0.len(), 33);
+        // a regular 32-gon inscribed in the circle
+        let expected = 16. * 4. * (std::f64::consts::PI / 16.).sin();
+        assert_relative_eq!(disc.unsigned_area(), expected, epsilon = 1e-10);
+
+        assert!(point!(x: 1., y: 1.).buffer(0.).0.is_empty());
+        assert!(point!(x: 1., y: 1.).buffer(-1.).0.is_empty());
+    }
+
+    #[test]
+    fn line_caps() {
+        let line = Line::new((0., 0.), (10., 0.));
+        assert_relative_eq!(line.buffer_with_style(1., &flat()).unsigned_area(), 20.);
+
+        let square = BufferStyle {
+            line_cap: LineCap::Square,
+            ..flat()
+        };
+        assert_relative_eq!(line.buffer_with_style(1., &square).unsigned_area(), 24.);
+
+        let round = line.buffer(1.);
+        assert!(round.is_valid());
+        let disc = point!(x: 0., y: 0.).buffer(1.).unsigned_area();
+        assert_relative_eq!(round.unsigned_area(), 20. + disc, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn line_joins() {
+        let corner = line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.)];
+
+        let mitre = corner.buffer_with_style(1., &flat());
+        assert!(mitre.is_valid());
+        assert_eq!(mitre.0.len(), 1);
+        assert_relative_eq!(mitre.unsigned_area(), 40.);
+        assert_eq!(
+            mitre.coordinate_position(&Coordinate { x: 10.9, y: -0.9 }),
+            CoordPos::Inside
+        );
+
+        let bevel = BufferStyle {
+            line_join: LineJoin::Bevel,
+            ..flat()
+        };
+        assert_relative_eq!(corner.buffer_with_style(1., &bevel).unsigned_area(), 39.5);
+
+        // a mitre limit below the corner's ratio of sqrt(2) bevels it
+        let limited = BufferStyle {
+            line_join: LineJoin::Mitre(1.4),
+            ..flat()
+        };
+        assert_relative_eq!(corner.buffer_with_style(1., &limited).unsigned_area(), 39.5);
+    }
+
+    #[test]
+    fn turning_back() {
+        // the end of the line beyond the turn is capped by the join
+        let ls = line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 5., y: 0.)];
+        assert!(ls.buffer(1.).is_valid());
+        let disc = point!(x: 0., y: 0.).buffer(1.).unsigned_area();
+        assert_relative_eq!(ls.buffer(1.).unsigned_area(), 20. + disc, epsilon = 1e-10);
+        assert_relative_eq!(ls.buffer_with_style(1., &flat()).unsigned_area(), 22.);
+        let bevel = BufferStyle {
+            line_join: LineJoin::Bevel,
+            ..flat()
+        };
+        assert_relative_eq!(ls.buffer_with_style(1., &bevel).unsigned_area(), 22.);
+
+        // as do both joins of a closed ring going back and forth
+        let ring = line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 0., y: 0.)];
+        assert_relative_eq!(ring.buffer(1.).unsigned_area(), 20. + disc, epsilon = 1e-10);
+        assert_relative_eq!(ring.buffer_with_style(1., &flat()).unsigned_area(), 24.);
+
+        // going straight on adds nothing
+        let straight = line_string![(x: 0., y: 0.), (x: 5., y: 0.), (x: 10., y: 0.)];
+        assert_relative_eq!(straight.buffer_with_style(1., &flat()).unsigned_area(), 20.);
+    }
+
+    #[test]
+    fn self_overlapping_line_string() {
+        // the loop of a line crossing itself leaves a hole, unless
+        // the buffer is wide enough to fill it
+        let ls = line_string![
+            (x: 0., y: 0.),
+            (x: 4., y: 0.),
+            (x: 4., y: 4.),
+            (x: 2., y: 4.),
+            (x: 2., y: -2.),
+        ];
+        let buffer = ls.buffer(0.5);
+        assert!(buffer.is_valid());
+        assert_eq!(buffer.0.len(), 1);
+        assert_eq!(buffer.0[0].interiors().len(), 1);
+        let buffer = ls.buffer(1.5);
+        assert!(buffer.is_valid());
+        assert_eq!(buffer.0.len(), 1);
+        assert_eq!(buffer.0[0].interiors().len(), 0);
+
+        // as does a closed ring
+        let ring = line_string![
+            (x: 0., y: 0.),
+            (x: 4., y: 0.),
+            (x: 4., y: 4.),
+            (x: 0., y: 4.),
+            (x: 0., y: 0.),
+        ];
+        let buffer = ring.buffer_with_style(0.5, &flat());
+        assert!(buffer.is_valid());
+        assert_eq!(buffer.0.len(), 1);
+        assert_eq!(buffer.0[0].interiors().len(), 1);
+        assert_relative_eq!(buffer.unsigned_area(), 25. - 9.);
+    }
+
+    #[test]
+    fn grow_and_shrink_polygons() {
+        let square = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)];
+        let style = flat();
+        assert_relative_eq!(square.buffer_with_style(1., &style).unsigned_area(), 36.);
+        assert_relative_eq!(square.buffer_with_style(-1., &style).unsigned_area(), 4.);
+        assert!(square.buffer_with_style(-2., &style).0.is_empty());
+        assert_relative_eq!(square.buffer_with_style(0., &style).unsigned_area(), 16.);
+
+        let rounded = square.buffer(1.);
+        assert!(rounded.is_valid());
+        assert!(square.buffer(-1.).is_valid());
+        assert!(rounded.unsigned_area() > 16. + 16. + 3.);
+        assert!(rounded.unsigned_area() < 16. + 16. + std::f64::consts::PI);
+    }
+
+    #[test]
+    fn shrinking_splits_polygons() {
+        // two squares connected by a thin corridor
+        let dumbbell = polygon![
+            (x: 0., y: 0.),
+            (x: 4., y: 0.),
+            (x: 4., y: 1.5),
+            (x: 6., y: 1.5),
+            (x: 6., y: 0.),
+            (x: 10., y: 0.),
+            (x: 10., y: 4.),
+            (x: 6., y: 4.),
+            (x: 6., y: 2.5),
+            (x: 4., y: 2.5),
+            (x: 4., y: 4.),
+            (x: 0., y: 4.),
+        ];
+        let shrunk = dumbbell.buffer_with_style(-1., &flat());
+        assert!(shrunk.is_valid());
+        assert_eq!(shrunk.0.len(), 2);
+        assert_relative_eq!(shrunk.unsigned_area(), 8.);
+    }
+
+    #[test]
+    fn holes_grow_when_polygons_shrink() {
+        let polygon = Polygon::new(
+            LineString::from(vec![(0., 0.), (10., 0.), (10., 10.), (0., 10.)]),
+            vec![LineString::from(vec![
+                (4., 4.),
+                (6., 4.),
+                (6., 6.),
+                (4., 6.),
+            ])],
+        );
+        let shrunk = polygon.buffer_with_style(-1., &flat());
+        assert!(shrunk.is_valid());
+        assert_eq!(shrunk.0.len(), 1);
+        assert_eq!(shrunk.0[0].interiors().len(), 1);
+        assert_relative_eq!(shrunk.unsigned_area(), 64. - 16.);
+
+        // growing fills the hole
+        let grown = polygon.buffer_with_style(1., &flat());
+        assert!(grown.is_valid());
+        assert!(grown.0[0].interiors().is_empty());
+    }
+
+    #[test]
+    fn hole_touching_the_exterior() {
+        let polygon = polygon![
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [[(x: 0., y: 5.), (x: 3., y: 4.), (x: 3., y: 6.)]],
+        ];
+        let buffer = polygon.buffer_with_style(0., &flat());
+        assert!(buffer.is_valid());
+        assert_eq!(buffer.0.len(), 1);
+        assert_eq!(buffer.0[0].interiors().len(), 1);
+        assert_relative_eq!(buffer.unsigned_area(), 100. - 3.);
+
+        assert!(polygon.buffer_with_style(0.1, &flat()).is_valid());
+        assert!(polygon.buffer_with_style(-0.1, &flat()).is_valid());
+    }
+
+    #[test]
+    fn multi_geometries() {
+        let points = MultiPoint(vec![point!(x: 0., y: 0.), point!(x: 1., y: 0.)]);
+        assert_eq!(points.buffer(1.).0.len(), 1);
+        let points = MultiPoint(vec![point!(x: 0., y: 0.), point!(x: 3., y: 0.)]);
+        assert_eq!(points.buffer(1.).0.len(), 2);
+
+        let geometry = Geometry::GeometryCollection(GeometryCollection(vec![
+            Geometry::Point(point!(x: 10., y: 10.)),
+            Geometry::Rect(Rect::new((0., 0.), (2., 2.))),
+        ]));
+        assert_eq!(geometry.buffer(1.).0.len(), 2);
+        assert_relative_eq!(geometry.buffer(-0.5).unsigned_area(), 1.);
+    }
+}
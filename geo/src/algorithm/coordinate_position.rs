@@ -7,7 +7,7 @@ use crate::{
 };
 
 /// The position of a `Coordinate` relative to a `Geometry`
-#[derive(PartialEq, Clone, Debug)]
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
 pub enum CoordPos {
     OnBoundary,
     Inside,
@@ -0,0 +1,375 @@
+use crate::algorithm::bearing::Bearing;
+use crate::algorithm::geodesic_distance::GeodesicDistance;
+use crate::algorithm::haversine_distance::HaversineDistance;
+use crate::{CoordFloat, Line, LineString, Point, MEAN_EARTH_RADIUS};
+use geographiclib_rs::{DirectGeodesic, Geodesic, InverseGeodesic};
+use num_traits::FromPrimitive;
+
+/// Determine the signed distance from a `Point` to a great-circle route.
+///
+/// The distance is positive if the `Point` lies to the right of the route,
+/// in its direction of travel, and negative if it lies to the left.
+///
+/// For a `Line`, the route is the whole great circle through its endpoints.
+/// For a `LineString`, it is the closest segment, and the distance is the
+/// signed distance to the closest point of that segment. A `LineString` with
+/// no segments gives a distance of zero.
+///
+/// *Note*: this implementation uses a mean earth radius of 6371.088 km, based on the [recommendation of
+/// the IUGG](ftp://athena.fsv.cvut.cz/ZFG/grs80-Moritz.pdf)
+pub trait CrossTrackDistance<T, Rhs = Self> {
+    /// Determine the signed distance from a `Point` to a great-circle route.
+    ///
+    /// # Units
+    ///
+    /// - return value: meters
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// #
+    /// use geo::algorithm::cross_track_distance::CrossTrackDistance;
+    /// use geo::{point, Line};
+    ///
+    /// // eastward along the equator
+    /// let route = Line::new((0., 0.), (10., 0.));
+    ///
+    /// // one degree north, on the left of the route
+    /// let p = point!(x: 5., y: 1.);
+    /// assert_relative_eq!(p.cross_track_distance(&route), -111_195.08, epsilon = 1.0e-2);
+    /// ```
+    fn cross_track_distance(&self, rhs: &Rhs) -> T;
+}
+
+/// Determine the signed distance from a `Point` to a geodesic route on an
+/// ellipsoidal model of the earth.
+///
+/// This is the ellipsoidal counterpart of [`CrossTrackDistance`], on the
+/// WGS84 ellipsoid. The closest point of the route, where the geodesic to
+/// the `Point` meets it at right angles, is found with a safeguarded Newton
+/// iteration on the geodesics of [Karney (2013)]. It is located to within a
+/// micrometre along the route, and the distance is as accurate as the
+/// geodesic computations, to a few nanometres. Near a pole of the route,
+/// where every point of it is almost equally far, the distance remains
+/// accurate, but the closest point becomes ill-defined.
+///
+/// [Karney (2013)]:  https://arxiv.org/pdf/1109.4448.pdf
+pub trait GeodesicCrossTrackDistance<T, Rhs = Self> {
+    /// Determine the signed distance from a `Point` to a geodesic route.
+    ///
+    /// # Units
+    ///
+    /// - return value: meters
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// #
+    /// use geo::algorithm::cross_track_distance::GeodesicCrossTrackDistance;
+    /// use geo::{point, Line};
+    ///
+    /// let route = Line::new((0., 0.), (10., 0.));
+    ///
+    /// let p = point!(x: 5., y: 1.);
+    /// assert_relative_eq!(p.geodesic_cross_track_distance(&route), -110_574.39, epsilon = 1.0e-2);
+    /// ```
+    fn geodesic_cross_track_distance(&self, rhs: &Rhs) -> T;
+}
+
+impl<T> CrossTrackDistance<T, Line<T>> for Point<T>
+where
+    T: CoordFloat + FromPrimitive,
+{
+    fn cross_track_distance(&self, rhs: &Line<T>) -> T {
+        haversine_track(*self, *rhs).cross
+    }
+}
+
+impl<T> CrossTrackDistance<T, LineString<T>> for Point<T>
+where
+    T: CoordFloat + FromPrimitive,
+{
+    fn cross_track_distance(&self, rhs: &LineString<T>) -> T {
+        line_string_track(*self, rhs, haversine_track, |p, q| p.haversine_distance(&q)).0
+    }
+}
+
+impl GeodesicCrossTrackDistance<f64, Line<f64>> for Point<f64> {
+    fn geodesic_cross_track_distance(&self, rhs: &Line<f64>) -> f64 {
+        geodesic_track(*self, *rhs).cross
+    }
+}
+
+impl GeodesicCrossTrackDistance<f64, LineString<f64>> for Point<f64> {
+    fn geodesic_cross_track_distance(&self, rhs: &LineString<f64>) -> f64 {
+        line_string_track(*self, rhs, geodesic_track, |p, q| p.geodesic_distance(&q)).0
+    }
+}
+
+/// The position of a `Point` relative to the route of a `Line`.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Track<T> {
+    /// The signed distance to the route, positive on the right.
+    pub cross: T,
+    /// The signed distance from the start of the `Line` to the
+    /// closest point of the route.
+    pub along: T,
+    /// The length of the `Line`.
+    pub length: T,
+}
+
+/// The track of `point` along the great circle through `line`.
+pub(crate) fn haversine_track<T>(point: Point<T>, line: Line<T>) -> Track<T>
+where
+    T: CoordFloat + FromPrimitive,
+{
+    let radius = T::from(MEAN_EARTH_RADIUS).unwrap();
+    let start = line.start_point();
+    let distance = start.haversine_distance(&point) / radius;
+    let angle = (start.bearing(point) - start.bearing(line.end_point())).to_radians();
+
+    let cross = (distance.sin() * angle.sin()).asin();
+    let along = (distance.sin() * angle.cos()).atan2(distance.cos());
+    Track {
+        cross: cross * radius,
+        along: along * radius,
+        length: start.haversine_distance(&line.end_point()),
+    }
+}
+
+/// The track of `point` along the geodesic through `line`, on the
+/// WGS84 ellipsoid.
+///
+/// The closest point of the geodesic is where the geodesic to `point`
+/// meets it at right angles, i.e. where `cos α` vanishes, `α` being
+/// the angle between the route and the direction of `point`. Starting
+/// from the spherical solution, the root is bracketed, then refined
+/// with Newton's method, falling back to bisection whenever a Newton
+/// step would leave the bracket or not shrink it fast enough. The
+/// derivative of `cos α` along the route is `-sin²α M / m`, from the
+/// reduced length `m` and the geodesic scale `M` of the geodesic to
+/// `point` ([Karney (2013)], section 3). Newton steps are only taken
+/// while they at least halve in length, and bisection halves the bracket
+/// otherwise, so the iteration converges even where Newton's method
+/// alone would not, in a handful of steps in practice.
+///
+/// [Karney (2013)]: https://arxiv.org/pdf/1109.4448.pdf
+pub(crate) fn geodesic_track(point: Point<f64>, line: Line<f64>) -> Track<f64> {
+    // The along-track distance is found to within a micrometre, and the
+    // cross-track distance, which is stationary there, more closely.
+    const TOLERANCE: f64 = 1e-6;
+    const MAX_ITERATIONS: usize = 200;
+
+    let geoid = Geodesic::wgs84();
+    let start = line.start_point();
+    let (length, azimuth, _, _) = geoid.inverse(start.lat(), start.lng(), line.end.y, line.end.x);
+
+    // The signed distance to `point` from the point `along` the route,
+    // `cos α` and its derivative.
+    let abeam = |along: f64| {
+        let (lat, lng, forward) = geoid.direct(start.lat(), start.lng(), azimuth, along);
+        let (distance, bearing, _, m12, big_m12, _, _) =
+            geoid.inverse(lat, lng, point.lat(), point.lng());
+        let (sin, cos) = (bearing - forward).to_radians().sin_cos();
+        (distance.copysign(sin), cos, -sin * sin * big_m12 / m12)
+    };
+
+    // Bracket the root around the spherical solution, whose error is
+    // a fraction of a percent.
+    let guess = haversine_track(point, line).along;
+    let half_circumference = std::f64::consts::PI * geoid.a;
+    let mut width = 1e3 + 0.01 * guess.abs();
+    let (mut low, mut high) = loop {
+        let (low, high) = (guess - width, guess + width);
+        if width >= half_circumference || (abeam(low).1 >= 0. && abeam(high).1 <= 0.) {
+            break (low, high);
+        }
+        width *= 2.;
+    };
+
+    let mut along = guess.max(low).min(high);
+    let (mut cross, mut cos, mut derivative) = abeam(along);
+    let mut step = high - low;
+    for _ in 0..MAX_ITERATIONS {
+        if cos > 0. {
+            low = along;
+        } else {
+            high = along;
+        }
+        let previous_step = step;
+        let newton = along - cos / derivative;
+        if newton > low && newton < high && (2. * cos).abs() <= (previous_step * derivative).abs() {
+            step = newton - along;
+            along = newton;
+        } else {
+            step = (high - low) / 2.;
+            along = low + step;
+        }
+        let next = abeam(along);
+        cross = next.0;
+        cos = next.1;
+        derivative = next.2;
+        if step.abs() < TOLERANCE || cos == 0. {
+            break;
+        }
+    }
+    Track {
+        cross,
+        along,
+        length,
+    }
+}
+
+/// The signed distance from `point` to the closest point of
+/// `line_string`, and the distance along `line_string` to it.
+pub(crate) fn line_string_track<T, F, D>(
+    point: Point<T>,
+    line_string: &LineString<T>,
+    track: F,
+    distance: D,
+) -> (T, T)
+where
+    T: CoordFloat,
+    F: Fn(Point<T>, Line<T>) -> Track<T>,
+    D: Fn(Point<T>, Point<T>) -> T,
+{
+    let mut offset = T::zero();
+    let mut closest: Option<(T, T, T)> = None;
+    for line in line_string.lines() {
+        let t = track(point, line);
+        let (d, along) = if t.along < T::zero() {
+            (distance(point, line.start_point()), T::zero())
+        } else if t.along > t.length {
+            (distance(point, line.end_point()), t.length)
+        } else {
+            (t.cross.abs(), t.along)
+        };
+        if !matches!(closest, Some((min, _, _)) if min <= d) {
+            closest = Some((d, d.copysign(t.cross), offset + along));
+        }
+        offset = offset + t.length;
+    }
+    closest.map_or((T::zero(), T::zero()), |(_, cross, along)| (cross, along))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point};
+
+    #[test]
+    fn sides_of_a_line() {
+        let route = Line::new((0., 0.), (10., 0.));
+        let left = point!(x: 5., y: 1.);
+        let right = point!(x: 5., y: -1.);
+        assert_relative_eq!(
+            left.cross_track_distance(&route),
+            -right.cross_track_distance(&route),
+            epsilon = 1.0e-6
+        );
+        assert_relative_eq!(
+            right.cross_track_distance(&route),
+            point!(x: 5., y: 0.).haversine_distance(&right),
+            epsilon = 1.0e-6
+        );
+        assert_relative_eq!(point!(x: 15., y: 0.).cross_track_distance(&route), 0.);
+    }
+
+    #[test]
+    fn meridian() {
+        // heading north along the prime meridian, the point lies to the right
+        let route = Line::new((0., 0.), (0., 10.));
+        let p = point!(x: 1., y: 5.);
+        let expected =
+            (5f64.to_radians().cos() * 1f64.to_radians().sin()).asin() * MEAN_EARTH_RADIUS;
+        assert_relative_eq!(p.cross_track_distance(&route), expected, epsilon = 1.0e-6);
+    }
+
+    #[test]
+    fn line_string() {
+        let route = line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.)];
+        // to the right of the second segment, heading north
+        let p = point!(x: 11., y: 5.);
+        let expected = p.cross_track_distance(&Line::new((10., 0.), (10., 10.)));
+        assert!(expected > 0.);
+        assert_relative_eq!(p.cross_track_distance(&route), expected);
+
+        // beyond the end of the route
+        let p: Point<f64> = point!(x: 10., y: 11.);
+        assert_relative_eq!(
+            p.cross_track_distance(&route).abs(),
+            p.haversine_distance(&point!(x: 10., y: 10.))
+        );
+
+        let empty: LineString<f64> = line_string![];
+        assert_eq!(p.cross_track_distance(&empty), 0.);
+    }
+
+    #[test]
+    fn geodesic() {
+        let route = Line::new((0., 0.), (10., 0.));
+        let p = point!(x: 5., y: -1.);
+        let track = geodesic_track(p, route);
+        assert_relative_eq!(track.cross, 110_574.389, epsilon = 1.0e-3);
+        assert_relative_eq!(track.along, 556_597.454, epsilon = 1.0e-3);
+
+        // close to the spherical distance
+        let route = line_string![(x: -74.006, y: 40.7128), (x: -0.1278, y: 51.5074)];
+        let p = point!(x: -40., y: 55.);
+        let geodesic = p.geodesic_cross_track_distance(&route);
+        let spherical = p.cross_track_distance(&route);
+        assert!(geodesic < 0.);
+        assert!((geodesic - spherical).abs() / spherical.abs() < 0.01);
+    }
+
+    // Check that `track` is the closest point of the geodesic through
+    // `line` to `p`, where the geodesic to `p` meets it at right angles.
+    fn assert_closest(p: Point<f64>, line: Line<f64>, track: Track<f64>) {
+        let geoid = Geodesic::wgs84();
+        let (_, azimuth, _, _) = geoid.inverse(line.start.y, line.start.x, line.end.y, line.end.x);
+        let distance_at = |along: f64| {
+            let (lat, lng, forward) = geoid.direct(line.start.y, line.start.x, azimuth, along);
+            let (distance, bearing, _, _) = geoid.inverse(lat, lng, p.lat(), p.lng());
+            (distance, bearing - forward)
+        };
+        let (distance, angle) = distance_at(track.along);
+        assert_relative_eq!(track.cross.abs(), distance, epsilon = 1.0e-6);
+        assert_relative_eq!(angle.to_radians().sin().abs(), 1., epsilon = 1.0e-12);
+        for &offset in &[10., 1e3] {
+            assert!(distance_at(track.along - offset).0 > distance);
+            assert!(distance_at(track.along + offset).0 > distance);
+        }
+    }
+
+    #[test]
+    fn geodesic_near_the_poles() {
+        // a long route passing a few degrees from the north pole
+        let route = Line::new((0., 60.), (150., 70.));
+        for &p in &[
+            point!(x: 60., y: 80.),
+            point!(x: -100., y: 85.),
+            point!(x: 20., y: 50.),
+        ] {
+            assert_closest(p, route, geodesic_track(p, route));
+        }
+    }
+
+    #[test]
+    fn geodesic_far_off_track() {
+        // almost at the pole of the equator, every point of the route is
+        // nearly as far: the closest one still lies due south
+        let route = Line::new((0., 0.), (10., 0.));
+        let p = point!(x: 5., y: 89.99);
+        let track = geodesic_track(p, route);
+        let meridian = point!(x: 5., y: 0.).geodesic_distance(&p);
+        assert_relative_eq!(track.cross, -meridian, epsilon = 1.0e-6);
+        assert_relative_eq!(track.along, 556_597.454, epsilon = 1.0e-3);
+
+        let p = point!(x: 5., y: -89.);
+        let track = geodesic_track(p, route);
+        assert_closest(p, route, track);
+        assert!(track.cross > 0.);
+    }
+}
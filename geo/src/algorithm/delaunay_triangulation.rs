@@ -0,0 +1,424 @@
+use std::collections::HashMap;
+
+use crate::algorithm::coordinate_position::CoordPos;
+use crate::algorithm::coords_iter::CoordsIter;
+use crate::kernels::{Kernel, Orientation};
+use crate::utils::lex_cmp;
+use crate::{Coordinate, GeoFloat, Triangle};
+
+/// Compute the [Delaunay triangulation] of the coordinates of a
+/// geometry.
+///
+/// No triangle of the result has an input coordinate strictly
+/// inside its circumcircle, which avoids long, thin triangles
+/// as much as possible. The triangles cover the convex hull of
+/// the input and are oriented counter-clockwise.
+///
+/// Duplicate and non-finite coordinates are ignored. If every
+/// coordinate is collinear there is no triangle, and the result
+/// is empty.
+///
+/// [Delaunay triangulation]: https://en.wikipedia.org/wiki/Delaunay_triangulation
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::delaunay_triangulation::DelaunayTriangulation;
+/// use geo::{Coordinate, MultiPoint, Triangle};
+///
+/// // a flat rhombus, split along its short diagonal
+/// let points: MultiPoint<f64> = vec![(0., 0.), (3., -1.), (6., 0.), (3., 1.)].into();
+///
+/// assert_eq!(points.delaunay_triangle_indices(), vec![[0, 1, 3], [1, 2, 3]]);
+/// assert_eq!(
+///     points.delaunay_triangulation()[0],
+///     Triangle(
+///         Coordinate { x: 0., y: 0. },
+///         Coordinate { x: 3., y: -1. },
+///         Coordinate { x: 3., y: 1. },
+///     )
+/// );
+/// ```
+pub trait DelaunayTriangulation<'a, T: GeoFloat> {
+    /// Return the triangles of the triangulation.
+    fn delaunay_triangulation(&'a self) -> Vec<Triangle<T>>;
+
+    /// Return the triangles of the triangulation, as triples of
+    /// indices into the coordinates of the geometry, in the order
+    /// of [`coords_iter`](CoordsIter::coords_iter).
+    fn delaunay_triangle_indices(&'a self) -> Vec<[usize; 3]>;
+}
+
+impl<'a, T, G> DelaunayTriangulation<'a, T> for G
+where
+    G: CoordsIter<'a, Scalar = T>,
+    T: GeoFloat,
+{
+    fn delaunay_triangulation(&'a self) -> Vec<Triangle<T>> {
+        let coords: Vec<_> = self.coords_iter().collect();
+        triangulate(&coords)
+            .into_iter()
+            .map(|[a, b, c]| Triangle(coords[a], coords[b], coords[c]))
+            .collect()
+    }
+
+    fn delaunay_triangle_indices(&'a self) -> Vec<[usize; 3]> {
+        let coords: Vec<_> = self.coords_iter().collect();
+        triangulate(&coords)
+    }
+}
+
+/// Triangulate `coords`, returning counter-clockwise triples of
+/// indices into it.
+///
+/// The coordinates are inserted in lexicographic order, so that
+/// each new one lies outside the convex hull of the previous
+/// ones. It is connected to the hull edges it can see, and the
+/// Delaunay property is then restored by flipping edges.
+///
+/// The visible hull edges are found by walking the hull from
+/// the previous coordinate, so sorting dominates and typical
+/// inputs take `O(n log n)`. Edge flips make the worst case
+/// `O(n²)`.
+pub(crate) fn triangulate<T: GeoFloat>(coords: &[Coordinate<T>]) -> Vec<[usize; 3]> {
+    let mut order: Vec<usize> = (0..coords.len())
+        .filter(|&i| coords[i].x.is_finite() && coords[i].y.is_finite())
+        .collect();
+    order.sort_by(|&i, &j| lex_cmp(&coords[i], &coords[j]).then(i.cmp(&j)));
+    order.dedup_by(|i, j| coords[*i] == coords[*j]);
+
+    let mut mesh = Mesh {
+        coords,
+        triangles: vec![],
+        edges: HashMap::new(),
+    };
+    if order.len() < 3 {
+        return vec![];
+    }
+
+    // The first coordinates may be collinear: they form a chain,
+    // and the first coordinate off it is the apex of a fan.
+    let first = |k: usize| coords[order[k]];
+    let apex = match (2..order.len())
+        .find(|&k| T::Ker::orient2d(first(0), first(1), first(k)) != Orientation::Collinear)
+    {
+        Some(apex) => apex,
+        None => return vec![],
+    };
+    let (chain, p) = (&order[..apex], order[apex]);
+    let hull_order =
+        if T::Ker::orient2d(first(0), first(1), coords[p]) == Orientation::CounterClockwise {
+            for pair in chain.windows(2) {
+                mesh.add([pair[0], pair[1], p]);
+            }
+            let mut hull_order = chain.to_vec();
+            hull_order.push(p);
+            hull_order
+        } else {
+            for pair in chain.windows(2) {
+                mesh.add([pair[1], pair[0], p]);
+            }
+            let mut hull_order = vec![chain[0], p];
+            hull_order.extend(chain[1..].iter().rev());
+            hull_order
+        };
+
+    let mut hull = Hull {
+        next: vec![0; coords.len()],
+        prev: vec![0; coords.len()],
+    };
+    for (k, &i) in hull_order.iter().enumerate() {
+        let j = hull_order[(k + 1) % hull_order.len()];
+        hull.next[i] = j;
+        hull.prev[j] = i;
+    }
+
+    let mut previous = p;
+    for &p in &order[apex + 1..] {
+        mesh.insert(&mut hull, previous, p);
+        previous = p;
+    }
+    mesh.triangles
+}
+
+// The convex hull, as a doubly linked list of counter-clockwise
+// vertices, indexed by coordinate.
+struct Hull {
+    next: Vec<usize>,
+    prev: Vec<usize>,
+}
+
+struct Mesh<'a, T: GeoFloat> {
+    coords: &'a [Coordinate<T>],
+    triangles: Vec<[usize; 3]>,
+    // The triangle on the left of each directed edge.
+    edges: HashMap<(usize, usize), usize>,
+}
+
+impl<'a, T: GeoFloat> Mesh<'a, T> {
+    fn add(&mut self, triangle: [usize; 3]) {
+        let idx = self.triangles.len();
+        self.triangles.push(triangle);
+        for k in 0..3 {
+            self.edges.insert((triangle[k], triangle[(k + 1) % 3]), idx);
+        }
+    }
+
+    fn replace(&mut self, idx: usize, triangle: [usize; 3]) {
+        let old = self.triangles[idx];
+        for k in 0..3 {
+            let edge = (old[k], old[(k + 1) % 3]);
+            if self.edges.get(&edge) == Some(&idx) {
+                self.edges.remove(&edge);
+            }
+        }
+        self.triangles[idx] = triangle;
+        for k in 0..3 {
+            self.edges.insert((triangle[k], triangle[(k + 1) % 3]), idx);
+        }
+    }
+
+    fn opposite(&self, idx: usize, u: usize, v: usize) -> usize {
+        *self.triangles[idx]
+            .iter()
+            .find(|&&w| w != u && w != v)
+            .unwrap()
+    }
+
+    /// Connect `p`, which lies outside the convex `hull`, to the
+    /// hull edges it can see, starting the search from the hull
+    /// vertex `start`.
+    fn insert(&mut self, hull: &mut Hull, start: usize, p: usize) {
+        let visible = |a: usize, b: usize| {
+            T::Ker::orient2d(self.coords[a], self.coords[b], self.coords[p])
+                == Orientation::Clockwise
+        };
+
+        // The visible edges are contiguous. The previous vertex,
+        // being lexicographically greatest, is almost always an
+        // end of one of them; otherwise go around the hull.
+        let mut first = if visible(start, hull.next[start]) {
+            start
+        } else {
+            hull.prev[start]
+        };
+        if !visible(first, hull.next[first]) {
+            first = hull.next[start];
+            while !visible(first, hull.next[first]) {
+                if first == start {
+                    return;
+                }
+                first = hull.next[first];
+            }
+        }
+        while visible(hull.prev[first], first) {
+            first = hull.prev[first];
+        }
+        let mut last = first;
+        while visible(last, hull.next[last]) {
+            last = hull.next[last];
+        }
+
+        let mut new_edges = vec![];
+        let mut a = first;
+        while a != last {
+            let b = hull.next[a];
+            self.add([a, p, b]);
+            new_edges.push((b, a));
+            a = b;
+        }
+        for (u, v) in new_edges {
+            self.legalize(u, v);
+        }
+
+        hull.next[first] = p;
+        hull.prev[p] = first;
+        hull.next[p] = last;
+        hull.prev[last] = p;
+    }
+
+    /// Flip the edge from `u` to `v`, and the edges it uncovers,
+    /// until none of them breaks the Delaunay property.
+    fn legalize(&mut self, u: usize, v: usize) {
+        let mut stack = vec![(u, v)];
+        while let Some((u, v)) = stack.pop() {
+            let (left, right) = match (self.edges.get(&(u, v)), self.edges.get(&(v, u))) {
+                (Some(&left), Some(&right)) => (left, right),
+                _ => continue,
+            };
+            let p = self.opposite(left, u, v);
+            let d = self.opposite(right, v, u);
+            let c = |i: usize| self.coords[i];
+            if T::Ker::incircle(c(u), c(v), c(p), c(d)) == CoordPos::Inside {
+                self.replace(left, [u, d, p]);
+                self.replace(right, [d, v, p]);
+                stack.push((u, d));
+                stack.push((d, v));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::area::Area;
+    use crate::algorithm::convex_hull::ConvexHull;
+    use crate::kernels::{RobustKernel, SimpleKernel};
+    use crate::{polygon, MultiPoint};
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    fn points(coords: &[(f64, f64)]) -> MultiPoint<f64> {
+        coords.to_vec().into()
+    }
+
+    fn assert_delaunay(coords: &[Coordinate<f64>], triangles: &[[usize; 3]]) {
+        for &[a, b, c] in triangles {
+            assert_eq!(
+                RobustKernel::orient2d(coords[a], coords[b], coords[c]),
+                Orientation::CounterClockwise
+            );
+            for &d in coords {
+                assert_ne!(
+                    RobustKernel::incircle(coords[a], coords[b], coords[c], d),
+                    CoordPos::Inside
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn incircle() {
+        let (a, b, c) = (
+            Coordinate { x: 0., y: 0. },
+            Coordinate { x: 2., y: 0. },
+            Coordinate { x: 0., y: 2. },
+        );
+        assert_eq!(
+            RobustKernel::incircle(a, b, c, Coordinate { x: 1., y: 1. }),
+            CoordPos::Inside
+        );
+        assert_eq!(
+            RobustKernel::incircle(a, b, c, Coordinate { x: 2., y: 2. }),
+            CoordPos::OnBoundary
+        );
+        assert_eq!(
+            RobustKernel::incircle(a, b, c, Coordinate { x: 3., y: 3. }),
+            CoordPos::Outside
+        );
+        let (a, b, c) = (
+            Coordinate { x: 0, y: 0 },
+            Coordinate { x: 2, y: 0 },
+            Coordinate { x: 0, y: 2 },
+        );
+        assert_eq!(
+            SimpleKernel::incircle(a, b, c, Coordinate { x: 1, y: 1 }),
+            CoordPos::Inside
+        );
+        assert_eq!(
+            SimpleKernel::incircle(a, b, c, Coordinate { x: 2, y: 2 }),
+            CoordPos::OnBoundary
+        );
+        assert_eq!(
+            SimpleKernel::incircle(a, b, c, Coordinate { x: 3, y: 3 }),
+            CoordPos::Outside
+        );
+    }
+
+    #[test]
+    fn too_few_points() {
+        assert!(points(&[]).delaunay_triangulation().is_empty());
+        assert!(points(&[(0., 0.), (1., 1.)])
+            .delaunay_triangulation()
+            .is_empty());
+        assert!(points(&[(0., 0.), (1., 1.), (2., 2.), (0., 0.)])
+            .delaunay_triangulation()
+            .is_empty());
+    }
+
+    #[test]
+    fn single_triangle() {
+        let mp = points(&[(0., 0.), (0., 1.), (1., 0.)]);
+        assert_eq!(mp.delaunay_triangle_indices(), vec![[1, 0, 2]]);
+    }
+
+    #[test]
+    fn flips_the_long_diagonal() {
+        // a flat rhombus: the short diagonal must be kept
+        let mp = points(&[(0., 0.), (3., -1.), (6., 0.), (3., 1.)]);
+        let triangles = mp.delaunay_triangle_indices();
+        assert_eq!(triangles.len(), 2);
+        for triangle in triangles {
+            assert!(triangle.contains(&1) && triangle.contains(&3));
+        }
+    }
+
+    #[test]
+    fn collinear_start_and_duplicates() {
+        let coords = [(0., 0.), (0., 1.), (0., 2.), (0., 1.), (1., 1.), (2., 1.)];
+        let mp = points(&coords);
+        let triangles = mp.delaunay_triangle_indices();
+        assert!(triangles.iter().all(|t| !t.contains(&3)));
+        let coords: Vec<_> = mp.coords_iter().collect();
+        assert_delaunay(&coords, &triangles);
+        let area: f64 = mp
+            .delaunay_triangulation()
+            .iter()
+            .map(|t| t.unsigned_area())
+            .sum();
+        assert_relative_eq!(area, 2.);
+    }
+
+    #[test]
+    fn convex_position() {
+        // every point stays on the hull as it is inserted
+        let coords: Vec<Coordinate<f64>> = (0..500)
+            .map(|i| {
+                let x = f64::from(i);
+                Coordinate { x, y: x * x }
+            })
+            .collect();
+        let triangles = triangulate(&coords);
+        assert_eq!(triangles.len(), coords.len() - 2);
+        assert_delaunay(&coords, &triangles);
+    }
+
+    #[test]
+    fn grid() {
+        let coords: Vec<_> = (0..25).map(|i| ((i % 5) as f64, (i / 5) as f64)).collect();
+        let mp = points(&coords);
+        let triangles = mp.delaunay_triangulation();
+        assert_eq!(triangles.len(), 32);
+        assert!(triangles.iter().all(|t| t.unsigned_area() == 0.5));
+    }
+
+    #[test]
+    fn pseudo_random_points() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let coords: Vec<_> = (0..200)
+            .map(|_| (rng.gen_range(0. ..100.), rng.gen_range(0. ..100.)))
+            .collect();
+        let mp = points(&coords);
+
+        let indices = mp.delaunay_triangle_indices();
+        let coords: Vec<_> = mp.coords_iter().collect();
+        assert_delaunay(&coords, &indices);
+
+        let area: f64 = mp
+            .delaunay_triangulation()
+            .iter()
+            .map(|t| t.unsigned_area())
+            .sum();
+        assert_relative_eq!(area, mp.convex_hull().unsigned_area(), epsilon = 1e-6);
+    }
+
+    #[test]
+    fn polygon_vertices() {
+        let poly = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 2., y: 5.), (x: 0., y: 4.)];
+        let triangles = poly.delaunay_triangle_indices();
+        assert_eq!(triangles.len(), 3);
+        // the closing coordinate is a duplicate of the first
+        assert!(triangles.iter().all(|t| !t.contains(&5)));
+    }
+}
@@ -0,0 +1,209 @@
+use crate::algorithm::euclidean_length::EuclideanLength;
+use crate::{
+    CoordFloat, Coordinate, Line, LineString, MultiLineString, MultiPolygon, Polygon, Rect,
+    Triangle,
+};
+
+/// Add vertices to the segments of a geometry, so that none is longer
+/// than `max_distance`, measured in the plane.
+///
+/// Each segment is split into equal parts. The existing vertices are
+/// kept. `max_distance` must be positive; other values leave the
+/// geometry as it is.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::densify::Densify;
+/// use geo::{line_string, LineString};
+///
+/// let line_string: LineString<f64> = line_string![(x: 0., y: 0.), (x: 0., y: 6.), (x: 1., y: 7.)];
+/// let densified = line_string.densify(2.);
+/// assert_eq!(
+///     densified,
+///     line_string![
+///         (x: 0., y: 0.), (x: 0., y: 2.), (x: 0., y: 4.), (x: 0., y: 6.), (x: 1., y: 7.),
+///     ]
+/// );
+/// ```
+pub trait Densify<F: CoordFloat> {
+    type Output;
+
+    fn densify(&self, max_distance: F) -> Self::Output;
+}
+
+/// Push the coordinates splitting the segment from `start` to `end`
+/// into equal parts no longer than `max_distance`.
+fn push_intermediate<T: CoordFloat>(
+    start: Coordinate<T>,
+    end: Coordinate<T>,
+    max_distance: T,
+    coords: &mut Vec<Coordinate<T>>,
+) {
+    let length = Line::new(start, end).euclidean_length();
+    if !is_valid_max_distance(max_distance) || length <= max_distance {
+        return;
+    }
+    let parts = (length / max_distance).ceil();
+    let delta = end - start;
+    let count = parts.to_usize().unwrap_or(1);
+    for i in 1..count {
+        let t = T::from(i).unwrap() / parts;
+        coords.push(Coordinate {
+            x: start.x + delta.x * t,
+            y: start.y + delta.y * t,
+        });
+    }
+}
+
+/// Densify `line_string`, pushing the coordinates between the ends of
+/// each segment with `intermediate`.
+pub(crate) fn densify_line_string<T, I>(
+    line_string: &LineString<T>,
+    intermediate: &I,
+) -> LineString<T>
+where
+    T: CoordFloat,
+    I: Fn(Coordinate<T>, Coordinate<T>, &mut Vec<Coordinate<T>>),
+{
+    let mut coords = Vec::with_capacity(line_string.0.len());
+    for line in line_string.lines() {
+        coords.push(line.start);
+        intermediate(line.start, line.end, &mut coords);
+    }
+    coords.extend(line_string.0.last());
+    LineString(coords)
+}
+
+/// Densify the rings of `polygon` with `intermediate`.
+pub(crate) fn densify_polygon<T, I>(polygon: &Polygon<T>, intermediate: &I) -> Polygon<T>
+where
+    T: CoordFloat,
+    I: Fn(Coordinate<T>, Coordinate<T>, &mut Vec<Coordinate<T>>),
+{
+    Polygon::new(
+        densify_line_string(polygon.exterior(), intermediate),
+        polygon
+            .interiors()
+            .iter()
+            .map(|interior| densify_line_string(interior, intermediate))
+            .collect(),
+    )
+}
+
+/// Whether `max_distance` allows densifying.
+pub(crate) fn is_valid_max_distance<T: CoordFloat>(max_distance: T) -> bool {
+    max_distance > T::zero()
+}
+
+impl<T: CoordFloat> Densify<T> for Line<T> {
+    type Output = LineString<T>;
+
+    fn densify(&self, max_distance: T) -> LineString<T> {
+        LineString(vec![self.start, self.end]).densify(max_distance)
+    }
+}
+
+impl<T: CoordFloat> Densify<T> for LineString<T> {
+    type Output = LineString<T>;
+
+    fn densify(&self, max_distance: T) -> LineString<T> {
+        densify_line_string(self, &|start, end, coords: &mut Vec<_>| {
+            push_intermediate(start, end, max_distance, coords)
+        })
+    }
+}
+
+impl<T: CoordFloat> Densify<T> for MultiLineString<T> {
+    type Output = MultiLineString<T>;
+
+    fn densify(&self, max_distance: T) -> MultiLineString<T> {
+        MultiLineString(self.iter().map(|ls| ls.densify(max_distance)).collect())
+    }
+}
+
+impl<T: CoordFloat> Densify<T> for Polygon<T> {
+    type Output = Polygon<T>;
+
+    fn densify(&self, max_distance: T) -> Polygon<T> {
+        densify_polygon(self, &|start, end, coords: &mut Vec<_>| {
+            push_intermediate(start, end, max_distance, coords)
+        })
+    }
+}
+
+impl<T: CoordFloat> Densify<T> for MultiPolygon<T> {
+    type Output = MultiPolygon<T>;
+
+    fn densify(&self, max_distance: T) -> MultiPolygon<T> {
+        MultiPolygon(self.iter().map(|p| p.densify(max_distance)).collect())
+    }
+}
+
+impl<T: CoordFloat> Densify<T> for Rect<T> {
+    type Output = Polygon<T>;
+
+    fn densify(&self, max_distance: T) -> Polygon<T> {
+        self.to_polygon().densify(max_distance)
+    }
+}
+
+impl<T: CoordFloat> Densify<T> for Triangle<T> {
+    type Output = Polygon<T>;
+
+    fn densify(&self, max_distance: T) -> Polygon<T> {
+        self.to_polygon().densify(max_distance)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, polygon};
+
+    #[test]
+    fn line() {
+        let line = Line::new((0., 0.), (3., 4.));
+        assert_eq!(
+            line.densify(2.),
+            line_string![(x: 0., y: 0.), (x: 1., y: 4. / 3.), (x: 2., y: 8. / 3.), (x: 3., y: 4.)]
+        );
+        assert_eq!(
+            line.densify(5.),
+            line_string![(x: 0., y: 0.), (x: 3., y: 4.)]
+        );
+    }
+
+    #[test]
+    fn polygon_rings() {
+        let polygon = polygon![
+            exterior: [(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)],
+            interiors: [[(x: 1., y: 1.), (x: 2., y: 1.), (x: 2., y: 2.), (x: 1., y: 2.)]],
+        ];
+        let densified = polygon.densify(1.);
+        assert_eq!(densified.exterior().0.len(), 17);
+        assert_eq!(densified.interiors()[0], polygon.interiors()[0]);
+        assert!(densified.exterior().is_closed());
+
+        let rect = Rect::new(Coordinate { x: 0., y: 0. }, Coordinate { x: 4., y: 4. });
+        assert_eq!(rect.densify(1.).exterior().0.len(), 17);
+    }
+
+    #[test]
+    fn invalid_max_distance() {
+        let line_string = line_string![(x: 0., y: 0.), (x: 10., y: 0.)];
+        assert_eq!(line_string.densify(0.), line_string);
+        assert_eq!(line_string.densify(-1.), line_string);
+        assert_eq!(line_string.densify(f64::NAN), line_string);
+    }
+
+    #[test]
+    fn empty() {
+        let empty: LineString<f64> = LineString(vec![]);
+        assert_eq!(empty.densify(1.), empty);
+        assert_eq!(
+            MultiPolygon::<f64>(vec![]).densify(1.),
+            MultiPolygon(vec![])
+        );
+    }
+}
@@ -0,0 +1,149 @@
+use crate::algorithm::densify::{densify_line_string, densify_polygon, is_valid_max_distance};
+use crate::algorithm::geodesic_intermediate::GeodesicIntermediate;
+use crate::{
+    Coordinate, Line, LineString, MultiLineString, MultiPolygon, Point, Polygon, Rect, Triangle,
+};
+
+/// Add vertices along the geodesics between the vertices of a geometry,
+/// on an ellipsoidal model of the earth, so that no segment is longer than
+/// `max_distance` meters.
+///
+/// The existing vertices are kept. `max_distance` must be positive;
+/// other values leave the geometry as it is.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::densify_geodesic::DensifyGeodesic;
+/// use geo::algorithm::geodesic_length::GeodesicLength;
+/// use geo::Line;
+///
+/// // from Paris to New York
+/// let route = Line::new((2.35, 48.86), (-74.01, 40.71));
+/// let densified = route.densify_geodesic(500_000.);
+/// assert_eq!(densified.0.len(), 13);
+/// for line in densified.lines() {
+///     assert!(line.geodesic_length() <= 500_000.);
+/// }
+/// ```
+pub trait DensifyGeodesic<F> {
+    type Output;
+
+    fn densify_geodesic(&self, max_distance: F) -> Self::Output;
+}
+
+/// Push the coordinates along the geodesic from `start` to `end`, no
+/// more than `max_distance` apart.
+fn push_intermediate(
+    start: Coordinate<f64>,
+    end: Coordinate<f64>,
+    max_distance: f64,
+    coords: &mut Vec<Coordinate<f64>>,
+) {
+    if is_valid_max_distance(max_distance) {
+        let points = Point(start).geodesic_intermediate_fill(&Point(end), max_distance, false);
+        coords.extend(points.into_iter().map(|point| point.0));
+    }
+}
+
+impl DensifyGeodesic<f64> for Line<f64> {
+    type Output = LineString<f64>;
+
+    fn densify_geodesic(&self, max_distance: f64) -> LineString<f64> {
+        LineString(vec![self.start, self.end]).densify_geodesic(max_distance)
+    }
+}
+
+impl DensifyGeodesic<f64> for LineString<f64> {
+    type Output = LineString<f64>;
+
+    fn densify_geodesic(&self, max_distance: f64) -> LineString<f64> {
+        densify_line_string(self, &|start, end, coords: &mut Vec<_>| {
+            push_intermediate(start, end, max_distance, coords)
+        })
+    }
+}
+
+impl DensifyGeodesic<f64> for MultiLineString<f64> {
+    type Output = MultiLineString<f64>;
+
+    fn densify_geodesic(&self, max_distance: f64) -> MultiLineString<f64> {
+        MultiLineString(
+            self.iter()
+                .map(|ls| ls.densify_geodesic(max_distance))
+                .collect(),
+        )
+    }
+}
+
+impl DensifyGeodesic<f64> for Polygon<f64> {
+    type Output = Polygon<f64>;
+
+    fn densify_geodesic(&self, max_distance: f64) -> Polygon<f64> {
+        densify_polygon(self, &|start, end, coords: &mut Vec<_>| {
+            push_intermediate(start, end, max_distance, coords)
+        })
+    }
+}
+
+impl DensifyGeodesic<f64> for MultiPolygon<f64> {
+    type Output = MultiPolygon<f64>;
+
+    fn densify_geodesic(&self, max_distance: f64) -> MultiPolygon<f64> {
+        MultiPolygon(
+            self.iter()
+                .map(|p| p.densify_geodesic(max_distance))
+                .collect(),
+        )
+    }
+}
+
+impl DensifyGeodesic<f64> for Rect<f64> {
+    type Output = Polygon<f64>;
+
+    fn densify_geodesic(&self, max_distance: f64) -> Polygon<f64> {
+        self.to_polygon().densify_geodesic(max_distance)
+    }
+}
+
+impl DensifyGeodesic<f64> for Triangle<f64> {
+    type Output = Polygon<f64>;
+
+    fn densify_geodesic(&self, max_distance: f64) -> Polygon<f64> {
+        self.to_polygon().densify_geodesic(max_distance)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::geodesic_length::GeodesicLength;
+    use crate::{line_string, polygon, MultiLineString};
+
+    #[test]
+    fn line_string() {
+        let line_string = line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 0.5)];
+        let densified = line_string.densify_geodesic(200_000.);
+        assert_eq!(densified.0.len(), 8);
+        assert_eq!(densified.0[6], line_string.0[1]);
+        assert_relative_eq!(
+            densified.geodesic_length(),
+            line_string.geodesic_length(),
+            epsilon = 1.0e-6
+        );
+    }
+
+    #[test]
+    fn multi_geometries() {
+        let mls = MultiLineString(vec![line_string![(x: 0., y: 0.), (x: 0., y: 10.)]]);
+        let densified = mls.densify_geodesic(1_000_000.);
+        assert_eq!(densified.0[0].0.len(), 3);
+
+        let mp = MultiPolygon(vec![
+            polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.)],
+        ]);
+        let densified = mp.densify_geodesic(600_000.);
+        assert!(densified.0[0].exterior().0.len() > 4);
+        assert_eq!(mp.densify_geodesic(-1.), mp);
+    }
+}
@@ -0,0 +1,153 @@
+use crate::algorithm::densify::{densify_line_string, densify_polygon, is_valid_max_distance};
+use crate::algorithm::haversine_intermediate::HaversineIntermediate;
+use crate::{
+    CoordFloat, Coordinate, Line, LineString, MultiLineString, MultiPolygon, Point, Polygon, Rect,
+    Triangle,
+};
+use num_traits::FromPrimitive;
+
+/// Add vertices along the great circle routes between the vertices of a
+/// geometry, so that no segment is longer than `max_distance` meters.
+///
+/// The existing vertices are kept. `max_distance` must be positive;
+/// other values leave the geometry as it is.
+///
+/// *Note*: this implementation uses a mean earth radius of 6371.088 km, based on the [recommendation of
+/// the IUGG](ftp://athena.fsv.cvut.cz/ZFG/grs80-Moritz.pdf)
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::densify_haversine::DensifyHaversine;
+/// use geo::algorithm::haversine_length::HaversineLength;
+/// use geo::Line;
+///
+/// // from Paris to New York
+/// let route = Line::new((2.35, 48.86), (-74.01, 40.71));
+/// let densified = route.densify_haversine(500_000.);
+/// assert_eq!(densified.0.len(), 13);
+/// for line in densified.lines() {
+///     assert!(line.haversine_length() <= 500_000.);
+/// }
+/// ```
+pub trait DensifyHaversine<F: CoordFloat> {
+    type Output;
+
+    fn densify_haversine(&self, max_distance: F) -> Self::Output;
+}
+
+/// Push the coordinates along the great circle route from `start` to
+/// `end`, no more than `max_distance` apart.
+fn push_intermediate<T: CoordFloat + FromPrimitive>(
+    start: Coordinate<T>,
+    end: Coordinate<T>,
+    max_distance: T,
+    coords: &mut Vec<Coordinate<T>>,
+) {
+    if is_valid_max_distance(max_distance) {
+        let points = Point(start).haversine_intermediate_fill(&Point(end), max_distance, false);
+        coords.extend(points.into_iter().map(|point| point.0));
+    }
+}
+
+impl<T: CoordFloat + FromPrimitive> DensifyHaversine<T> for Line<T> {
+    type Output = LineString<T>;
+
+    fn densify_haversine(&self, max_distance: T) -> LineString<T> {
+        LineString(vec![self.start, self.end]).densify_haversine(max_distance)
+    }
+}
+
+impl<T: CoordFloat + FromPrimitive> DensifyHaversine<T> for LineString<T> {
+    type Output = LineString<T>;
+
+    fn densify_haversine(&self, max_distance: T) -> LineString<T> {
+        densify_line_string(self, &|start, end, coords: &mut Vec<_>| {
+            push_intermediate(start, end, max_distance, coords)
+        })
+    }
+}
+
+impl<T: CoordFloat + FromPrimitive> DensifyHaversine<T> for MultiLineString<T> {
+    type Output = MultiLineString<T>;
+
+    fn densify_haversine(&self, max_distance: T) -> MultiLineString<T> {
+        MultiLineString(
+            self.iter()
+                .map(|ls| ls.densify_haversine(max_distance))
+                .collect(),
+        )
+    }
+}
+
+impl<T: CoordFloat + FromPrimitive> DensifyHaversine<T> for Polygon<T> {
+    type Output = Polygon<T>;
+
+    fn densify_haversine(&self, max_distance: T) -> Polygon<T> {
+        densify_polygon(self, &|start, end, coords: &mut Vec<_>| {
+            push_intermediate(start, end, max_distance, coords)
+        })
+    }
+}
+
+impl<T: CoordFloat + FromPrimitive> DensifyHaversine<T> for MultiPolygon<T> {
+    type Output = MultiPolygon<T>;
+
+    fn densify_haversine(&self, max_distance: T) -> MultiPolygon<T> {
+        MultiPolygon(
+            self.iter()
+                .map(|p| p.densify_haversine(max_distance))
+                .collect(),
+        )
+    }
+}
+
+impl<T: CoordFloat + FromPrimitive> DensifyHaversine<T> for Rect<T> {
+    type Output = Polygon<T>;
+
+    fn densify_haversine(&self, max_distance: T) -> Polygon<T> {
+        self.to_polygon().densify_haversine(max_distance)
+    }
+}
+
+impl<T: CoordFloat + FromPrimitive> DensifyHaversine<T> for Triangle<T> {
+    type Output = Polygon<T>;
+
+    fn densify_haversine(&self, max_distance: T) -> Polygon<T> {
+        self.to_polygon().densify_haversine(max_distance)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::haversine_length::HaversineLength;
+    use crate::{line_string, polygon};
+
+    #[test]
+    fn line_string() {
+        let line_string = line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 0.5)];
+        let densified = line_string.densify_haversine(200_000.);
+        // the first segment is split, the second is short enough
+        assert_eq!(densified.0.len(), 8);
+        assert_eq!(densified.0[0], line_string.0[0]);
+        assert_eq!(densified.0[6], line_string.0[1]);
+        assert_eq!(densified.0[7], line_string.0[2]);
+        assert_relative_eq!(
+            densified.haversine_length(),
+            line_string.haversine_length(),
+            epsilon = 1.0e-6
+        );
+    }
+
+    #[test]
+    fn polygon() {
+        let polygon = polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)];
+        let densified = polygon.densify_haversine(500_000.);
+        assert!(densified.exterior().is_closed());
+        for line in densified.exterior().lines() {
+            assert!(line.haversine_length() <= 500_000.);
+        }
+        assert_eq!(polygon.densify_haversine(0.), polygon);
+    }
+}
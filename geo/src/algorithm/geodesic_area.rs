@@ -0,0 +1,340 @@
+use crate::{Geometry, GeometryCollection, LineString, MultiPolygon, Polygon, Rect, Triangle};
+use geographiclib_rs::{Geodesic, PolygonArea, Winding};
+
+/// Determine the perimeter and area of a geometry on an ellipsoidal model of the earth.
+///
+/// This uses the geodesic measurement methods given by [Karney (2013)], on the WGS84
+/// ellipsoid, as PostGIS does for `geography` types.
+///
+/// The signed area is positive if the exterior ring of a polygon is oriented
+/// counter-clockwise, and negative otherwise. Interior rings always decrease the
+/// magnitude of the area, and add to the perimeter. Geometries without an area, like
+/// points and lines, have no area nor perimeter.
+///
+/// # Units
+///
+/// - return values: meters and meters²
+///
+/// [Karney (2013)]:  https://arxiv.org/pdf/1109.4448.pdf
+pub trait GeodesicArea<T> {
+    /// Determine the perimeter of a geometry on an ellipsoidal model of the earth.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::geodesic_area::GeodesicArea;
+    /// use geo::polygon;
+    ///
+    /// // one degree square on the equator
+    /// let polygon = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)];
+    ///
+    /// assert_eq!(443_771., polygon.geodesic_perimeter().round());
+    /// ```
+    fn geodesic_perimeter(&self) -> T;
+
+    /// Determine the area of a geometry on an ellipsoidal model of the earth,
+    /// signed by the orientation of its exterior ring.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::geodesic_area::GeodesicArea;
+    /// use geo::polygon;
+    ///
+    /// // one degree square on the equator, oriented clockwise
+    /// let polygon = polygon![(x: 0., y: 0.), (x: 0., y: 1.), (x: 1., y: 1.), (x: 1., y: 0.)];
+    ///
+    /// assert_eq!(-12_308_778_361., polygon.geodesic_area_signed().round());
+    /// ```
+    fn geodesic_area_signed(&self) -> T;
+
+    /// Determine the area of a geometry on an ellipsoidal model of the earth,
+    /// whatever its orientation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::geodesic_area::GeodesicArea;
+    /// use geo::polygon;
+    ///
+    /// let polygon = polygon![(x: 0., y: 0.), (x: 0., y: 1.), (x: 1., y: 1.), (x: 1., y: 0.)];
+    ///
+    /// assert_eq!(12_308_778_361., polygon.geodesic_area_unsigned().round());
+    /// ```
+    fn geodesic_area_unsigned(&self) -> T;
+
+    /// Determine the perimeter and the signed area of a geometry at once.
+    fn geodesic_perimeter_area_signed(&self) -> (T, T);
+
+    /// Determine the perimeter and the unsigned area of a geometry at once.
+    fn geodesic_perimeter_area_unsigned(&self) -> (T, T);
+}
+
+/// The perimeter and signed area of a ring, positive if it is
+/// oriented counter-clockwise.
+fn ring_perimeter_area(geoid: &Geodesic, ring: &LineString<f64>) -> (f64, f64) {
+    let mut polygon_area = PolygonArea::new(geoid, Winding::CounterClockwise);
+    // The closing coordinate is implied.
+    let coords = match ring.0.split_last() {
+        Some((_, rest)) if ring.is_closed() => rest,
+        _ => &ring.0[..],
+    };
+    for coord in coords {
+        polygon_area.add_point(coord.y, coord.x);
+    }
+    let (perimeter, area, _) = polygon_area.compute(true);
+    (perimeter, area)
+}
+
+impl GeodesicArea<f64> for Polygon<f64> {
+    fn geodesic_perimeter(&self) -> f64 {
+        self.geodesic_perimeter_area_signed().0
+    }
+
+    fn geodesic_area_signed(&self) -> f64 {
+        self.geodesic_perimeter_area_signed().1
+    }
+
+    fn geodesic_area_unsigned(&self) -> f64 {
+        self.geodesic_perimeter_area_unsigned().1
+    }
+
+    fn geodesic_perimeter_area_signed(&self) -> (f64, f64) {
+        let geoid = Geodesic::wgs84();
+        let (mut perimeter, exterior_area) = ring_perimeter_area(&geoid, self.exterior());
+        let mut area = exterior_area.abs();
+        for interior in self.interiors() {
+            let (interior_perimeter, interior_area) = ring_perimeter_area(&geoid, interior);
+            perimeter += interior_perimeter;
+            area -= interior_area.abs();
+        }
+        (perimeter, area.copysign(exterior_area))
+    }
+
+    fn geodesic_perimeter_area_unsigned(&self) -> (f64, f64) {
+        let (perimeter, area) = self.geodesic_perimeter_area_signed();
+        (perimeter, area.abs())
+    }
+}
+
+impl GeodesicArea<f64> for MultiPolygon<f64> {
+    fn geodesic_perimeter(&self) -> f64 {
+        self.iter()
+            .map(|polygon| polygon.geodesic_perimeter())
+            .sum()
+    }
+
+    fn geodesic_area_signed(&self) -> f64 {
+        self.iter()
+            .map(|polygon| polygon.geodesic_area_signed())
+            .sum()
+    }
+
+    fn geodesic_area_unsigned(&self) -> f64 {
+        self.iter()
+            .map(|polygon| polygon.geodesic_area_unsigned())
+            .sum()
+    }
+
+    fn geodesic_perimeter_area_signed(&self) -> (f64, f64) {
+        self.iter().fold((0., 0.), |(perimeter, area), polygon| {
+            let (p, a) = polygon.geodesic_perimeter_area_signed();
+            (perimeter + p, area + a)
+        })
+    }
+
+    fn geodesic_perimeter_area_unsigned(&self) -> (f64, f64) {
+        self.iter().fold((0., 0.), |(perimeter, area), polygon| {
+            let (p, a) = polygon.geodesic_perimeter_area_unsigned();
+            (perimeter + p, area + a)
+        })
+    }
+}
+
+macro_rules! to_polygon_impl {
+    ($type:ident) => {
+        impl GeodesicArea<f64> for $type<f64> {
+            fn geodesic_perimeter(&self) -> f64 {
+                self.to_polygon().geodesic_perimeter()
+            }
+
+            fn geodesic_area_signed(&self) -> f64 {
+                self.to_polygon().geodesic_area_signed()
+            }
+
+            fn geodesic_area_unsigned(&self) -> f64 {
+                self.to_polygon().geodesic_area_unsigned()
+            }
+
+            fn geodesic_perimeter_area_signed(&self) -> (f64, f64) {
+                self.to_polygon().geodesic_perimeter_area_signed()
+            }
+
+            fn geodesic_perimeter_area_unsigned(&self) -> (f64, f64) {
+                self.to_polygon().geodesic_perimeter_area_unsigned()
+            }
+        }
+    };
+}
+
+to_polygon_impl!(Rect);
+to_polygon_impl!(Triangle);
+
+impl GeodesicArea<f64> for GeometryCollection<f64> {
+    fn geodesic_perimeter(&self) -> f64 {
+        self.geodesic_perimeter_area_signed().0
+    }
+
+    fn geodesic_area_signed(&self) -> f64 {
+        self.geodesic_perimeter_area_signed().1
+    }
+
+    fn geodesic_area_unsigned(&self) -> f64 {
+        self.geodesic_perimeter_area_unsigned().1
+    }
+
+    fn geodesic_perimeter_area_signed(&self) -> (f64, f64) {
+        self.iter().fold((0., 0.), |(perimeter, area), geometry| {
+            let (p, a) = geometry.geodesic_perimeter_area_signed();
+            (perimeter + p, area + a)
+        })
+    }
+
+    fn geodesic_perimeter_area_unsigned(&self) -> (f64, f64) {
+        self.iter().fold((0., 0.), |(perimeter, area), geometry| {
+            let (p, a) = geometry.geodesic_perimeter_area_unsigned();
+            (perimeter + p, area + a)
+        })
+    }
+}
+
+impl GeodesicArea<f64> for Geometry<f64> {
+    fn geodesic_perimeter(&self) -> f64 {
+        self.geodesic_perimeter_area_signed().0
+    }
+
+    fn geodesic_area_signed(&self) -> f64 {
+        self.geodesic_perimeter_area_signed().1
+    }
+
+    fn geodesic_area_unsigned(&self) -> f64 {
+        self.geodesic_perimeter_area_unsigned().1
+    }
+
+    fn geodesic_perimeter_area_signed(&self) -> (f64, f64) {
+        match self {
+            Geometry::Polygon(g) => g.geodesic_perimeter_area_signed(),
+            Geometry::MultiPolygon(g) => g.geodesic_perimeter_area_signed(),
+            Geometry::Rect(g) => g.geodesic_perimeter_area_signed(),
+            Geometry::Triangle(g) => g.geodesic_perimeter_area_signed(),
+            Geometry::GeometryCollection(g) => g.geodesic_perimeter_area_signed(),
+            Geometry::Point(_)
+            | Geometry::Line(_)
+            | Geometry::LineString(_)
+            | Geometry::MultiPoint(_)
+            | Geometry::MultiLineString(_) => (0., 0.),
+        }
+    }
+
+    fn geodesic_perimeter_area_unsigned(&self) -> (f64, f64) {
+        match self {
+            Geometry::Polygon(g) => g.geodesic_perimeter_area_unsigned(),
+            Geometry::MultiPolygon(g) => g.geodesic_perimeter_area_unsigned(),
+            Geometry::Rect(g) => g.geodesic_perimeter_area_unsigned(),
+            Geometry::Triangle(g) => g.geodesic_perimeter_area_unsigned(),
+            Geometry::GeometryCollection(g) => g.geodesic_perimeter_area_unsigned(),
+            Geometry::Point(_)
+            | Geometry::Line(_)
+            | Geometry::LineString(_)
+            | Geometry::MultiPoint(_)
+            | Geometry::MultiLineString(_) => (0., 0.),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::chamberlain_duquette_area::ChamberlainDuquetteArea;
+    use crate::{point, polygon, Coordinate};
+
+    #[test]
+    fn orientation() {
+        let ccw = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)];
+        let mut cw = ccw.clone();
+        cw.exterior_mut(|ring| ring.0.reverse());
+
+        assert!(ccw.geodesic_area_signed() > 0.);
+        assert_relative_eq!(ccw.geodesic_area_signed(), -cw.geodesic_area_signed());
+        assert_relative_eq!(ccw.geodesic_area_unsigned(), cw.geodesic_area_unsigned());
+        assert_relative_eq!(ccw.geodesic_perimeter(), cw.geodesic_perimeter());
+    }
+
+    #[test]
+    fn close_to_spherical_approximation() {
+        // the O2 in London
+        let polygon = polygon![
+            (x: 0.00388383, y: 51.501574),
+            (x: 0.00538587, y: 51.502278),
+            (x: 0.00553607, y: 51.503299),
+            (x: 0.00467777, y: 51.504181),
+            (x: 0.00327229, y: 51.504435),
+            (x: 0.00187754, y: 51.504168),
+            (x: 0.00087976, y: 51.503380),
+            (x: 0.00107303, y: 51.502150),
+            (x: 0.00246132, y: 51.501596),
+            (x: 0.00388383, y: 51.501574),
+        ];
+        let area = polygon.geodesic_area_unsigned();
+        let spherical = polygon.chamberlain_duquette_unsigned_area();
+        assert!((area - spherical).abs() / area < 0.01);
+    }
+
+    #[test]
+    fn holes() {
+        let hole = polygon![(x: 0.25, y: 0.25), (x: 0.75, y: 0.25), (x: 0.75, y: 0.75), (x: 0.25, y: 0.75)];
+        let square = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)];
+        let polygon = Polygon::new(square.exterior().clone(), vec![hole.exterior().clone()]);
+
+        let (perimeter, area) = polygon.geodesic_perimeter_area_unsigned();
+        assert_relative_eq!(
+            area,
+            square.geodesic_area_unsigned() - hole.geodesic_area_unsigned(),
+            epsilon = 1e-3
+        );
+        assert_relative_eq!(
+            perimeter,
+            square.geodesic_perimeter() + hole.geodesic_perimeter(),
+            epsilon = 1e-6
+        );
+    }
+
+    #[test]
+    fn other_geometries() {
+        let square = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)];
+        let rect = Rect::new(Coordinate { x: 0., y: 0. }, Coordinate { x: 1., y: 1. });
+        assert_relative_eq!(
+            rect.geodesic_area_unsigned(),
+            square.geodesic_area_unsigned()
+        );
+
+        let mp = MultiPolygon(vec![square.clone(), square.clone()]);
+        assert_relative_eq!(
+            mp.geodesic_area_signed(),
+            2. * square.geodesic_area_signed()
+        );
+
+        let collection = Geometry::GeometryCollection(GeometryCollection(vec![
+            Geometry::Polygon(square.clone()),
+            Geometry::Point(point!(x: 1., y: 1.)),
+        ]));
+        assert_relative_eq!(
+            collection.geodesic_perimeter_area_signed().1,
+            square.geodesic_area_signed()
+        );
+        assert_eq!(
+            Geometry::Point(point!(x: 1., y: 1.)).geodesic_perimeter(),
+            0.
+        );
+    }
+}
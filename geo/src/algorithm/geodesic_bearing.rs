@@ -0,0 +1,98 @@
+use crate::{CoordNum, Point};
+use geographiclib_rs::{Geodesic, InverseGeodesic};
+
+/// Returns the bearing to another Point in degrees, on an ellipsoidal model of the earth.
+///
+/// This uses the geodesic methods given by [Karney (2013)], on the WGS84 ellipsoid.
+///
+/// [Karney (2013)]:  https://arxiv.org/pdf/1109.4448.pdf
+pub trait GeodesicBearing<T: CoordNum> {
+    /// Returns the bearing to another Point in degrees, where North is 0° and East is 90°.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// #
+    /// use geo::algorithm::geodesic_bearing::GeodesicBearing;
+    /// use geo::Point;
+    ///
+    /// let p_1 = Point::<f64>::new(9.177789688110352, 48.776781529534965);
+    /// let p_2 = Point::<f64>::new(9.177789688110352, 49.776781529534965);
+    /// let bearing = p_1.geodesic_bearing(p_2);
+    /// assert_relative_eq!(bearing, 0., epsilon = 1.0e-9);
+    /// ```
+    fn geodesic_bearing(&self, point: Point<T>) -> T;
+
+    /// Returns the distance to another Point in meters, the bearing to it from this Point,
+    /// and the bearing back to this Point from it, both in degrees.
+    ///
+    /// The bearings lie in `(-180, 180]`, where North is 0° and East is 90°.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// #
+    /// use geo::algorithm::geodesic_bearing::GeodesicBearing;
+    /// use geo::Point;
+    ///
+    /// // along the equator
+    /// let p_1 = Point::<f64>::new(0., 0.);
+    /// let p_2 = Point::<f64>::new(1., 0.);
+    /// let (distance, forward, back) = p_1.geodesic_inverse(p_2);
+    /// assert_relative_eq!(distance, 111_319.49079327357, epsilon = 1.0e-6);
+    /// assert_relative_eq!(forward, 90.);
+    /// assert_relative_eq!(back, -90.);
+    /// ```
+    fn geodesic_inverse(&self, point: Point<T>) -> (T, T, T);
+}
+
+impl GeodesicBearing<f64> for Point<f64> {
+    fn geodesic_bearing(&self, point: Point<f64>) -> f64 {
+        let (azi1, _, _) =
+            Geodesic::wgs84().inverse(self.lat(), self.lng(), point.lat(), point.lng());
+        azi1
+    }
+
+    fn geodesic_inverse(&self, point: Point<f64>) -> (f64, f64, f64) {
+        let (distance, azi1, azi2, _) =
+            Geodesic::wgs84().inverse(self.lat(), self.lng(), point.lat(), point.lng());
+        // `azi2` is the direction of travel at `point`, the way back is opposite.
+        let back = if azi2 > 0. { azi2 - 180. } else { azi2 + 180. };
+        (distance, azi1, back)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::geodesic_destination::GeodesicDestination;
+    use crate::algorithm::geodesic_distance::GeodesicDistance;
+
+    #[test]
+    fn returns_the_proper_bearing_to_another_point() {
+        let p_1 = Point::<f64>::new(9.177789688110352, 48.776781529534965);
+        let p_2 = p_1.geodesic_destination(45., 10000.);
+        assert_relative_eq!(p_1.geodesic_bearing(p_2), 45., epsilon = 1.0e-9);
+    }
+
+    #[test]
+    fn inverse_matches_distance_and_bearings() {
+        // New York City and London
+        let p_1 = Point::<f64>::new(-74.006, 40.7128);
+        let p_2 = Point::<f64>::new(-0.1278, 51.5074);
+        let (distance, forward, back) = p_1.geodesic_inverse(p_2);
+        assert_relative_eq!(distance, p_1.geodesic_distance(&p_2));
+        assert_relative_eq!(forward, p_1.geodesic_bearing(p_2));
+        assert_relative_eq!(back, p_2.geodesic_bearing(p_1), epsilon = 1.0e-9);
+    }
+
+    #[test]
+    fn back_bearing_range() {
+        let p_1 = Point::<f64>::new(5., 0.);
+        let (_, forward, back) = p_1.geodesic_inverse(Point::new(5., 10.));
+        assert_relative_eq!(forward, 0.);
+        assert_relative_eq!(back, 180.);
+    }
+}
@@ -0,0 +1,63 @@
+use crate::{CoordNum, Point};
+use geographiclib_rs::{DirectGeodesic, Geodesic};
+
+/// Returns a new Point using the distance to the existing Point and a bearing for the direction,
+/// on an ellipsoidal model of the earth.
+///
+/// This uses the geodesic methods given by [Karney (2013)], on the WGS84 ellipsoid.
+///
+/// [Karney (2013)]:  https://arxiv.org/pdf/1109.4448.pdf
+pub trait GeodesicDestination<T: CoordNum> {
+    /// Returns a new Point using distance to the existing Point and a bearing for the direction
+    ///
+    /// # Units
+    ///
+    /// - `bearing`: degrees, where North is 0° and East is 90°
+    /// - `distance`: meters
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// #
+    /// use geo::algorithm::geodesic_destination::GeodesicDestination;
+    /// use geo::Point;
+    ///
+    /// // 100 km east of a point on the equator
+    /// let p_1 = Point::<f64>::new(0., 0.);
+    /// let p_2 = p_1.geodesic_destination(90., 100_000.);
+    /// assert_relative_eq!(p_2, Point::new(0.8983152841195214, 0.), epsilon = 1.0e-9);
+    /// ```
+    fn geodesic_destination(&self, bearing: T, distance: T) -> Point<T>;
+}
+
+impl GeodesicDestination<f64> for Point<f64> {
+    fn geodesic_destination(&self, bearing: f64, distance: f64) -> Point<f64> {
+        let (lat, lng) = Geodesic::wgs84().direct(self.lat(), self.lng(), bearing, distance);
+        Point::new(lng, lat)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::geodesic_distance::GeodesicDistance;
+
+    #[test]
+    fn returns_a_new_point() {
+        let p_1 = Point::<f64>::new(9.177789688110352, 48.776781529534965);
+        let p_2 = p_1.geodesic_destination(45., 10000.);
+        assert_relative_eq!(p_1.geodesic_distance(&p_2), 10000., epsilon = 1.0e-6);
+    }
+
+    #[test]
+    fn along_a_meridian() {
+        let p_1 = Point::<f64>::new(12., -30.);
+        let p_2 = p_1.geodesic_destination(0., 1_000_000.);
+        assert_relative_eq!(p_2.lng(), 12., epsilon = 1.0e-9);
+        assert!(p_2.lat() > -30.);
+
+        let p_3 = p_1.geodesic_destination(180., 0.);
+        assert_relative_eq!(p_3, p_1, epsilon = 1.0e-9);
+    }
+}
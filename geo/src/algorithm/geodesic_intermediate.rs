@@ -66,17 +66,17 @@ impl GeodesicIntermediate<f64> for Point<f64> {
         }
 
         let number_of_points = (total_distance / max_dist).ceil();
-        let interval = 1.0 / number_of_points;
 
-        let mut current_step = interval;
         let mut points = if include_ends { vec![*self] } else { vec![] };
 
-        while current_step < 1.0 {
+        // Compute each step from its index, as summing the intervals
+        // may fall short of one and add a point next to the end.
+        for i in 1..number_of_points as usize {
+            let current_step = i as f64 / number_of_points;
             let (lat2, lon2) =
                 g.direct(self.lat(), self.lng(), azi1, total_distance * current_step);
             let point = Point::new(lon2, lat2);
             points.push(point);
-            current_step += interval;
         }
 
         if include_ends {
@@ -0,0 +1,288 @@
+use crate::algorithm::coords_iter::CoordsIter;
+use crate::algorithm::euclidean_distance::EuclideanDistance;
+use crate::{
+    Coordinate, GeoFloat, Geometry, GeometryCollection, Line, LineString, MultiLineString,
+    MultiPoint, MultiPolygon, Point, Polygon, Rect, Triangle,
+};
+
+/// Determine the similarity between two geometries using the
+/// discrete [Hausdorff distance]: the greatest distance from a
+/// vertex of one geometry to the closest vertex of the other.
+///
+/// The discrete distance only considers vertices, so it may be
+/// much larger than the actual Hausdorff distance between
+/// geometries with long segments. Densifying the segments
+/// brings it closer to the actual distance.
+///
+/// If either geometry is empty, the distance is zero.
+///
+/// [Hausdorff distance]: https://en.wikipedia.org/wiki/Hausdorff_distance
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::hausdorff_distance::HausdorffDistance;
+/// use geo::{line_string, Line};
+///
+/// let ls = line_string![(x: 0., y: 0.), (x: 1., y: 1.), (x: 2., y: 0.)];
+/// let simplified = Line::new((0., 0.), (2., 0.));
+///
+/// assert_eq!(ls.hausdorff_distance(&simplified), 2f64.sqrt());
+/// // the middle of the line is closer to the top of `ls`
+/// assert_eq!(ls.hausdorff_distance_densified(&simplified, 0.5), 1.);
+/// ```
+pub trait HausdorffDistance<T, Rhs = Self> {
+    /// The discrete Hausdorff distance between the vertices of
+    /// `self` and `rhs`.
+    fn hausdorff_distance(&self, rhs: &Rhs) -> T;
+
+    /// The discrete Hausdorff distance between `self` and `rhs`,
+    /// after splitting their segments into equal parts no longer
+    /// than `densify_fraction` times their length.
+    /// `densify_fraction` must lie in `(0, 1]`; other values leave
+    /// the segments as they are.
+    fn hausdorff_distance_densified(&self, rhs: &Rhs, densify_fraction: T) -> T;
+}
+
+macro_rules! hausdorff_distance_impl {
+    ($($t:ident),* $(,)?) => {
+        hausdorff_distance_impl!(@lhs [$($t),*] [$($t),*]);
+    };
+    (@lhs [$($lhs:ident),*] $rhs:tt) => {
+        $(hausdorff_distance_impl!(@rhs $lhs $rhs);)*
+    };
+    (@rhs $lhs:ident [$($rhs:ident),*]) => {
+        $(
+            impl<T: GeoFloat> HausdorffDistance<T, $rhs<T>> for $lhs<T> {
+                fn hausdorff_distance(&self, rhs: &$rhs<T>) -> T {
+                    let a: Vec<_> = self.coords_iter().collect();
+                    let b: Vec<_> = rhs.coords_iter().collect();
+                    hausdorff_distance(&a, &b)
+                }
+
+                fn hausdorff_distance_densified(&self, rhs: &$rhs<T>, densify_fraction: T) -> T {
+                    let steps = steps(densify_fraction);
+                    let (mut a, mut b) = (vec![], vec![]);
+                    self.push_samples(steps, &mut a);
+                    rhs.push_samples(steps, &mut b);
+                    hausdorff_distance(&a, &b)
+                }
+            }
+        )*
+    };
+}
+
+hausdorff_distance_impl![
+    Point,
+    Line,
+    LineString,
+    Polygon,
+    MultiPoint,
+    MultiLineString,
+    MultiPolygon,
+    Rect,
+    Triangle,
+    GeometryCollection,
+    Geometry,
+];
+
+fn hausdorff_distance<T: GeoFloat>(a: &[Coordinate<T>], b: &[Coordinate<T>]) -> T {
+    if a.is_empty() || b.is_empty() {
+        return T::zero();
+    }
+    directed_distance(a, b).max(directed_distance(b, a))
+}
+
+/// The greatest distance from a coordinate of `a` to the
+/// closest coordinate of `b`.
+fn directed_distance<T: GeoFloat>(a: &[Coordinate<T>], b: &[Coordinate<T>]) -> T {
+    a.iter().fold(T::zero(), |max, p| {
+        let min = b
+            .iter()
+            .fold(T::infinity(), |min, q| min.min(p.euclidean_distance(q)));
+        max.max(min)
+    })
+}
+
+/// The number of parts to split each segment into.
+fn steps<T: GeoFloat>(densify_fraction: T) -> usize {
+    if densify_fraction > T::zero() && densify_fraction <= T::one() {
+        (T::one() / densify_fraction).ceil().to_usize().unwrap_or(1)
+    } else {
+        1
+    }
+}
+
+/// Sample the coordinates of a geometry, adding evenly spaced
+/// coordinates along its segments.
+trait PushSamples<T: GeoFloat> {
+    fn push_samples(&self, steps: usize, samples: &mut Vec<Coordinate<T>>);
+}
+
+fn push_line_string_samples<T: GeoFloat>(
+    line_string: &LineString<T>,
+    steps: usize,
+    samples: &mut Vec<Coordinate<T>>,
+) {
+    let step = T::one() / T::from(steps).unwrap();
+    for line in line_string.lines() {
+        let delta = line.delta();
+        samples.push(line.start);
+        for i in 1..steps {
+            let t = step * T::from(i).unwrap();
+            samples.push(Coordinate {
+                x: line.start.x + delta.x * t,
+                y: line.start.y + delta.y * t,
+            });
+        }
+    }
+    samples.extend(line_string.0.last());
+}
+
+impl<T: GeoFloat> PushSamples<T> for Point<T> {
+    fn push_samples(&self, _: usize, samples: &mut Vec<Coordinate<T>>) {
+        samples.push(self.0);
+    }
+}
+
+impl<T: GeoFloat> PushSamples<T> for Line<T> {
+    fn push_samples(&self, steps: usize, samples: &mut Vec<Coordinate<T>>) {
+        push_line_string_samples(&LineString(vec![self.start, self.end]), steps, samples);
+    }
+}
+
+impl<T: GeoFloat> PushSamples<T> for LineString<T> {
+    fn push_samples(&self, steps: usize, samples: &mut Vec<Coordinate<T>>) {
+        push_line_string_samples(self, steps, samples);
+    }
+}
+
+impl<T: GeoFloat> PushSamples<T> for Polygon<T> {
+    fn push_samples(&self, steps: usize, samples: &mut Vec<Coordinate<T>>) {
+        push_line_string_samples(self.exterior(), steps, samples);
+        for interior in self.interiors() {
+            push_line_string_samples(interior, steps, samples);
+        }
+    }
+}
+
+impl<T: GeoFloat> PushSamples<T> for MultiPoint<T> {
+    fn push_samples(&self, _: usize, samples: &mut Vec<Coordinate<T>>) {
+        samples.extend(self.iter().map(|point| point.0));
+    }
+}
+
+impl<T: GeoFloat> PushSamples<T> for MultiLineString<T> {
+    fn push_samples(&self, steps: usize, samples: &mut Vec<Coordinate<T>>) {
+        for line_string in self {
+            push_line_string_samples(line_string, steps, samples);
+        }
+    }
+}
+
+impl<T: GeoFloat> PushSamples<T> for MultiPolygon<T> {
+    fn push_samples(&self, steps: usize, samples: &mut Vec<Coordinate<T>>) {
+        for polygon in self {
+            polygon.push_samples(steps, samples);
+        }
+    }
+}
+
+impl<T: GeoFloat> PushSamples<T> for Rect<T> {
+    fn push_samples(&self, steps: usize, samples: &mut Vec<Coordinate<T>>) {
+        self.to_polygon().push_samples(steps, samples);
+    }
+}
+
+impl<T: GeoFloat> PushSamples<T> for Triangle<T> {
+    fn push_samples(&self, steps: usize, samples: &mut Vec<Coordinate<T>>) {
+        self.to_polygon().push_samples(steps, samples);
+    }
+}
+
+impl<T: GeoFloat> PushSamples<T> for GeometryCollection<T> {
+    fn push_samples(&self, steps: usize, samples: &mut Vec<Coordinate<T>>) {
+        for geometry in self {
+            geometry.push_samples(steps, samples);
+        }
+    }
+}
+
+impl<T: GeoFloat> PushSamples<T> for Geometry<T> {
+    fn push_samples(&self, steps: usize, samples: &mut Vec<Coordinate<T>>) {
+        match self {
+            Geometry::Point(g) => g.push_samples(steps, samples),
+            Geometry::Line(g) => g.push_samples(steps, samples),
+            Geometry::LineString(g) => g.push_samples(steps, samples),
+            Geometry::Polygon(g) => g.push_samples(steps, samples),
+            Geometry::MultiPoint(g) => g.push_samples(steps, samples),
+            Geometry::MultiLineString(g) => g.push_samples(steps, samples),
+            Geometry::MultiPolygon(g) => g.push_samples(steps, samples),
+            Geometry::GeometryCollection(g) => g.push_samples(steps, samples),
+            Geometry::Rect(g) => g.push_samples(steps, samples),
+            Geometry::Triangle(g) => g.push_samples(steps, samples),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, polygon};
+
+    #[test]
+    fn identical_geometries() {
+        let poly = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 1., y: 2.)];
+        assert_eq!(poly.hausdorff_distance(&poly), 0.);
+        assert_eq!(poly.hausdorff_distance_densified(&poly, 0.1), 0.);
+    }
+
+    #[test]
+    fn is_symmetric() {
+        let a = MultiPoint::from(vec![(0., 0.), (1., 0.)]);
+        let b = point!(x: 0., y: 0.);
+        assert_eq!(a.hausdorff_distance(&b), 1.);
+        assert_eq!(b.hausdorff_distance(&a), 1.);
+    }
+
+    #[test]
+    fn line_strings() {
+        let a = line_string![(x: 0., y: 0.), (x: 100., y: 0.), (x: 200., y: 0.)];
+        let b = line_string![(x: 0., y: 10.), (x: 200., y: 10.)];
+        assert_relative_eq!(a.hausdorff_distance(&b), 100.5, epsilon = 1e-2);
+        assert_relative_eq!(a.hausdorff_distance_densified(&b, 0.5), 2600f64.sqrt());
+        assert_relative_eq!(a.hausdorff_distance_densified(&b, 0.01), 10., epsilon = 0.1);
+    }
+
+    #[test]
+    fn densify_fraction_out_of_range() {
+        let a = Line::new((0., 0.), (10., 0.));
+        let b = point!(x: 5., y: 1.);
+        let expected = a.hausdorff_distance(&b);
+        assert_eq!(a.hausdorff_distance_densified(&b, 0.), expected);
+        assert_eq!(a.hausdorff_distance_densified(&b, 2.), expected);
+        assert_eq!(a.hausdorff_distance_densified(&b, f64::NAN), expected);
+        assert_eq!(a.hausdorff_distance_densified(&b, 0.5), expected);
+        assert_eq!(a.hausdorff_distance_densified(&b, 0.3), 26f64.sqrt());
+    }
+
+    #[test]
+    fn polygon_and_rect() {
+        let poly = polygon![
+            exterior: [(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)],
+            interiors: [[(x: 1., y: 1.), (x: 2., y: 1.), (x: 2., y: 2.), (x: 1., y: 2.)]],
+        ];
+        let rect = Rect::new(Coordinate { x: 0., y: 0. }, Coordinate { x: 4., y: 4. });
+        assert_relative_eq!(poly.hausdorff_distance(&rect), 8f64.sqrt());
+        assert_relative_eq!(
+            Geometry::Rect(rect).hausdorff_distance(&Geometry::Polygon(poly)),
+            8f64.sqrt()
+        );
+    }
+
+    #[test]
+    fn empty_geometry() {
+        let empty = MultiPolygon::<f64>(vec![]);
+        assert_eq!(empty.hausdorff_distance(&point!(x: 1., y: 1.)), 0.);
+    }
+}
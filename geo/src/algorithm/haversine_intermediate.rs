@@ -68,15 +68,15 @@ where
         }
 
         let number_of_points = (total_distance / max_dist).ceil();
-        let interval = T::one() / number_of_points;
 
-        let mut current_step = interval;
         let mut points = if include_ends { vec![*self] } else { vec![] };
 
-        while current_step < T::one() {
+        // Compute each step from its index, as summing the intervals
+        // may fall short of one and add a point next to the end.
+        for i in 1..number_of_points.to_usize().unwrap_or(1) {
+            let current_step = T::from(i).unwrap() / number_of_points;
             let point = get_point(&params, current_step);
             points.push(point);
-            current_step = current_step + interval;
         }
 
         if include_ends {
@@ -1,3 +1,4 @@
+use crate::algorithm::coordinate_position::CoordPos;
 use crate::{CoordNum, Coordinate};
 use num_traits::Zero;
 
@@ -24,6 +25,29 @@ pub trait Kernel<T: CoordNum> {
         }
     }
 
+    /// Gives the position of `d` relative to the circle
+    /// passing through `a`, `b` and `c`, which must be in
+    /// counter-clockwise order: inside, outside or on the
+    /// circle (`OnBoundary`).
+    fn incircle(
+        a: Coordinate<T>,
+        b: Coordinate<T>,
+        c: Coordinate<T>,
+        d: Coordinate<T>,
+    ) -> CoordPos {
+        let (ad, bd, cd) = (a - d, b - d, c - d);
+        let res = (ad.x * ad.x + ad.y * ad.y) * (bd.x * cd.y - cd.x * bd.y)
+            + (bd.x * bd.x + bd.y * bd.y) * (cd.x * ad.y - ad.x * cd.y)
+            + (cd.x * cd.x + cd.y * cd.y) * (ad.x * bd.y - bd.x * ad.y);
+        if res > Zero::zero() {
+            CoordPos::Inside
+        } else if res < Zero::zero() {
+            CoordPos::Outside
+        } else {
+            CoordPos::OnBoundary
+        }
+    }
+
     fn square_euclidean_distance(p: Coordinate<T>, q: Coordinate<T>) -> T {
         (p.x - q.x) * (p.x - q.x) + (p.y - q.y) * (p.y - q.y)
     }
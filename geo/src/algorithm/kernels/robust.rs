@@ -1,4 +1,5 @@
 use super::{CoordNum, Kernel, Orientation};
+use crate::algorithm::coordinate_position::CoordPos;
 use crate::Coordinate;
 
 use num_traits::{Float, NumCast};
@@ -41,4 +42,27 @@ where
             Orientation::Collinear
         }
     }
+
+    fn incircle(
+        a: Coordinate<T>,
+        b: Coordinate<T>,
+        c: Coordinate<T>,
+        d: Coordinate<T>,
+    ) -> CoordPos {
+        use robust::{incircle, Coord};
+
+        let coord = |c: Coordinate<T>| Coord {
+            x: <f64 as NumCast>::from(c.x).unwrap(),
+            y: <f64 as NumCast>::from(c.y).unwrap(),
+        };
+        let res = incircle(coord(a), coord(b), coord(c), coord(d));
+
+        if res > 0. {
+            CoordPos::Inside
+        } else if res < 0. {
+            CoordPos::Outside
+        } else {
+            CoordPos::OnBoundary
+        }
+    }
 }
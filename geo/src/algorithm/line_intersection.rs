@@ -0,0 +1,274 @@
+use crate::kernels::{Kernel, Orientation};
+use crate::utils::lex_cmp;
+use crate::{Coordinate, GeoFloat, Line};
+use std::cmp::Ordering;
+
+/// The ways in which two `Line`s can meet.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LineIntersection<F: GeoFloat> {
+    /// The lines meet in exactly one point.
+    SinglePoint {
+        intersection: Coordinate<F>,
+        /// Whether the point lies in the interior of both lines, rather than
+        /// on an endpoint of either of them.
+        is_proper: bool,
+    },
+    /// The lines are collinear, and share the given (non-degenerate) line.
+    Collinear { intersection: Line<F> },
+}
+
+impl<F: GeoFloat> LineIntersection<F> {
+    /// Whether the lines cross in the interior of both.
+    pub fn is_proper(&self) -> bool {
+        matches!(
+            self,
+            LineIntersection::SinglePoint {
+                is_proper: true,
+                ..
+            }
+        )
+    }
+}
+
+/// Compute where two `Line`s meet, if at all.
+///
+/// The orientation tests are done with the robust predicates of the scalar's
+/// `Kernel`, so touching and collinear configurations are detected exactly.
+/// Whenever the intersection is an endpoint of either line, that endpoint is
+/// returned as is. Only proper crossings have to be computed, and those are
+/// clamped to the bounding boxes of both lines.
+///
+/// A degenerate line, whose start and end are equal, is treated as a point.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::line_intersection::{line_intersection, LineIntersection};
+/// use geo::{Coordinate, Line};
+///
+/// let p = Line::new((0., 0.), (2., 2.));
+/// let q = Line::new((0., 2.), (2., 0.));
+/// assert_eq!(
+///     line_intersection(p, q),
+///     Some(LineIntersection::SinglePoint {
+///         intersection: Coordinate { x: 1., y: 1. },
+///         is_proper: true,
+///     })
+/// );
+///
+/// let q = Line::new((4., 4.), (1., 1.));
+/// assert_eq!(
+///     line_intersection(p, q),
+///     Some(LineIntersection::Collinear {
+///         intersection: Line::new((1., 1.), (2., 2.)),
+///     })
+/// );
+///
+/// let q = Line::new((0., 1.), (1., 2.));
+/// assert_eq!(line_intersection(p, q), None);
+/// ```
+pub fn line_intersection<F: GeoFloat>(p: Line<F>, q: Line<F>) -> Option<LineIntersection<F>> {
+    if !boxes_overlap(p, q) {
+        return None;
+    }
+
+    // Degenerate lines are points.
+    if p.start == p.end {
+        return point_on_line(p.start, q).map(improper);
+    }
+    if q.start == q.end {
+        return point_on_line(q.start, p).map(improper);
+    }
+
+    let p_q1 = F::Ker::orient2d(p.start, p.end, q.start);
+    let p_q2 = F::Ker::orient2d(p.start, p.end, q.end);
+    if p_q1 == p_q2 && p_q1 != Orientation::Collinear {
+        return None;
+    }
+
+    let q_p1 = F::Ker::orient2d(q.start, q.end, p.start);
+    let q_p2 = F::Ker::orient2d(q.start, q.end, p.end);
+    if q_p1 == q_p2 && q_p1 != Orientation::Collinear {
+        return None;
+    }
+
+    if p_q1 == Orientation::Collinear && p_q2 == Orientation::Collinear {
+        return collinear_intersection(p, q);
+    }
+
+    // The lines are not collinear, so they meet in exactly
+    // one point. Prefer an existing endpoint over computing a
+    // new coordinate.
+    if p_q1 == Orientation::Collinear {
+        return Some(improper(q.start));
+    }
+    if p_q2 == Orientation::Collinear {
+        return Some(improper(q.end));
+    }
+    if q_p1 == Orientation::Collinear {
+        return Some(improper(p.start));
+    }
+    if q_p2 == Orientation::Collinear {
+        return Some(improper(p.end));
+    }
+
+    Some(LineIntersection::SinglePoint {
+        intersection: proper_intersection(p, q),
+        is_proper: true,
+    })
+}
+
+fn improper<F: GeoFloat>(intersection: Coordinate<F>) -> LineIntersection<F> {
+    LineIntersection::SinglePoint {
+        intersection,
+        is_proper: false,
+    }
+}
+
+// The lines are known to cross in their interiors.
+fn proper_intersection<F: GeoFloat>(p: Line<F>, q: Line<F>) -> Coordinate<F> {
+    let dp = p.delta();
+    let dq = q.delta();
+    let denom = dp.x * dq.y - dp.y * dq.x;
+    let t = ((q.start.x - p.start.x) * dq.y - (q.start.y - p.start.y) * dq.x) / denom;
+    let crossing = p.start + dp * t;
+
+    // Floating point error may place the computed point
+    // slightly outside of the lines; pull it back into the
+    // region both of them span.
+    let (min_x, max_x) = overlap_range(p.start.x, p.end.x, q.start.x, q.end.x);
+    let (min_y, max_y) = overlap_range(p.start.y, p.end.y, q.start.y, q.end.y);
+    Coordinate {
+        x: crossing.x.max(min_x).min(max_x),
+        y: crossing.y.max(min_y).min(max_y),
+    }
+}
+
+fn collinear_intersection<F: GeoFloat>(p: Line<F>, q: Line<F>) -> Option<LineIntersection<F>> {
+    // Order both lines lexicographically, then the overlap
+    // runs from the greater of the starts to the lesser of the
+    // ends.
+    let (p_min, p_max) = ordered(p);
+    let (q_min, q_max) = ordered(q);
+    let start = if lex_cmp(&p_min, &q_min) == Ordering::Less {
+        q_min
+    } else {
+        p_min
+    };
+    let end = if lex_cmp(&p_max, &q_max) == Ordering::Less {
+        p_max
+    } else {
+        q_max
+    };
+    match lex_cmp(&start, &end) {
+        Ordering::Less => Some(LineIntersection::Collinear {
+            intersection: Line::new(start, end),
+        }),
+        Ordering::Equal => Some(improper(start)),
+        Ordering::Greater => None,
+    }
+}
+
+fn point_on_line<F: GeoFloat>(c: Coordinate<F>, line: Line<F>) -> Option<Coordinate<F>> {
+    use crate::algorithm::intersects::Intersects;
+    if line.intersects(&c) {
+        Some(c)
+    } else {
+        None
+    }
+}
+
+fn ordered<F: GeoFloat>(line: Line<F>) -> (Coordinate<F>, Coordinate<F>) {
+    if lex_cmp(&line.start, &line.end) == Ordering::Greater {
+        (line.end, line.start)
+    } else {
+        (line.start, line.end)
+    }
+}
+
+fn overlap_range<F: GeoFloat>(p1: F, p2: F, q1: F, q2: F) -> (F, F) {
+    (p1.min(p2).max(q1.min(q2)), p1.max(p2).min(q1.max(q2)))
+}
+
+/// Whether the bounding boxes of two lines overlap.
+pub(crate) fn boxes_overlap<F: GeoFloat>(p: Line<F>, q: Line<F>) -> bool {
+    let (min_x, max_x) = overlap_range(p.start.x, p.end.x, q.start.x, q.end.x);
+    let (min_y, max_y) = overlap_range(p.start.y, p.end.y, q.start.y, q.end.y);
+    min_x <= max_x && min_y <= max_y
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn point(x: f64, y: f64, is_proper: bool) -> Option<LineIntersection<f64>> {
+        Some(LineIntersection::SinglePoint {
+            intersection: Coordinate { x, y },
+            is_proper,
+        })
+    }
+
+    #[test]
+    fn crossing_lines() {
+        let p = Line::new((0., 0.), (2., 2.));
+        let q = Line::new((0., 2.), (2., 0.));
+        assert_eq!(line_intersection(p, q), point(1., 1., true));
+        assert!(line_intersection(p, q).unwrap().is_proper());
+    }
+
+    #[test]
+    fn touching_lines() {
+        let p = Line::new((0., 0.), (2., 0.));
+        let q = Line::new((1., 0.), (1., 5.));
+        assert_eq!(line_intersection(p, q), point(1., 0., false));
+        assert_eq!(line_intersection(q, p), point(1., 0., false));
+        let q = Line::new((1., 1e-12), (1., 5.));
+        assert_eq!(line_intersection(p, q), None);
+        // sharing an endpoint
+        let q = Line::new((2., 0.), (3., 3.));
+        assert_eq!(line_intersection(p, q), point(2., 0., false));
+    }
+
+    #[test]
+    fn collinear_lines() {
+        let p = Line::new((0., 0.), (4., 0.));
+        let q = Line::new((6., 0.), (2., 0.));
+        assert_eq!(
+            line_intersection(p, q),
+            Some(LineIntersection::Collinear {
+                intersection: Line::new((2., 0.), (4., 0.))
+            })
+        );
+        let q = Line::new((6., 0.), (4., 0.));
+        assert_eq!(line_intersection(p, q), point(4., 0., false));
+        let q = Line::new((6., 0.), (5., 0.));
+        assert_eq!(line_intersection(p, q), None);
+    }
+
+    #[test]
+    fn degenerate_lines() {
+        let p = Line::new((0., 0.), (4., 4.));
+        let q = Line::new((1., 1.), (1., 1.));
+        assert_eq!(line_intersection(p, q), point(1., 1., false));
+        assert_eq!(line_intersection(q, q), point(1., 1., false));
+        let q = Line::new((1., 2.), (1., 2.));
+        assert_eq!(line_intersection(p, q), None);
+    }
+
+    #[test]
+    fn clamped_crossing() {
+        let p = Line::new((0., 0.), (1., 1e-20));
+        let q = Line::new((0.5, -1.), (0.5, 1.));
+        let intersection = line_intersection(p, q).unwrap();
+        match intersection {
+            LineIntersection::SinglePoint {
+                intersection,
+                is_proper: true,
+            } => {
+                assert_eq!(intersection.x, 0.5);
+                assert!(intersection.y >= 0. && intersection.y <= 1e-20);
+            }
+            _ => panic!("expected a proper crossing, got {:?}", intersection),
+        }
+    }
+}
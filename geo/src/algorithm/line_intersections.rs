@@ -0,0 +1,267 @@
+use crate::algorithm::line_intersection::{line_intersection, LineIntersection};
+use crate::algorithm::noding::for_each_candidate_pair;
+use crate::{GeoFloat, Line, LineString};
+use rstar::RTreeNum;
+
+/// The position of a segment among a set of `LineString`s.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SegmentIndex {
+    /// The index of the `LineString`.
+    pub line_string: usize,
+    /// The index of the segment within the `LineString`.
+    pub segment: usize,
+}
+
+/// Find every pair of `lines` which meet, with where they meet.
+///
+/// The bounding boxes of the segments are stored in an R*-tree, so that
+/// only those which overlap are tested against each other, using
+/// [`line_intersection`]. Segments with a NaN or infinite coordinate meet
+/// nothing. Each pair is returned once, as the indices of both lines in
+/// increasing order, and the pairs are sorted.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::line_intersection::LineIntersection;
+/// use geo::algorithm::line_intersections::line_intersections;
+/// use geo::{Coordinate, Line};
+///
+/// let lines = vec![
+///     Line::new((0., 0.), (2., 2.)),
+///     Line::new((10., 0.), (12., 2.)),
+///     Line::new((0., 2.), (2., 0.)),
+/// ];
+/// assert_eq!(
+///     line_intersections(&lines),
+///     vec![(
+///         0,
+///         2,
+///         LineIntersection::SinglePoint {
+///             intersection: Coordinate { x: 1., y: 1. },
+///             is_proper: true,
+///         }
+///     )]
+/// );
+/// ```
+pub fn line_intersections<T: GeoFloat + RTreeNum>(
+    lines: &[Line<T>],
+) -> Vec<(usize, usize, LineIntersection<T>)> {
+    let mut intersections = vec![];
+    for_each_candidate_pair(lines, |i, j| {
+        if let Some(intersection) = line_intersection(lines[i], lines[j]) {
+            intersections.push((i, j, intersection));
+        }
+    });
+    intersections.sort_by_key(|&(i, j, _)| (i, j));
+    intersections
+}
+
+/// Find every pair of segments of `line_strings` which meet, with where they
+/// meet, including the self-intersections of each `LineString`.
+///
+/// Consecutive segments of a `LineString`, including the last and the first
+/// segments of a closed one, always share a vertex: that vertex is not
+/// reported, but any other intersection of theirs is. As for
+/// [`line_intersections`], segments with a NaN or infinite coordinate
+/// meet nothing. Each pair is returned once, with the lesser `SegmentIndex`
+/// first, and the pairs are sorted.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::line_intersection::LineIntersection;
+/// use geo::algorithm::line_intersections::{line_string_intersections, SegmentIndex};
+/// use geo::{line_string, Coordinate};
+///
+/// let bowtie = line_string![(x: 0., y: 0.), (x: 2., y: 2.), (x: 2., y: 0.), (x: 0., y: 2.)];
+/// let road = line_string![(x: 3., y: 0.), (x: 3., y: 2.)];
+///
+/// assert_eq!(
+///     line_string_intersections(&[bowtie, road]),
+///     vec![(
+///         SegmentIndex { line_string: 0, segment: 0 },
+///         SegmentIndex { line_string: 0, segment: 2 },
+///         LineIntersection::SinglePoint {
+///             intersection: Coordinate { x: 1., y: 1. },
+///             is_proper: true,
+///         }
+///     )]
+/// );
+/// ```
+pub fn line_string_intersections<T: GeoFloat + RTreeNum>(
+    line_strings: &[LineString<T>],
+) -> Vec<(SegmentIndex, SegmentIndex, LineIntersection<T>)> {
+    let mut lines = vec![];
+    let mut indices = vec![];
+    for (line_string, ls) in line_strings.iter().enumerate() {
+        for (segment, line) in ls.lines().enumerate() {
+            lines.push(line);
+            indices.push(SegmentIndex {
+                line_string,
+                segment,
+            });
+        }
+    }
+
+    let mut intersections = vec![];
+    for_each_candidate_pair(&lines, |i, j| {
+        let intersection = match line_intersection(lines[i], lines[j]) {
+            Some(intersection) => intersection,
+            None => return,
+        };
+        let (first, second) = (indices[i].min(indices[j]), indices[i].max(indices[j]));
+        if first.line_string == second.line_string {
+            let ls = &line_strings[first.line_string];
+            let shared = if second.segment == first.segment + 1 {
+                Some(ls[second.segment])
+            } else if first.segment == 0 && second.segment + 2 == ls.0.len() && ls.is_closed() {
+                Some(ls[0])
+            } else {
+                None
+            };
+            if let (
+                Some(shared),
+                LineIntersection::SinglePoint {
+                    intersection: c, ..
+                },
+            ) = (shared, intersection)
+            {
+                if c == shared {
+                    return;
+                }
+            }
+        }
+        intersections.push((first, second, intersection));
+    });
+    intersections.sort_by_key(|&(first, second, _)| (first, second));
+    intersections
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, Coordinate};
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    #[test]
+    fn lines() {
+        let lines = vec![
+            Line::new((0., 0.), (4., 0.)),
+            Line::new((2., -2.), (2., 2.)),
+            Line::new((3., 0.), (6., 0.)),
+            Line::new((4., 0.), (4., 1.)),
+        ];
+        let found: Vec<(usize, usize)> = line_intersections(&lines)
+            .into_iter()
+            .map(|(i, j, _)| (i, j))
+            .collect();
+        assert_eq!(found, vec![(0, 1), (0, 2), (0, 3), (2, 3)]);
+        assert_eq!(
+            line_intersections(&lines)[1].2,
+            LineIntersection::Collinear {
+                intersection: Line::new((3., 0.), (4., 0.))
+            }
+        );
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let mut next = || rng.gen_range(0. ..100.);
+        let lines: Vec<Line<f64>> = (0..200)
+            .map(|_| Line::new((next(), next()), (next(), next())))
+            .collect();
+
+        let mut expected = vec![];
+        for i in 0..lines.len() {
+            for j in i + 1..lines.len() {
+                if let Some(intersection) = line_intersection(lines[i], lines[j]) {
+                    expected.push((i, j, intersection));
+                }
+            }
+        }
+        assert!(!expected.is_empty());
+        assert_eq!(line_intersections(&lines), expected);
+    }
+
+    #[test]
+    fn non_finite() {
+        let lines = vec![
+            Line::new((0., 0.), (2., 2.)),
+            Line::new((f64::NAN, 0.), (2., 0.)),
+            Line::new((0., 2.), (2., 0.)),
+            Line::new((1., f64::INFINITY), (1., 0.)),
+        ];
+        let found: Vec<(usize, usize)> = line_intersections(&lines)
+            .into_iter()
+            .map(|(i, j, _)| (i, j))
+            .collect();
+        assert_eq!(found, vec![(0, 2)]);
+
+        let ls = line_string![
+            (x: 0., y: 0.),
+            (x: f64::NAN, y: 1.),
+            (x: 0., y: 0.),
+            (x: 2., y: 2.),
+            (x: 2., y: 0.),
+            (x: 0., y: 2.)
+        ];
+        let found = line_string_intersections(&[ls]);
+        assert_eq!(found.len(), 1);
+        assert_eq!(
+            found[0].2,
+            LineIntersection::SinglePoint {
+                intersection: Coordinate { x: 1., y: 1. },
+                is_proper: true
+            }
+        );
+    }
+
+    #[test]
+    fn closed_ring() {
+        let ring = line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 0.)];
+        assert_eq!(line_string_intersections(&[ring]), vec![]);
+
+        // the last segment doubles back over the first
+        let ring = line_string![(x: 0., y: 0.), (x: 2., y: 0.), (x: 1., y: 0.)];
+        let found = line_string_intersections(&[ring]);
+        assert_eq!(
+            found,
+            vec![(
+                SegmentIndex {
+                    line_string: 0,
+                    segment: 0
+                },
+                SegmentIndex {
+                    line_string: 0,
+                    segment: 1
+                },
+                LineIntersection::Collinear {
+                    intersection: Line::new((1., 0.), (2., 0.))
+                }
+            )]
+        );
+    }
+
+    #[test]
+    fn between_line_strings() {
+        let a = line_string![(x: 0., y: 0.), (x: 2., y: 0.), (x: 4., y: 0.)];
+        let b = line_string![(x: 2., y: -1.), (x: 2., y: 1.)];
+        let found = line_string_intersections(&[a, b]);
+        // both segments of `a` meet `b` at their shared vertex
+        assert_eq!(found.len(), 2);
+        for (first, second, intersection) in found {
+            assert_eq!(first.line_string, 0);
+            assert_eq!(second.line_string, 1);
+            assert_eq!(
+                intersection,
+                LineIntersection::SinglePoint {
+                    intersection: Coordinate { x: 2., y: 0. },
+                    is_proper: false
+                }
+            );
+        }
+    }
+}
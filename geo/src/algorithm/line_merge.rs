@@ -0,0 +1,243 @@
+use crate::utils::lex_cmp;
+use crate::{Coordinate, GeoFloat, LineString, MultiLineString};
+use std::cmp::Ordering;
+
+/// Join the `LineString`s of a geometry whose ends touch into the longest
+/// possible `LineString`s.
+///
+/// Two `LineString`s are joined where an end of each meets, unless the ends of
+/// other `LineString`s meet there too, so the result has the same nodes as a
+/// routing graph of the input. `LineString`s are reversed as needed to be
+/// joined, but each result keeps the direction of the first of its
+/// `LineString`s in the input. A chain which returns to where it started
+/// gives a closed `LineString`. `LineString`s with fewer than two points are
+/// dropped, and an end with a non-finite coordinate is never joined.
+///
+/// The `LineString`s are only joined at their ends; use [`Node`] first to cut
+/// them where they cross.
+///
+/// [`Node`]: crate::algorithm::node::Node
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::line_merge::LineMerge;
+/// use geo::{line_string, MultiLineString};
+///
+/// let ways = MultiLineString(vec![
+///     line_string![(x: 0., y: 0.), (x: 1., y: 0.)],
+///     line_string![(x: 2., y: 0.), (x: 1., y: 0.)],
+///     line_string![(x: 2., y: 0.), (x: 3., y: 0.)],
+///     // a junction with two more ways
+///     line_string![(x: 3., y: 0.), (x: 4., y: 1.)],
+///     line_string![(x: 3., y: 0.), (x: 4., y: -1.)],
+/// ]);
+///
+/// assert_eq!(
+///     ways.line_merge(),
+///     MultiLineString(vec![
+///         line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 2., y: 0.), (x: 3., y: 0.)],
+///         line_string![(x: 3., y: 0.), (x: 4., y: 1.)],
+///         line_string![(x: 3., y: 0.), (x: 4., y: -1.)],
+///     ])
+/// );
+/// ```
+pub trait LineMerge<T: GeoFloat> {
+    fn line_merge(&self) -> MultiLineString<T>;
+}
+
+impl<T: GeoFloat> LineMerge<T> for MultiLineString<T> {
+    fn line_merge(&self) -> MultiLineString<T> {
+        let line_strings: Vec<&LineString<T>> = self.iter().filter(|ls| ls.0.len() >= 2).collect();
+        let graph = Graph::new(&line_strings);
+
+        let mut visited = vec![false; line_strings.len()];
+        let mut merged = vec![];
+        for seed in 0..line_strings.len() {
+            if visited[seed] {
+                continue;
+            }
+            visited[seed] = true;
+            let mut coords = line_strings[seed].0.clone();
+
+            // Extend the chain forwards from the end of the seed, then
+            // backwards from its start.
+            let mut end = (seed, true);
+            while let Some((next, at_end)) = graph.continuation(end, &visited) {
+                visited[next] = true;
+                let next_coords = &line_strings[next].0;
+                if at_end {
+                    coords.extend(next_coords.iter().rev().skip(1));
+                } else {
+                    coords.extend(next_coords.iter().skip(1));
+                }
+                end = (next, !at_end);
+            }
+            let mut start = (seed, false);
+            let mut prefix: Vec<Coordinate<T>> = vec![];
+            while let Some((next, at_end)) = graph.continuation(start, &visited) {
+                visited[next] = true;
+                let next_coords = &line_strings[next].0;
+                if at_end {
+                    prefix.extend(next_coords.iter().rev().skip(1));
+                } else {
+                    prefix.extend(next_coords.iter().skip(1));
+                }
+                start = (next, !at_end);
+            }
+            prefix.reverse();
+            prefix.extend(coords);
+            merged.push(LineString(prefix));
+        }
+        MultiLineString(merged)
+    }
+}
+
+/// The nodes where the ends of `LineString`s meet.
+struct Graph {
+    /// The node of the start and the end of each `LineString`.
+    nodes: Vec<[usize; 2]>,
+    /// The ends of `LineString`s meeting at each node, as the index of
+    /// the `LineString` and whether it is its end rather than its start.
+    ends: Vec<Vec<(usize, bool)>>,
+}
+
+impl Graph {
+    fn new<T: GeoFloat>(line_strings: &[&LineString<T>]) -> Self {
+        let all_ends: Vec<(Coordinate<T>, usize, bool)> = line_strings
+            .iter()
+            .enumerate()
+            .flat_map(|(i, ls)| vec![(ls[0], i, false), (ls[ls.0.len() - 1], i, true)])
+            .collect();
+        // an end with a non-finite coordinate meets no other end
+        let (mut all_ends, non_finite): (Vec<_>, Vec<_>) = all_ends
+            .into_iter()
+            .partition(|(c, _, _)| c.x.is_finite() && c.y.is_finite());
+        all_ends.sort_by(|a, b| lex_cmp(&a.0, &b.0));
+
+        let mut nodes = vec![[0, 0]; line_strings.len()];
+        let mut ends: Vec<Vec<(usize, bool)>> = vec![];
+        for &(_, i, at_end) in &non_finite {
+            nodes[i][at_end as usize] = ends.len();
+            ends.push(vec![(i, at_end)]);
+        }
+        for (k, &(c, i, at_end)) in all_ends.iter().enumerate() {
+            if k == 0 || lex_cmp(&all_ends[k - 1].0, &c) != Ordering::Equal {
+                ends.push(vec![]);
+            }
+            nodes[i][at_end as usize] = ends.len() - 1;
+            ends.last_mut().unwrap().push((i, at_end));
+        }
+        Graph { nodes, ends }
+    }
+
+    /// The unvisited `LineString` end which continues a chain through
+    /// the given end, if exactly two ends meet there.
+    fn continuation(&self, (i, at_end): (usize, bool), visited: &[bool]) -> Option<(usize, bool)> {
+        match self.ends[self.nodes[i][at_end as usize]].as_slice() {
+            &[a, b] => {
+                let other = if a == (i, at_end) { b } else { a };
+                Some(other).filter(|&(j, _)| !visited[j])
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::node::Node;
+    use crate::line_string;
+
+    #[test]
+    fn chain_in_any_order() {
+        let mls = MultiLineString(vec![
+            line_string![(x: 2., y: 0.), (x: 3., y: 0.)],
+            line_string![(x: 1., y: 0.), (x: 0., y: 0.)],
+            line_string![(x: 2., y: 0.), (x: 1., y: 0.)],
+        ]);
+        assert_eq!(
+            mls.line_merge(),
+            MultiLineString(vec![line_string![
+                (x: 0., y: 0.),
+                (x: 1., y: 0.),
+                (x: 2., y: 0.),
+                (x: 3., y: 0.)
+            ]])
+        );
+    }
+
+    #[test]
+    fn ring() {
+        let mls = MultiLineString(vec![
+            line_string![(x: 0., y: 0.), (x: 1., y: 0.)],
+            line_string![(x: 1., y: 1.), (x: 0., y: 0.)],
+            line_string![(x: 1., y: 0.), (x: 1., y: 1.)],
+        ]);
+        let merged = mls.line_merge();
+        assert_eq!(
+            merged,
+            MultiLineString(vec![line_string![
+                (x: 0., y: 0.),
+                (x: 1., y: 0.),
+                (x: 1., y: 1.),
+                (x: 0., y: 0.)
+            ]])
+        );
+        assert!(merged.0[0].is_closed());
+    }
+
+    #[test]
+    fn junctions_and_degenerate() {
+        let mls = MultiLineString(vec![
+            line_string![(x: 0., y: 0.), (x: 1., y: 0.)],
+            line_string![(x: 1., y: 0.), (x: 2., y: 0.)],
+            line_string![(x: 1., y: 0.), (x: 1., y: 1.)],
+            line_string![(x: 5., y: 5.)],
+            line_string![],
+        ]);
+        assert_eq!(
+            mls.line_merge(),
+            MultiLineString(vec![
+                line_string![(x: 0., y: 0.), (x: 1., y: 0.)],
+                line_string![(x: 1., y: 0.), (x: 2., y: 0.)],
+                line_string![(x: 1., y: 0.), (x: 1., y: 1.)],
+            ])
+        );
+    }
+
+    #[test]
+    fn non_finite_ends_are_not_joined() {
+        let mls = MultiLineString(vec![
+            line_string![(x: 0., y: 0.), (x: f64::NAN, y: 1.)],
+            line_string![(x: f64::NAN, y: 1.), (x: 2., y: 2.)],
+            line_string![(x: 1., y: 0.), (x: 0., y: 0.)],
+        ]);
+        let merged = mls.line_merge();
+        assert_eq!(merged.0.len(), 2);
+        assert_eq!(merged.0[0].0.len(), 3);
+        assert_eq!(merged.0[0].0[0], Coordinate { x: 1., y: 0. });
+        assert!(merged.0[0].0[2].x.is_nan());
+        assert_eq!(merged.0[1].0[1], Coordinate { x: 2., y: 2. });
+    }
+
+    #[test]
+    fn node_then_merge() {
+        // a cross made of two ways, and a way continuing one of its arms
+        let mls = MultiLineString(vec![
+            line_string![(x: 0., y: 0.), (x: 10., y: 0.)],
+            line_string![(x: 5., y: -5.), (x: 5., y: 5.)],
+            line_string![(x: 10., y: 0.), (x: 20., y: 0.)],
+        ]);
+        assert_eq!(
+            mls.node().line_merge(),
+            MultiLineString(vec![
+                line_string![(x: 0., y: 0.), (x: 5., y: 0.)],
+                line_string![(x: 5., y: 0.), (x: 10., y: 0.), (x: 20., y: 0.)],
+                line_string![(x: 5., y: -5.), (x: 5., y: 0.)],
+                line_string![(x: 5., y: 0.), (x: 5., y: 5.)],
+            ])
+        );
+    }
+}
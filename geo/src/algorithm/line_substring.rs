@@ -0,0 +1,343 @@
+use crate::algorithm::euclidean_length::EuclideanLength;
+use crate::algorithm::haversine_intermediate::HaversineIntermediate;
+use crate::algorithm::haversine_length::HaversineLength;
+use crate::{CoordFloat, Coordinate, Line, LineString};
+use num_traits::FromPrimitive;
+use std::iter::Sum;
+
+/// Returns an option of the part of the line between two fractions of its
+/// length.
+///
+/// Fractions below zero or above one are replaced with zero or one. If the
+/// start fraction is greater than the end fraction, the substring is
+/// reversed. If both fractions are equal, the substring has two equal
+/// points.
+///
+/// If either fraction is NaN, or the line has no segments, returns `None`.
+///
+/// # Examples
+///
+/// ```
+/// use geo::line_string;
+/// use geo::algorithm::line_substring::LineSubstring;
+///
+/// let linestring = line_string![(x: -1.0, y: 0.0), (x: 0.0, y: 0.0), (x: 0.0, y: 1.0)];
+///
+/// assert_eq!(
+///     linestring.line_substring(0.25, 0.75),
+///     Some(line_string![(x: -0.5, y: 0.0), (x: 0.0, y: 0.0), (x: 0.0, y: 0.5)])
+/// );
+/// assert_eq!(
+///     linestring.line_substring(0.75, 0.5),
+///     Some(line_string![(x: 0.0, y: 0.5), (x: 0.0, y: 0.0)])
+/// );
+/// ```
+pub trait LineSubstring<F: CoordFloat> {
+    type Output;
+
+    fn line_substring(&self, start_fraction: F, end_fraction: F) -> Self::Output;
+}
+
+/// Returns an option of the part of the line between two Euclidean
+/// distances along it.
+///
+/// Distances below zero or above the length of the line are replaced with
+/// zero or its length. As for [`LineSubstring`], the substring is reversed
+/// if the start distance is greater than the end distance, and `None` is
+/// returned if either distance is NaN or the line has no segments.
+///
+/// # Examples
+///
+/// ```
+/// use geo::line_string;
+/// use geo::algorithm::line_substring::EuclideanLineSubstring;
+///
+/// let linestring = line_string![(x: 0.0, y: 0.0), (x: 10.0, y: 0.0), (x: 10.0, y: 10.0)];
+///
+/// assert_eq!(
+///     linestring.euclidean_line_substring(5.0, 12.0),
+///     Some(line_string![(x: 5.0, y: 0.0), (x: 10.0, y: 0.0), (x: 10.0, y: 2.0)])
+/// );
+/// ```
+pub trait EuclideanLineSubstring<F: CoordFloat> {
+    type Output;
+
+    fn euclidean_line_substring(&self, start_distance: F, end_distance: F) -> Self::Output;
+}
+
+/// Returns an option of the part of the line between two haversine
+/// distances along it, following great circle routes between its vertices.
+///
+/// Distances are in meters, and are replaced with zero or the haversine
+/// length of the line if they lie outside of it. As for [`LineSubstring`],
+/// the substring is reversed if the start distance is greater than the end
+/// distance, and `None` is returned if either distance is NaN or the line
+/// has no segments.
+///
+/// *Note*: this implementation uses a mean earth radius of 6371.088 km, based on the [recommendation of
+/// the IUGG](ftp://athena.fsv.cvut.cz/ZFG/grs80-Moritz.pdf)
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate approx;
+/// #
+/// use geo::{line_string, Line};
+/// use geo::algorithm::haversine_length::HaversineLength;
+/// use geo::algorithm::line_substring::HaversineLineSubstring;
+///
+/// let route = line_string![(x: 0.0, y: 0.0), (x: 10.0, y: 0.0), (x: 10.0, y: 10.0)];
+/// let corner = Line::new((0.0, 0.0), (10.0, 0.0)).haversine_length();
+///
+/// // the 100 kilometers before and after the corner
+/// let substring = route
+///     .haversine_line_substring(corner - 100_000.0, corner + 100_000.0)
+///     .unwrap();
+/// assert_eq!(substring.0.len(), 3);
+/// assert_relative_eq!(substring.haversine_length(), 200_000.0, epsilon = 1.0e-6);
+/// ```
+pub trait HaversineLineSubstring<F: CoordFloat> {
+    type Output;
+
+    fn haversine_line_substring(&self, start_distance: F, end_distance: F) -> Self::Output;
+}
+
+impl<T> LineSubstring<T> for Line<T>
+where
+    T: CoordFloat + Sum,
+{
+    type Output = Option<LineString<T>>;
+
+    fn line_substring(&self, start_fraction: T, end_fraction: T) -> Self::Output {
+        LineString(vec![self.start, self.end]).line_substring(start_fraction, end_fraction)
+    }
+}
+
+impl<T> LineSubstring<T> for LineString<T>
+where
+    T: CoordFloat + Sum,
+{
+    type Output = Option<LineString<T>>;
+
+    fn line_substring(&self, start_fraction: T, end_fraction: T) -> Self::Output {
+        let length = self.euclidean_length();
+        self.euclidean_line_substring(start_fraction * length, end_fraction * length)
+    }
+}
+
+impl<T> EuclideanLineSubstring<T> for Line<T>
+where
+    T: CoordFloat,
+{
+    type Output = Option<LineString<T>>;
+
+    fn euclidean_line_substring(&self, start_distance: T, end_distance: T) -> Self::Output {
+        LineString(vec![self.start, self.end])
+            .euclidean_line_substring(start_distance, end_distance)
+    }
+}
+
+impl<T> EuclideanLineSubstring<T> for LineString<T>
+where
+    T: CoordFloat,
+{
+    type Output = Option<LineString<T>>;
+
+    fn euclidean_line_substring(&self, start_distance: T, end_distance: T) -> Self::Output {
+        substring(
+            self,
+            start_distance,
+            end_distance,
+            |line| line.euclidean_length(),
+            |line, fraction| line.start + (line.end - line.start) * fraction,
+        )
+    }
+}
+
+impl<T> HaversineLineSubstring<T> for Line<T>
+where
+    T: CoordFloat + FromPrimitive,
+{
+    type Output = Option<LineString<T>>;
+
+    fn haversine_line_substring(&self, start_distance: T, end_distance: T) -> Self::Output {
+        LineString(vec![self.start, self.end])
+            .haversine_line_substring(start_distance, end_distance)
+    }
+}
+
+impl<T> HaversineLineSubstring<T> for LineString<T>
+where
+    T: CoordFloat + FromPrimitive,
+{
+    type Output = Option<LineString<T>>;
+
+    fn haversine_line_substring(&self, start_distance: T, end_distance: T) -> Self::Output {
+        substring(
+            self,
+            start_distance,
+            end_distance,
+            |line| line.haversine_length(),
+            |line, fraction| {
+                line.start_point()
+                    .haversine_intermediate(&line.end_point(), fraction)
+                    .0
+            },
+        )
+    }
+}
+
+/// The part of `line_string` between two distances along it, measuring
+/// each segment with `length` and interpolating along it with
+/// `interpolate`.
+fn substring<T, L, I>(
+    line_string: &LineString<T>,
+    start: T,
+    end: T,
+    length: L,
+    interpolate: I,
+) -> Option<LineString<T>>
+where
+    T: CoordFloat,
+    L: Fn(Line<T>) -> T,
+    I: Fn(Line<T>, T) -> Coordinate<T>,
+{
+    if start.is_nan() || end.is_nan() {
+        return None;
+    }
+    let lengths: Vec<T> = line_string.lines().map(&length).collect();
+    if lengths.is_empty() {
+        return None;
+    }
+    let total = lengths
+        .iter()
+        .fold(T::zero(), |total, &length| total + length);
+    let clamp = |distance: T| distance.max(T::zero()).min(total);
+    let (from, to) = (clamp(start.min(end)), clamp(start.max(end)));
+
+    let point_at = |line: Line<T>, length: T, distance: T| {
+        if distance <= T::zero() || length <= T::zero() {
+            line.start
+        } else if distance >= length {
+            line.end
+        } else {
+            interpolate(line, distance / length)
+        }
+    };
+
+    let mut coords: Vec<Coordinate<T>> = Vec::new();
+    let mut push = |coord: Coordinate<T>| {
+        if coords.last() != Some(&coord) {
+            coords.push(coord);
+        }
+    };
+    let mut offset = T::zero();
+    let mut started = false;
+    for (line, length) in line_string.lines().zip(lengths) {
+        let next = offset + length;
+        if !started && from <= next {
+            push(point_at(line, length, from - offset));
+            started = true;
+        }
+        if started {
+            if to <= next {
+                push(point_at(line, length, to - offset));
+                break;
+            }
+            push(line.end);
+        }
+        offset = next;
+    }
+
+    if coords.len() == 1 {
+        coords.push(coords[0]);
+    }
+    if start > end {
+        coords.reverse();
+    }
+    Some(coords.into())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::line_string;
+
+    #[test]
+    fn line() {
+        let line = Line::new((0., 0.), (4., 0.));
+        assert_eq!(
+            line.line_substring(0.25, 0.5),
+            Some(line_string![(x: 1., y: 0.), (x: 2., y: 0.)])
+        );
+        assert_eq!(
+            line.euclidean_line_substring(-1., 10.),
+            Some(line_string![(x: 0., y: 0.), (x: 4., y: 0.)])
+        );
+        assert_eq!(
+            line.euclidean_line_substring(3., 1.),
+            Some(line_string![(x: 3., y: 0.), (x: 1., y: 0.)])
+        );
+    }
+
+    #[test]
+    fn vertices() {
+        let ls = line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 2., y: 1.)];
+        // starting and ending on vertices
+        assert_eq!(
+            ls.euclidean_line_substring(1., 2.),
+            Some(line_string![(x: 1., y: 0.), (x: 1., y: 1.)])
+        );
+        assert_eq!(ls.line_substring(0., 1.), Some(ls.clone()));
+        // a single point
+        assert_eq!(
+            ls.euclidean_line_substring(1.5, 1.5),
+            Some(line_string![(x: 1., y: 0.5), (x: 1., y: 0.5)])
+        );
+        assert_eq!(
+            ls.line_substring(2., 3.),
+            Some(line_string![(x: 2., y: 1.), (x: 2., y: 1.)])
+        );
+    }
+
+    #[test]
+    fn repeated_points() {
+        let ls = line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 0.), (x: 2., y: 0.)];
+        assert_eq!(
+            ls.euclidean_line_substring(0.5, 1.5),
+            Some(line_string![(x: 0.5, y: 0.), (x: 1., y: 0.), (x: 1.5, y: 0.)])
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        let empty: LineString<f64> = line_string![];
+        assert_eq!(empty.line_substring(0., 1.), None);
+        let point = line_string![(x: 1., y: 1.)];
+        assert_eq!(point.euclidean_line_substring(0., 1.), None);
+        let ls = line_string![(x: 0., y: 0.), (x: 1., y: 0.)];
+        assert_eq!(ls.line_substring(f64::NAN, 1.), None);
+        assert_eq!(ls.haversine_line_substring(0., f64::NAN), None);
+    }
+
+    #[test]
+    fn haversine() {
+        let ls = line_string![(x: 0., y: 0.), (x: 0., y: 10.), (x: 10., y: 10.)];
+        let first = Line::new((0., 0.), (0., 10.)).haversine_length();
+        let substring = ls.haversine_line_substring(first / 2., first).unwrap();
+        assert_eq!(substring.0.len(), 2);
+        assert_relative_eq!(substring[0], Coordinate { x: 0., y: 5. }, epsilon = 1e-9);
+        assert_eq!(substring[1], Coordinate { x: 0., y: 10. });
+
+        let substring = ls
+            .haversine_line_substring(first + 1_000., f64::INFINITY)
+            .unwrap();
+        assert_eq!(substring.0.len(), 2);
+        assert_eq!(substring[1], Coordinate { x: 10., y: 10. });
+        assert_relative_eq!(
+            substring.haversine_length(),
+            ls.haversine_length() - first - 1_000.,
+            epsilon = 1e-6
+        );
+    }
+}
@@ -0,0 +1,391 @@
+use crate::algorithm::bool_ops::{cascade, BooleanOps, OpType};
+use crate::algorithm::noding::node_segments;
+use crate::utils::lex_cmp;
+use crate::{
+    Coordinate, GeoFloat, Geometry, GeometryCollection, LineString, MultiLineString, MultiPoint,
+    MultiPolygon, Point, Polygon,
+};
+use rstar::RTreeNum;
+
+/// Repair invalid geometries, keeping the area they cover.
+///
+/// Rings are read with the even-odd rule: where a ring crosses
+/// or touches itself, it is split into simple loops, and the
+/// areas covered by an odd number of loops are kept. Zero-area
+/// spikes and cut lines therefore disappear. Holes are then cut
+/// from the exterior, so parts of holes lying outside of it are
+/// ignored, and overlapping polygons of a `MultiPolygon` are
+/// merged. Non-finite and repeated coordinates are removed
+/// first.
+///
+/// The result passes [`Validation`](crate::algorithm::validation::Validation).
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::area::Area;
+/// use geo::algorithm::make_valid::MakeValid;
+/// use geo::algorithm::validation::Validation;
+/// use geo::polygon;
+///
+/// let bow_tie = polygon![(x: 0., y: 0.), (x: 2., y: 2.), (x: 2., y: 0.), (x: 0., y: 2.)];
+/// assert!(!bow_tie.is_valid());
+///
+/// let repaired = bow_tie.make_valid();
+/// assert!(repaired.is_valid());
+/// assert_eq!(repaired.0.len(), 2);
+/// assert_eq!(repaired.unsigned_area(), 2.);
+/// ```
+pub trait MakeValid {
+    type Output;
+
+    fn make_valid(&self) -> Self::Output;
+}
+
+impl<T: GeoFloat + RTreeNum> MakeValid for Polygon<T> {
+    type Output = MultiPolygon<T>;
+
+    fn make_valid(&self) -> MultiPolygon<T> {
+        let exterior = ring_area(self.exterior());
+        if self.interiors().is_empty() {
+            return exterior;
+        }
+        let holes = cascade(
+            self.interiors().iter().map(ring_area).collect(),
+            OpType::Union,
+        );
+        exterior.difference(&holes)
+    }
+}
+
+impl<T: GeoFloat + RTreeNum> MakeValid for MultiPolygon<T> {
+    type Output = MultiPolygon<T>;
+
+    fn make_valid(&self) -> MultiPolygon<T> {
+        cascade(
+            self.iter().map(|polygon| polygon.make_valid()).collect(),
+            OpType::Union,
+        )
+    }
+}
+
+impl<T: GeoFloat + RTreeNum> MakeValid for Geometry<T> {
+    type Output = Geometry<T>;
+
+    /// Polygonal geometries become a `MultiPolygon`. Lines
+    /// collapsing to a single point become a `Point`.
+    fn make_valid(&self) -> Geometry<T> {
+        match self {
+            Geometry::Point(p) => {
+                if is_finite(&p.0) {
+                    Geometry::Point(*p)
+                } else {
+                    Geometry::MultiPoint(MultiPoint(vec![]))
+                }
+            }
+            Geometry::MultiPoint(mp) => {
+                Geometry::MultiPoint(mp.iter().filter(|p| is_finite(&p.0)).copied().collect())
+            }
+            Geometry::Line(line) => {
+                let coords = clean_coords(&[line.start, line.end]);
+                match coords.len() {
+                    2 => Geometry::Line(*line),
+                    _ => collapsed_line(coords),
+                }
+            }
+            Geometry::LineString(ls) => {
+                let coords = clean_coords(&ls.0);
+                match coords.len() {
+                    0 if ls.0.is_empty() => Geometry::LineString(LineString(vec![])),
+                    0 | 1 => collapsed_line(coords),
+                    _ => Geometry::LineString(LineString(coords)),
+                }
+            }
+            Geometry::MultiLineString(mls) => Geometry::MultiLineString(MultiLineString(
+                mls.iter()
+                    .map(|ls| LineString(clean_coords(&ls.0)))
+                    .filter(|ls| ls.0.len() > 1)
+                    .collect(),
+            )),
+            Geometry::Polygon(polygon) => Geometry::MultiPolygon(polygon.make_valid()),
+            Geometry::MultiPolygon(mp) => Geometry::MultiPolygon(mp.make_valid()),
+            Geometry::Rect(rect) => Geometry::MultiPolygon(rect.to_polygon().make_valid()),
+            Geometry::Triangle(triangle) => {
+                Geometry::MultiPolygon(triangle.to_polygon().make_valid())
+            }
+            Geometry::GeometryCollection(gc) => Geometry::GeometryCollection(GeometryCollection(
+                gc.iter().map(|geometry| geometry.make_valid()).collect(),
+            )),
+        }
+    }
+}
+
+fn collapsed_line<T: GeoFloat>(coords: Vec<Coordinate<T>>) -> Geometry<T> {
+    match coords.first() {
+        Some(c) => Geometry::Point(Point(*c)),
+        None => Geometry::MultiPoint(MultiPoint(vec![])),
+    }
+}
+
+fn is_finite<T: GeoFloat>(coord: &Coordinate<T>) -> bool {
+    coord.x.is_finite() && coord.y.is_finite()
+}
+
+// Drop non-finite coordinates, and those repeating the one
+// before them.
+fn clean_coords<T: GeoFloat>(coords: &[Coordinate<T>]) -> Vec<Coordinate<T>> {
+    let mut cleaned: Vec<Coordinate<T>> = coords.iter().copied().filter(is_finite).collect();
+    cleaned.dedup();
+    cleaned
+}
+
+// The area enclosed by a ring, by the even-odd rule.
+fn ring_area<T: GeoFloat + RTreeNum>(ring: &LineString<T>) -> MultiPolygon<T> {
+    let mut coords = clean_coords(&ring.0);
+    if coords.first() != coords.last() {
+        coords.push(coords[0]);
+    }
+    if coords.len() < 4 {
+        return MultiPolygon(vec![]);
+    }
+
+    // Split the ring wherever it meets itself, so that it only
+    // touches itself at its vertices.
+    let segments: Vec<_> = LineString(coords).lines().map(|line| (line, ())).collect();
+    let mut noded = node_segments(&segments).into_iter().map(|(line, _)| line);
+    let first = match noded.next() {
+        Some(line) => line,
+        None => return MultiPolygon(vec![]),
+    };
+    let coords: Vec<Coordinate<T>> = std::iter::once(first.start)
+        .chain(std::iter::once(first.end))
+        .chain(noded.map(|line| line.end))
+        .collect();
+
+    cascade(
+        simple_loops(&coords)
+            .into_iter()
+            .map(|ring| MultiPolygon(vec![Polygon::new(ring, vec![])]))
+            .collect(),
+        OpType::Xor,
+    )
+}
+
+// Split a closed sequence of coordinates into loops which do not
+// repeat any coordinate, cutting out a loop each time the walk
+// along the sequence returns to a coordinate it has visited.
+fn simple_loops<T: GeoFloat>(coords: &[Coordinate<T>]) -> Vec<LineString<T>> {
+    let mut distinct = coords.to_vec();
+    distinct.sort_by(lex_cmp);
+    distinct.dedup();
+    let id = |c: &Coordinate<T>| distinct.binary_search_by(|d| lex_cmp(d, c)).unwrap();
+
+    let mut loops = vec![];
+    let mut stack: Vec<Coordinate<T>> = vec![];
+    let mut position_in_stack = vec![None; distinct.len()];
+    for coord in coords {
+        match position_in_stack[id(coord)] {
+            Some(position) => {
+                let mut ring: Vec<Coordinate<T>> = stack.drain(position + 1..).collect();
+                for c in &ring {
+                    position_in_stack[id(c)] = None;
+                }
+                ring.insert(0, *coord);
+                ring.push(*coord);
+                // Loops of two segments are spikes, without area.
+                if ring.len() > 3 {
+                    loops.push(LineString(ring));
+                }
+            }
+            None => {
+                position_in_stack[id(coord)] = Some(stack.len());
+                stack.push(*coord);
+            }
+        }
+    }
+    loops
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::area::Area;
+    use crate::algorithm::validation::Validation;
+    use crate::{line_string, polygon};
+
+    #[test]
+    fn valid_polygons_are_kept() {
+        let polygon = polygon![
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [[(x: 2., y: 2.), (x: 4., y: 2.), (x: 4., y: 4.), (x: 2., y: 4.)]],
+        ];
+        let repaired = polygon.make_valid();
+        assert_eq!(repaired.0.len(), 1);
+        assert_eq!(repaired.0[0].interiors().len(), 1);
+        assert_eq!(repaired.unsigned_area(), 96.);
+    }
+
+    #[test]
+    fn bow_tie() {
+        let bow_tie = polygon![(x: 0., y: 0.), (x: 4., y: 4.), (x: 4., y: 0.), (x: 0., y: 4.)];
+        let repaired = bow_tie.make_valid();
+        assert!(repaired.is_valid());
+        assert_eq!(repaired.0.len(), 2);
+        assert_eq!(repaired.unsigned_area(), 8.);
+    }
+
+    #[test]
+    fn self_touching_ring() {
+        // a ring touching itself is split into separate loops
+        let ring = polygon![
+            (x: 0., y: 0.),
+            (x: 6., y: 0.),
+            (x: 6., y: 6.),
+            (x: 3., y: 0.),
+            (x: 2., y: 4.),
+            (x: 4., y: 4.),
+            (x: 3., y: 0.),
+            (x: 0., y: 6.),
+        ];
+        let repaired = ring.make_valid();
+        assert!(repaired.is_valid());
+        assert_eq!(repaired.0.len(), 3);
+        assert_eq!(repaired.unsigned_area(), 9. + 4. + 9.);
+
+        // an exterior reaching in along a cut line encloses a hole
+        let ring = polygon![
+            (x: 0., y: 0.),
+            (x: 6., y: 0.),
+            (x: 6., y: 6.),
+            (x: 0., y: 6.),
+            (x: 0., y: 3.),
+            (x: 2., y: 3.),
+            (x: 3., y: 4.),
+            (x: 4., y: 3.),
+            (x: 2., y: 3.),
+            (x: 0., y: 3.),
+        ];
+        let repaired = ring.make_valid();
+        assert!(repaired.is_valid());
+        assert_eq!(repaired.0.len(), 1);
+        assert_eq!(repaired.0[0].interiors().len(), 1);
+        assert_eq!(repaired.unsigned_area(), 36. - 1.);
+    }
+
+    #[test]
+    fn spikes_are_removed() {
+        let spiky = polygon![
+            (x: 0., y: 0.),
+            (x: 4., y: 0.),
+            (x: 6., y: 0.),
+            (x: 4., y: 0.),
+            (x: 4., y: 4.),
+            (x: 2., y: 4.),
+            (x: 2., y: 6.),
+            (x: 2., y: 4.),
+            (x: 0., y: 4.),
+        ];
+        let repaired = spiky.make_valid();
+        assert!(repaired.is_valid());
+        assert_eq!(repaired.0.len(), 1);
+        assert_eq!(repaired.0[0].exterior().0.len(), 6);
+        assert_eq!(repaired.unsigned_area(), 16.);
+    }
+
+    #[test]
+    fn repeated_and_non_finite_points() {
+        let polygon = polygon![
+            (x: 0., y: 0.),
+            (x: 2., y: 0.),
+            (x: 2., y: 0.),
+            (x: f64::NAN, y: 1.),
+            (x: 2., y: 2.),
+            (x: 0., y: 2.),
+        ];
+        let repaired = polygon.make_valid();
+        assert!(repaired.is_valid());
+        assert_eq!(repaired.unsigned_area(), 4.);
+
+        // collapsed rings disappear
+        let polygon = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 0.)];
+        assert!(polygon.make_valid().0.is_empty());
+    }
+
+    #[test]
+    fn holes() {
+        // a hole partly outside of the exterior
+        let polygon = polygon![
+            exterior: [(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)],
+            interiors: [[(x: 2., y: 2.), (x: 6., y: 2.), (x: 6., y: 6.), (x: 2., y: 6.)]],
+        ];
+        let repaired = polygon.make_valid();
+        assert!(repaired.is_valid());
+        assert_eq!(repaired.unsigned_area(), 12.);
+
+        // a hole completely outside of the exterior
+        let polygon = polygon![
+            exterior: [(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)],
+            interiors: [[(x: 8., y: 8.), (x: 9., y: 8.), (x: 9., y: 9.), (x: 8., y: 9.)]],
+        ];
+        assert_eq!(polygon.make_valid().unsigned_area(), 16.);
+
+        // nested and overlapping holes
+        let polygon = polygon![
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [
+                [(x: 1., y: 1.), (x: 7., y: 1.), (x: 7., y: 7.), (x: 1., y: 7.)],
+                [(x: 2., y: 2.), (x: 4., y: 2.), (x: 4., y: 4.), (x: 2., y: 4.)],
+                [(x: 6., y: 6.), (x: 8., y: 6.), (x: 8., y: 8.), (x: 6., y: 8.)],
+            ],
+        ];
+        let repaired = polygon.make_valid();
+        assert!(repaired.is_valid());
+        assert_eq!(repaired.unsigned_area(), 100. - 36. - 3.);
+
+        // a hole touching the exterior in a single point
+        let polygon = polygon![
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [[(x: 0., y: 5.), (x: 3., y: 4.), (x: 3., y: 6.)]],
+        ];
+        assert!(polygon.is_valid());
+        let repaired = polygon.make_valid();
+        assert!(repaired.is_valid());
+        assert_eq!(repaired.0.len(), 1);
+        assert_eq!(repaired.0[0].interiors().len(), 1);
+        assert_eq!(repaired.unsigned_area(), 100. - 3.);
+    }
+
+    #[test]
+    fn overlapping_multi_polygon() {
+        let mp = MultiPolygon(vec![
+            polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)],
+            polygon![(x: 1., y: 1.), (x: 3., y: 1.), (x: 3., y: 3.), (x: 1., y: 3.)],
+            polygon![(x: 2., y: 0.), (x: 3., y: 0.), (x: 3., y: 1.), (x: 2., y: 1.)],
+        ]);
+        assert!(!mp.is_valid());
+        let repaired = mp.make_valid();
+        assert!(repaired.is_valid());
+        assert_eq!(repaired.0.len(), 1);
+        assert_eq!(repaired.unsigned_area(), 8.);
+    }
+
+    #[test]
+    fn geometries() {
+        let geometry = Geometry::LineString(line_string![
+            (x: 1., y: 1.),
+            (x: 1., y: 1.),
+            (x: f64::INFINITY, y: 1.),
+        ]);
+        assert_eq!(geometry.make_valid(), Geometry::Point(Point::new(1., 1.)));
+
+        let geometry = Geometry::GeometryCollection(GeometryCollection(vec![
+            Geometry::Polygon(
+                polygon![(x: 0., y: 0.), (x: 2., y: 2.), (x: 2., y: 0.), (x: 0., y: 2.)],
+            ),
+            Geometry::Point(Point::new(f64::NAN, 0.)),
+        ]));
+        let repaired = geometry.make_valid();
+        assert!(repaired.is_valid());
+        assert_eq!(repaired.unsigned_area(), 2.);
+    }
+}
@@ -0,0 +1,220 @@
+use crate::algorithm::convex_hull::quick_hull;
+use crate::algorithm::coords_iter::CoordsIter;
+use crate::algorithm::euclidean_distance::EuclideanDistance;
+use crate::{Coordinate, GeoFloat};
+
+/// A circle, as returned by
+/// [`MinimumBoundingCircle`](MinimumBoundingCircle).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Circle<T: GeoFloat> {
+    pub center: Coordinate<T>,
+    pub radius: T,
+}
+
+impl<T: GeoFloat> Circle<T> {
+    /// Whether `coord` lies in the circle, allowing for
+    /// rounding errors.
+    fn covers(&self, coord: Coordinate<T>) -> bool {
+        let tolerance = T::epsilon().sqrt() * self.radius.max(T::one());
+        self.center.euclidean_distance(&coord) <= self.radius + tolerance
+    }
+
+    fn from_diameter(a: Coordinate<T>, b: Coordinate<T>) -> Self {
+        let two = T::one() + T::one();
+        let center = Coordinate {
+            x: (a.x + b.x) / two,
+            y: (a.y + b.y) / two,
+        };
+        Circle {
+            center,
+            radius: center.euclidean_distance(&a),
+        }
+    }
+
+    fn circumcircle(a: Coordinate<T>, b: Coordinate<T>, c: Coordinate<T>) -> Self {
+        let (b, c) = (b - a, c - a);
+        let two = T::one() + T::one();
+        let det = two * (b.x * c.y - b.y * c.x);
+        if det == T::zero() {
+            // collinear: the farthest two points are a diameter
+            let (ab, ac, bc) = (b.x.hypot(b.y), c.x.hypot(c.y), (c.x - b.x).hypot(c.y - b.y));
+            return if ab >= ac && ab >= bc {
+                Self::from_diameter(a, a + b)
+            } else if ac >= bc {
+                Self::from_diameter(a, a + c)
+            } else {
+                Self::from_diameter(a + b, a + c)
+            };
+        }
+        let (b2, c2) = (b.x * b.x + b.y * b.y, c.x * c.x + c.y * c.y);
+        let offset = Coordinate {
+            x: (c.y * b2 - b.y * c2) / det,
+            y: (b.x * c2 - c.x * b2) / det,
+        };
+        Circle {
+            center: a + offset,
+            radius: offset.x.hypot(offset.y),
+        }
+    }
+}
+
+/// Return the smallest circle enclosing a geometry, or `None`
+/// if it has no coordinates.
+///
+/// The circle is computed with [Welzl's algorithm], over the
+/// vertices of the convex hull of the geometry. The vertices
+/// are shuffled first, as the algorithm takes quadratic time
+/// or worse over points in order around the hull; the shuffle
+/// is seeded, so the result is the same on every call.
+///
+/// [Welzl's algorithm]: https://en.wikipedia.org/wiki/Smallest-circle_problem#Welzl's_algorithm
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::minimum_bounding_circle::MinimumBoundingCircle;
+/// use geo::{line_string, Coordinate};
+///
+/// let ls = line_string![(x: 0., y: 0.), (x: 1., y: 1.), (x: 4., y: 0.)];
+///
+/// let circle = ls.minimum_bounding_circle().unwrap();
+/// assert_eq!(circle.center, Coordinate { x: 2., y: 0. });
+/// assert_eq!(circle.radius, 2.);
+/// ```
+pub trait MinimumBoundingCircle<'a, T: GeoFloat> {
+    fn minimum_bounding_circle(&'a self) -> Option<Circle<T>>;
+}
+
+impl<'a, T, G> MinimumBoundingCircle<'a, T> for G
+where
+    G: CoordsIter<'a, Scalar = T>,
+    T: GeoFloat,
+{
+    fn minimum_bounding_circle(&'a self) -> Option<Circle<T>> {
+        let mut coords: Vec<_> = self.coords_iter().collect();
+        if coords.is_empty() {
+            return None;
+        }
+        let mut hull = quick_hull(&mut coords).0;
+        hull.pop();
+        shuffle(&mut hull);
+        Some(welzl(&hull))
+    }
+}
+
+/// Shuffle `points` with a Fisher–Yates shuffle, driven by a
+/// xorshift generator with a fixed seed.
+fn shuffle<T>(points: &mut [T]) {
+    let mut state = 0x2545_f491_u32;
+    for i in (1..points.len()).rev() {
+        state ^= state << 13;
+        state ^= state >> 17;
+        state ^= state << 5;
+        points.swap(i, state as usize % (i + 1));
+    }
+}
+
+/// The iterative form of Welzl's algorithm: each point outside
+/// the current circle lies on the boundary of the circle of the
+/// points so far.
+fn welzl<T: GeoFloat>(points: &[Coordinate<T>]) -> Circle<T> {
+    let mut circle = Circle {
+        center: points[0],
+        radius: T::zero(),
+    };
+    for i in 1..points.len() {
+        if circle.covers(points[i]) {
+            continue;
+        }
+        circle = Circle {
+            center: points[i],
+            radius: T::zero(),
+        };
+        for j in 0..i {
+            if circle.covers(points[j]) {
+                continue;
+            }
+            circle = Circle::from_diameter(points[i], points[j]);
+            for k in 0..j {
+                if !circle.covers(points[k]) {
+                    circle = Circle::circumcircle(points[i], points[j], points[k]);
+                }
+            }
+        }
+    }
+    circle
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{point, polygon, MultiPoint};
+
+    #[test]
+    fn single_point() {
+        let circle = point!(x: 1., y: 2.).minimum_bounding_circle().unwrap();
+        assert_eq!(circle.center, Coordinate { x: 1., y: 2. });
+        assert_eq!(circle.radius, 0.);
+        assert!(MultiPoint::<f64>(vec![])
+            .minimum_bounding_circle()
+            .is_none());
+    }
+
+    #[test]
+    fn equilateral_triangle() {
+        let h = 3f64.sqrt();
+        let poly = polygon![(x: -1., y: 0.), (x: 1., y: 0.), (x: 0., y: h)];
+        let circle = poly.minimum_bounding_circle().unwrap();
+        assert_relative_eq!(circle.center.x, 0.);
+        assert_relative_eq!(circle.center.y, h / 3.);
+        assert_relative_eq!(circle.radius, 2. * h / 3.);
+    }
+
+    #[test]
+    fn obtuse_triangle() {
+        // the circumcircle is larger than the circle on the
+        // longest side
+        let mp: MultiPoint<f64> = vec![(0., 0.), (10., 0.), (5., 1.)].into();
+        let circle = mp.minimum_bounding_circle().unwrap();
+        assert_eq!(circle.center, Coordinate { x: 5., y: 0. });
+        assert_eq!(circle.radius, 5.);
+    }
+
+    #[test]
+    fn many_points() {
+        let coords: Vec<_> = (0..100)
+            .map(|i| {
+                let angle = (i * 37 % 360) as f64;
+                let (sin, cos) = angle.to_radians().sin_cos();
+                let r = if i % 3 == 0 {
+                    3.
+                } else {
+                    1. + (i % 7) as f64 / 7.
+                };
+                (r * cos + 10., r * sin - 4.)
+            })
+            .collect();
+        let mp: MultiPoint<f64> = coords.into();
+        let circle = mp.minimum_bounding_circle().unwrap();
+        assert_relative_eq!(circle.center.x, 10., epsilon = 1e-9);
+        assert_relative_eq!(circle.center.y, -4., epsilon = 1e-9);
+        assert_relative_eq!(circle.radius, 3., epsilon = 1e-9);
+    }
+
+    #[test]
+    fn points_in_order() {
+        // a regular polygon, whose hull is already in order
+        let coords: Vec<_> = (0..1000)
+            .map(|i| {
+                let (sin, cos) = (f64::from(i) * 0.36).to_radians().sin_cos();
+                (2. * cos + 1., 2. * sin)
+            })
+            .collect();
+        let mp: MultiPoint<f64> = coords.into();
+        let circle = mp.minimum_bounding_circle().unwrap();
+        assert_relative_eq!(circle.center.x, 1., epsilon = 1e-9);
+        assert_relative_eq!(circle.center.y, 0., epsilon = 1e-9);
+        assert_relative_eq!(circle.radius, 2., epsilon = 1e-9);
+        assert!(mp.iter().all(|p| circle.covers(p.0)));
+    }
+}
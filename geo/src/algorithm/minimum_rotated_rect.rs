@@ -0,0 +1,215 @@
+use crate::algorithm::convex_hull::quick_hull;
+use crate::algorithm::coords_iter::CoordsIter;
+use crate::{Coordinate, GeoFloat, LineString, Polygon};
+
+/// Return the minimum-area rectangle enclosing a geometry,
+/// whatever its orientation.
+///
+/// The rectangle is found with the [rotating calipers]
+/// method: one of its sides lies along an edge of the
+/// [convex hull](crate::algorithm::convex_hull::ConvexHull) of
+/// the geometry. Its exterior is oriented counter-clockwise.
+///
+/// If the geometry is collinear or a single point, the
+/// rectangle is degenerate. If it has no coordinates, there is
+/// no rectangle.
+///
+/// [rotating calipers]: https://en.wikipedia.org/wiki/Rotating_calipers
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::area::Area;
+/// use geo::algorithm::minimum_rotated_rect::MinimumRotatedRect;
+/// use geo::polygon;
+///
+/// // a building, at 45° from the axes
+/// let building = polygon![(x: 2., y: 0.), (x: 4., y: 2.), (x: 2., y: 4.), (x: 0., y: 2.)];
+///
+/// let rect = building.minimum_rotated_rect().unwrap();
+/// approx::assert_relative_eq!(rect.unsigned_area(), 8., epsilon = 1e-10);
+/// ```
+pub trait MinimumRotatedRect<'a, T: GeoFloat> {
+    fn minimum_rotated_rect(&'a self) -> Option<Polygon<T>>;
+}
+
+impl<'a, T, G> MinimumRotatedRect<'a, T> for G
+where
+    G: CoordsIter<'a, Scalar = T>,
+    T: GeoFloat,
+{
+    fn minimum_rotated_rect(&'a self) -> Option<Polygon<T>> {
+        let mut coords: Vec<_> = self.coords_iter().collect();
+        if coords.is_empty() {
+            return None;
+        }
+        let mut hull = quick_hull(&mut coords).0;
+        hull.pop();
+        hull.dedup();
+        let n = hull.len();
+
+        // For each edge of the hull, the rectangle is bounded by
+        // the hull vertices farthest right, up and left of it,
+        // in its direction. As the edges turn counter-clockwise,
+        // so do those vertices: follow them with three calipers,
+        // which only ever move forwards.
+        let point = |k: usize| hull[k % n];
+        let along = |u: Coordinate<T>, c: Coordinate<T>| c.x * u.x + c.y * u.y;
+        let across = |u: Coordinate<T>, c: Coordinate<T>| c.y * u.x - c.x * u.y;
+        let (mut right, mut top, mut left) = (0, 0, 0);
+
+        // The axes of the best rectangle so far, with the
+        // extents of the hull along them.
+        let mut best: Option<(T, Coordinate<T>, [T; 4])> = None;
+        for i in 0..n {
+            let edge = point(i + 1) - point(i);
+            let length = edge.x.hypot(edge.y);
+            if length == T::zero() {
+                continue;
+            }
+            let u = Coordinate {
+                x: edge.x / length,
+                y: edge.y / length,
+            };
+            right = right.max(i + 1);
+            while along(u, point(right + 1)) > along(u, point(right)) {
+                right += 1;
+            }
+            top = top.max(right);
+            while across(u, point(top + 1)) > across(u, point(top)) {
+                top += 1;
+            }
+            left = left.max(top);
+            while along(u, point(left + 1)) < along(u, point(left)) {
+                left += 1;
+            }
+
+            let (min_u, max_u) = (along(u, point(left)), along(u, point(right)));
+            // both ends of the edge are the lowest, up to rounding
+            let min_v = across(u, point(i)).min(across(u, point(i + 1)));
+            let max_v = across(u, point(top));
+            let area = (max_u - min_u) * (max_v - min_v);
+            if !matches!(best, Some((best_area, _, _)) if best_area <= area) {
+                best = Some((area, u, [min_u, max_u, min_v, max_v]));
+            }
+        }
+
+        let (u, [min_u, max_u, min_v, max_v]) = match best {
+            Some((_, u, extents)) => (u, extents),
+            None => {
+                // every coordinate is the same
+                let c = coords[0];
+                return Some(Polygon::new(LineString(vec![c; 5]), vec![]));
+            }
+        };
+        let corner = |pu: T, pv: T| Coordinate {
+            x: pu * u.x - pv * u.y,
+            y: pu * u.y + pv * u.x,
+        };
+        Some(Polygon::new(
+            vec![
+                corner(min_u, min_v),
+                corner(max_u, min_v),
+                corner(max_u, max_v),
+                corner(min_u, max_v),
+                corner(min_u, min_v),
+            ]
+            .into(),
+            vec![],
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::area::Area;
+    use crate::algorithm::contains::Contains;
+    use crate::algorithm::euclidean_distance::EuclideanDistance;
+    use crate::algorithm::winding_order::Winding;
+    use crate::{line_string, point, MultiPoint, Point};
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    #[test]
+    fn axis_aligned() {
+        let mp: MultiPoint<f64> = vec![(0., 0.), (4., 0.), (4., 2.), (1., 1.), (0., 2.)].into();
+        let rect = mp.minimum_rotated_rect().unwrap();
+        assert_relative_eq!(rect.unsigned_area(), 8.);
+        assert!(rect.exterior().is_ccw());
+    }
+
+    #[test]
+    fn rotated() {
+        // a 3 × 1 rectangle rotated by 30°, with an inner point
+        let (sin, cos) = 30f64.to_radians().sin_cos();
+        let rotate = |x: f64, y: f64| (x * cos - y * sin + 5., x * sin + y * cos - 2.);
+        let mp: MultiPoint<f64> = vec![
+            rotate(0., 0.),
+            rotate(3., 0.),
+            rotate(3., 1.),
+            rotate(0., 1.),
+            rotate(1., 0.5),
+        ]
+        .into();
+        let rect = mp.minimum_rotated_rect().unwrap();
+        assert_relative_eq!(rect.unsigned_area(), 3., epsilon = 1e-10);
+        for (corner, expected) in rect.exterior().0.iter().zip(&mp.0[..4]) {
+            assert_relative_eq!(corner.x, expected.x(), epsilon = 1e-10);
+            assert_relative_eq!(corner.y, expected.y(), epsilon = 1e-10);
+        }
+        assert!(rect.contains(&mp.0[4]));
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        let mut rng = StdRng::seed_from_u64(11);
+        let mut coords: Vec<Coordinate<f64>> = (0..500)
+            .map(|_| Coordinate {
+                x: rng.gen_range(0. ..100.),
+                y: rng.gen_range(0. ..100.),
+            })
+            .collect();
+        let hull = quick_hull(&mut coords);
+
+        // the smallest rectangle along any edge of the hull
+        let expected = hull
+            .lines()
+            .map(|edge| {
+                let d = edge.delta();
+                let u = Coordinate {
+                    x: d.x / d.x.hypot(d.y),
+                    y: d.y / d.x.hypot(d.y),
+                };
+                let (mut min_u, mut max_u) = (f64::INFINITY, f64::NEG_INFINITY);
+                let (mut min_v, mut max_v) = (f64::INFINITY, f64::NEG_INFINITY);
+                for c in &hull.0 {
+                    let (pu, pv) = (c.x * u.x + c.y * u.y, c.y * u.x - c.x * u.y);
+                    min_u = min_u.min(pu);
+                    max_u = max_u.max(pu);
+                    min_v = min_v.min(pv);
+                    max_v = max_v.max(pv);
+                }
+                (max_u - min_u) * (max_v - min_v)
+            })
+            .fold(f64::INFINITY, f64::min);
+
+        let mp: MultiPoint<f64> = coords.into_iter().map(Point).collect();
+        let rect = mp.minimum_rotated_rect().unwrap();
+        assert_relative_eq!(rect.unsigned_area(), expected, epsilon = 1e-9);
+        // the points of the hull lie on the rectangle, up to rounding
+        assert!(mp.iter().all(|p| p.euclidean_distance(&rect) < 1e-9));
+    }
+
+    #[test]
+    fn degenerate() {
+        let ls = line_string![(x: 0., y: 0.), (x: 1., y: 1.), (x: 3., y: 3.)];
+        assert_relative_eq!(ls.minimum_rotated_rect().unwrap().unsigned_area(), 0.);
+
+        let p = point!(x: 1., y: 2.);
+        let rect = p.minimum_rotated_rect().unwrap();
+        assert!(rect.exterior().0.iter().all(|&c| c == p.0));
+
+        assert!(MultiPoint::<f64>(vec![]).minimum_rotated_rect().is_none());
+    }
+}
@@ -1,12 +1,22 @@
 /// Kernels to compute various predicates
 pub mod kernels;
 
+/// Apply affine transformations like rotation, scaling, skewing and translation to a `Geometry`.
+pub mod affine_ops;
+/// Calculate the distance along a great-circle route to the point closest to a `Point`.
+pub mod along_track_distance;
+/// Split geometries at the antimeridian, and calculate their wrapped bounding rectangles.
+pub mod antimeridian;
 /// Calculate the area of the surface of a `Geometry`.
 pub mod area;
 /// Calculate the bearing to another `Point`, in degrees.
 pub mod bearing;
+/// Boolean operations on polygons: intersection, union, difference and xor.
+pub mod bool_ops;
 /// Calculate the bounding rectangle of a `Geometry`.
 pub mod bounding_rect;
+/// Calculate the area within a given distance of a `Geometry`.
+pub mod buffer;
 /// Calculate the centroid of a `Geometry`.
 pub mod centroid;
 /// Calculate the signed approximate geodesic area of a `Geometry`.
@@ -23,6 +33,16 @@ pub mod convex_hull;
 pub mod coordinate_position;
 /// Iterate over geometry coordinates.
 pub mod coords_iter;
+/// Calculate the signed distance from a `Point` to a great-circle route.
+pub mod cross_track_distance;
+/// Compute the Delaunay triangulation of the coordinates of a `Geometry`.
+pub mod delaunay_triangulation;
+/// Add vertices to the segments of a `Geometry`, so that none is longer than a distance.
+pub mod densify;
+/// Add vertices along the geodesics between the vertices of a `Geometry`.
+pub mod densify_geodesic;
+/// Add vertices along the great circle routes between the vertices of a `Geometry`.
+pub mod densify_haversine;
 /// Dimensionality of a geometry and its boundary, based on OGC-SFA.
 pub mod dimensions;
 /// Calculate the minimum Euclidean distance between two `Geometries`.
@@ -33,12 +53,20 @@ pub mod euclidean_length;
 pub mod extremes;
 /// Calculate the Frechet distance between two `LineStrings`.
 pub mod frechet_distance;
+/// Calculate the Geodesic area and perimeter of polygons.
+pub mod geodesic_area;
+/// Calculate the Geodesic bearing between two `Point`s.
+pub mod geodesic_bearing;
+/// Calculate a destination `Point` on the ellipsoid, given a distance and a bearing.
+pub mod geodesic_destination;
 /// Calculate the Geodesic distance between two `Point`s.
 pub mod geodesic_distance;
-/// Calculate the Geodesic length of a line.
-pub mod geodesic_length;
 /// Calculate a new `Point` lying on a Geodesic arc between two `Point`s.
 pub mod geodesic_intermediate;
+/// Calculate the Geodesic length of a line.
+pub mod geodesic_length;
+/// Calculate the Hausdorff distance between two `Geometries`.
+pub mod hausdorff_distance;
 /// Calculate a destination `Point`, given a distance and a bearing.
 pub mod haversine_destination;
 /// Calculate the Haversine distance between two `Geometries`.
@@ -53,28 +81,68 @@ pub mod intersects;
 pub mod is_convex;
 /// Interpolate a point along a `Line` or `LineString`.
 pub mod line_interpolate_point;
+/// Compute the point or the segment where two `Line`s meet.
+pub mod line_intersection;
+/// Find the intersections among many `Line`s or `LineString`s.
+pub mod line_intersections;
 /// Locate a point along a `Line` or `LineString`.
 pub mod line_locate_point;
+/// Join the `LineString`s of a `MultiLineString` whose ends touch into the longest possible `LineString`s.
+pub mod line_merge;
+/// Extract the part of a `Line` or `LineString` between two fractions or distances along it.
+pub mod line_substring;
+/// Repair invalid geometries, keeping the area they cover.
+pub mod make_valid;
 /// Apply a function to all `Coordinates` of a `Geometry`.
 pub mod map_coords;
+/// Calculate the smallest circle enclosing a `Geometry`.
+pub mod minimum_bounding_circle;
+/// Calculate the minimum-area rectangle enclosing a `Geometry`, in any orientation.
+pub mod minimum_rotated_rect;
+/// Split the `LineString`s of a `MultiLineString` wherever they meet.
+pub mod node;
+/// Helper functions to split line segments at their intersections.
+pub(crate) mod noding;
 /// Orient a `Polygon`'s exterior and interior rings.
 pub mod orient;
+/// Find the point inside a `Polygon` farthest from its boundary, to place a label.
+pub mod pole_of_inaccessibility;
 /// Helper functions for the "fast path" variant of the Polygon-Polygon Euclidean distance method.
 pub(crate) mod polygon_distance_fast_path;
 /// Coordinate projections and transformations using the current stable version of [PROJ](http://proj.org).
 #[cfg(feature = "use-proj")]
 pub mod proj;
+/// Project geometries with Web Mercator, Transverse Mercator and UTM, without PROJ.
+pub mod projection;
+/// Topologically relate two geometries based on DE-9IM semantics.
+pub mod relate;
+/// Calculate distances, bearings, destinations and intermediate points along rhumb lines.
+pub mod rhumb;
 /// Rotate a `Geometry` around either its centroid or a `Point` by an angle given in degrees.
 pub mod rotate;
+/// Scale a `Geometry` up or down by a factor.
+pub mod scale;
 /// Simplify `Geometries` using the Ramer-Douglas-Peucker algorithm.
 pub mod simplify;
 /// Simplify `Geometries` using the Visvalingam-Whyatt algorithm. Includes a topology-preserving variant.
 pub mod simplifyvw;
+/// Skew a `Geometry` by shearing it at angles along the x and y dimensions.
+pub mod skew;
+/// Determine the position of a `Coordinate` relative to a polygon on the sphere.
+pub mod spherical_contains;
+/// Split a `LineString` at points, at fractions of its length, or where it meets another `Geometry`.
+pub mod split;
 /// Translate a `Geometry` along the given offsets.
 pub mod translate;
+/// Split `Polygon`s into triangles by ear clipping.
+pub mod triangulate_polygon;
+/// Check whether a `Geometry` is valid, and report why not.
+pub mod validation;
 /// Calculate the Vincenty distance between two `Point`s.
 pub mod vincenty_distance;
 /// Calculate the Vincenty length of a `LineString`.
 pub mod vincenty_length;
+/// Compute the Voronoi diagram of a set of points.
+pub mod voronoi;
 /// Calculate and work with the winding order of `Linestring`s.
 pub mod winding_order;
@@ -0,0 +1,187 @@
+use crate::algorithm::line_intersection::LineIntersection;
+use crate::algorithm::line_intersections::line_string_intersections;
+use crate::algorithm::split::split_line_string;
+use crate::utils::lex_cmp;
+use crate::{Coordinate, GeoFloat, LineString, MultiLineString};
+use rstar::RTreeNum;
+use std::cmp::Ordering;
+
+/// Split the `LineString`s of a geometry wherever they meet each other or
+/// themselves, so that the pieces only meet at their ends.
+///
+/// The pieces keep the direction and the vertices of the `LineString` they
+/// are cut from. Where `LineString`s overlap, or a `LineString` doubles back
+/// over itself, each of them is cut at both ends of the overlap, and the
+/// overlapping pieces are the same. Only the first of them is kept: a piece
+/// is dropped if it has the same vertices as an earlier one, in the same or
+/// in the reverse order.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::node::Node;
+/// use geo::{line_string, MultiLineString};
+///
+/// let ways = MultiLineString(vec![
+///     line_string![(x: 0., y: 0.), (x: 10., y: 0.)],
+///     line_string![(x: 5., y: -5.), (x: 5., y: 5.)],
+/// ]);
+///
+/// assert_eq!(
+///     ways.node(),
+///     MultiLineString(vec![
+///         line_string![(x: 0., y: 0.), (x: 5., y: 0.)],
+///         line_string![(x: 5., y: 0.), (x: 10., y: 0.)],
+///         line_string![(x: 5., y: -5.), (x: 5., y: 0.)],
+///         line_string![(x: 5., y: 0.), (x: 5., y: 5.)],
+///     ])
+/// );
+/// ```
+pub trait Node<T: GeoFloat> {
+    fn node(&self) -> MultiLineString<T>;
+}
+
+impl<T: GeoFloat + RTreeNum> Node<T> for MultiLineString<T> {
+    fn node(&self) -> MultiLineString<T> {
+        let mut cuts = vec![vec![]; self.0.len()];
+        for (first, second, intersection) in line_string_intersections(&self.0) {
+            let coords = match intersection {
+                LineIntersection::SinglePoint { intersection, .. } => vec![intersection],
+                LineIntersection::Collinear { intersection } => {
+                    vec![intersection.start, intersection.end]
+                }
+            };
+            for c in coords {
+                cuts[first.line_string].push((first.segment, c));
+                cuts[second.line_string].push((second.segment, c));
+            }
+        }
+        let pieces: Vec<_> = self
+            .iter()
+            .zip(cuts)
+            .flat_map(|(line_string, cuts)| split_line_string(line_string, cuts))
+            .collect();
+        MultiLineString(drop_duplicates(pieces))
+    }
+}
+
+/// Drop the pieces with the same vertices as an earlier piece, in either
+/// order. Pieces with a non-finite coordinate are all kept.
+fn drop_duplicates<T: GeoFloat>(pieces: Vec<LineString<T>>) -> Vec<LineString<T>> {
+    // each piece, in whichever of its directions comes first
+    let canonical: Vec<Vec<Coordinate<T>>> = pieces
+        .iter()
+        .map(|piece| {
+            let mut reversed = piece.0.clone();
+            reversed.reverse();
+            if compare(&reversed, &piece.0) == Ordering::Less {
+                reversed
+            } else {
+                piece.0.clone()
+            }
+        })
+        .collect();
+    let mut order: Vec<usize> = (0..pieces.len())
+        .filter(|&i| {
+            canonical[i]
+                .iter()
+                .all(|c| c.x.is_finite() && c.y.is_finite())
+        })
+        .collect();
+    // a stable sort, so that the first of equal pieces comes first
+    order.sort_by(|&i, &j| compare(&canonical[i], &canonical[j]));
+
+    let mut keep = vec![true; pieces.len()];
+    for pair in order.windows(2) {
+        if canonical[pair[0]] == canonical[pair[1]] {
+            keep[pair[1]] = false;
+        }
+    }
+    pieces
+        .into_iter()
+        .zip(keep)
+        .filter_map(|(piece, keep)| if keep { Some(piece) } else { None })
+        .collect()
+}
+
+fn compare<T: GeoFloat>(a: &[Coordinate<T>], b: &[Coordinate<T>]) -> Ordering {
+    a.iter()
+        .zip(b)
+        .map(|(a, b)| lex_cmp(a, b))
+        .find(|&ordering| ordering != Ordering::Equal)
+        .unwrap_or_else(|| a.len().cmp(&b.len()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::line_string;
+
+    #[test]
+    fn self_intersection() {
+        let loop_ = MultiLineString(vec![line_string![
+            (x: 0., y: 0.),
+            (x: 2., y: 2.),
+            (x: 2., y: 0.),
+            (x: 0., y: 2.)
+        ]]);
+        assert_eq!(
+            loop_.node(),
+            MultiLineString(vec![
+                line_string![(x: 0., y: 0.), (x: 1., y: 1.)],
+                line_string![(x: 1., y: 1.), (x: 2., y: 2.), (x: 2., y: 0.), (x: 1., y: 1.)],
+                line_string![(x: 1., y: 1.), (x: 0., y: 2.)],
+            ])
+        );
+    }
+
+    #[test]
+    fn touching_and_overlapping() {
+        let mls = MultiLineString(vec![
+            line_string![(x: 0., y: 0.), (x: 4., y: 0.)],
+            // touches the interior of the first
+            line_string![(x: 1., y: 0.), (x: 1., y: 1.)],
+            // overlaps the first
+            line_string![(x: 3., y: 0.), (x: 6., y: 0.)],
+        ]);
+        assert_eq!(
+            mls.node(),
+            MultiLineString(vec![
+                line_string![(x: 0., y: 0.), (x: 1., y: 0.)],
+                line_string![(x: 1., y: 0.), (x: 3., y: 0.)],
+                line_string![(x: 3., y: 0.), (x: 4., y: 0.)],
+                line_string![(x: 1., y: 0.), (x: 1., y: 1.)],
+                line_string![(x: 4., y: 0.), (x: 6., y: 0.)],
+            ])
+        );
+    }
+
+    #[test]
+    fn reversed_overlap() {
+        let mls = MultiLineString(vec![
+            line_string![(x: 0., y: 0.), (x: 4., y: 0.)],
+            line_string![(x: 4., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.)],
+            // doubles back over itself
+            line_string![(x: 0., y: 5.), (x: 2., y: 5.), (x: 1., y: 5.)],
+        ]);
+        assert_eq!(
+            mls.node(),
+            MultiLineString(vec![
+                line_string![(x: 0., y: 0.), (x: 2., y: 0.)],
+                line_string![(x: 2., y: 0.), (x: 4., y: 0.)],
+                line_string![(x: 2., y: 0.), (x: 2., y: 2.)],
+                line_string![(x: 0., y: 5.), (x: 1., y: 5.)],
+                line_string![(x: 1., y: 5.), (x: 2., y: 5.)],
+            ])
+        );
+    }
+
+    #[test]
+    fn already_noded() {
+        let mls = MultiLineString(vec![
+            line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.)],
+            line_string![(x: 1., y: 1.), (x: 2., y: 1.)],
+        ]);
+        assert_eq!(mls.node(), mls);
+    }
+}
@@ -0,0 +1,178 @@
+use crate::algorithm::line_intersection::{line_intersection, LineIntersection};
+use crate::{Coordinate, GeoFloat, Line, Point};
+use rstar::{RTree, RTreeNum, RTreeObject, AABB};
+use std::cmp::Ordering;
+
+/// The bounding box of a segment, with its index, to be stored in an
+/// [`RTree`].
+struct Envelope<T: GeoFloat + RTreeNum> {
+    index: usize,
+    envelope: AABB<Point<T>>,
+}
+
+impl<T: GeoFloat + RTreeNum> RTreeObject for Envelope<T> {
+    type Envelope = AABB<Point<T>>;
+
+    fn envelope(&self) -> Self::Envelope {
+        self.envelope
+    }
+}
+
+/// Call `f` with the indices `i < j` of every pair of segments
+/// whose bounding boxes overlap, found with an [`RTree`].
+/// Segments with a non-finite coordinate meet nothing, and are
+/// skipped.
+pub(crate) fn for_each_candidate_pair<T, F>(lines: &[Line<T>], mut f: F)
+where
+    T: GeoFloat + RTreeNum,
+    F: FnMut(usize, usize),
+{
+    let envelopes: Vec<_> = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| {
+            [line.start, line.end]
+                .iter()
+                .all(|c| c.x.is_finite() && c.y.is_finite())
+        })
+        .map(|(index, line)| Envelope {
+            index,
+            envelope: AABB::from_corners(line.start_point(), line.end_point()),
+        })
+        .collect();
+    let tree = RTree::bulk_load(envelopes);
+    for segment in tree.iter() {
+        for other in tree.locate_in_envelope_intersecting(&segment.envelope) {
+            if segment.index < other.index {
+                f(segment.index, other.index);
+            }
+        }
+    }
+}
+
+/// Split every segment at each point where it meets another
+/// segment, so that the resulting segments only ever meet at
+/// their endpoints. Each output segment keeps the label of
+/// the segment it was cut from, and the original direction.
+/// Degenerate (zero length) segments are dropped.
+pub(crate) fn node_segments<T, L>(segments: &[(Line<T>, L)]) -> Vec<(Line<T>, L)>
+where
+    T: GeoFloat + RTreeNum,
+    L: Copy,
+{
+    let lines: Vec<Line<T>> = segments.iter().map(|(line, _)| *line).collect();
+    let mut cuts: Vec<Vec<Coordinate<T>>> = vec![vec![]; lines.len()];
+
+    for_each_candidate_pair(&lines, |i, j| match line_intersection(lines[i], lines[j]) {
+        Some(LineIntersection::SinglePoint { intersection, .. }) => {
+            cuts[i].push(intersection);
+            cuts[j].push(intersection);
+        }
+        Some(LineIntersection::Collinear {
+            intersection: overlap,
+        }) => {
+            cuts[i].push(overlap.start);
+            cuts[i].push(overlap.end);
+            cuts[j].push(overlap.start);
+            cuts[j].push(overlap.end);
+        }
+        None => {}
+    });
+
+    let mut noded = Vec::with_capacity(segments.len());
+    for ((line, label), mut cuts) in segments.iter().zip(cuts) {
+        if line.start == line.end {
+            continue;
+        }
+        sort_along(*line, &mut cuts);
+        let mut start = line.start;
+        for c in cuts.into_iter().chain(std::iter::once(line.end)) {
+            if c != start {
+                noded.push((Line::new(start, c), *label));
+                start = c;
+            }
+        }
+    }
+    noded
+}
+
+/// Sort coordinates lying on `line` by their distance from
+/// its start, and remove duplicates. Coordinates whose
+/// distance is NaN are sorted last.
+pub(crate) fn sort_along<T: GeoFloat>(line: Line<T>, coords: &mut Vec<Coordinate<T>>) {
+    let delta = line.delta();
+    let key = |c: &Coordinate<T>| (c.x - line.start.x) * delta.x + (c.y - line.start.y) * delta.y;
+    coords.sort_by(|a, b| nan_last(key(a), key(b)));
+    coords.dedup();
+}
+
+// A total order on scalars, with NaN greater than any number.
+fn nan_last<T: GeoFloat>(a: T, b: T) -> Ordering {
+    match (a.is_nan(), b.is_nan()) {
+        (false, false) => a.partial_cmp(&b).unwrap(),
+        (false, true) => Ordering::Less,
+        (true, false) => Ordering::Greater,
+        (true, true) => Ordering::Equal,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn node_crossing_and_overlapping_segments() {
+        let segments = vec![
+            (Line::new((0., 0.), (4., 0.)), 0),
+            (Line::new((2., -2.), (2., 2.)), 1),
+            (Line::new((3., 0.), (6., 0.)), 2),
+        ];
+        let noded = node_segments(&segments);
+        assert_eq!(
+            noded,
+            vec![
+                (Line::new((0., 0.), (2., 0.)), 0),
+                (Line::new((2., 0.), (3., 0.)), 0),
+                (Line::new((3., 0.), (4., 0.)), 0),
+                (Line::new((2., -2.), (2., 0.)), 1),
+                (Line::new((2., 0.), (2., 2.)), 1),
+                (Line::new((3., 0.), (4., 0.)), 2),
+                (Line::new((4., 0.), (6., 0.)), 2),
+            ]
+        );
+    }
+
+    #[test]
+    fn non_finite_segments() {
+        let segments = vec![
+            (Line::new((0., 0.), (4., 0.)), 0),
+            (Line::new((2., -2.), (f64::NAN, 2.)), 1),
+            (Line::new((2., -2.), (2., 2.)), 2),
+            (Line::new((f64::INFINITY, 0.), (1., 0.)), 3),
+        ];
+        let mut pairs = vec![];
+        for_each_candidate_pair(
+            &segments.iter().map(|(line, _)| *line).collect::<Vec<_>>(),
+            |i, j| pairs.push((i, j)),
+        );
+        assert_eq!(pairs, vec![(0, 2)]);
+        // the non-finite segments are kept whole
+        assert_eq!(node_segments(&segments).len(), 6);
+    }
+
+    #[test]
+    fn sort_along_nan() {
+        let line = Line::new((0., 0.), (4., 0.));
+        let mut coords = vec![
+            Coordinate { x: 3., y: 0. },
+            Coordinate { x: f64::NAN, y: 0. },
+            Coordinate { x: 1., y: 0. },
+        ];
+        sort_along(line, &mut coords);
+        assert_eq!(
+            coords[..2],
+            [Coordinate { x: 1., y: 0. }, Coordinate { x: 3., y: 0. }]
+        );
+        assert!(coords[2].x.is_nan());
+    }
+}
@@ -0,0 +1,314 @@
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::iter::Sum;
+
+use num_traits::FromPrimitive;
+
+use crate::algorithm::area::Area;
+use crate::algorithm::bounding_rect::BoundingRect;
+use crate::algorithm::centroid::Centroid;
+use crate::algorithm::contains::Contains;
+use crate::algorithm::euclidean_distance::EuclideanDistance;
+use crate::{Coordinate, GeoFloat, MultiPolygon, Point, Polygon, Rect};
+
+/// Find the [pole of inaccessibility] of a polygon: the point
+/// inside it which lies farthest from its boundary, which makes
+/// a good place for a label.
+///
+/// The point is searched with the [polylabel] algorithm, to
+/// within `tolerance` of the actual pole. It is returned with
+/// its distance to the boundary. For a `MultiPolygon`, the
+/// point lies in the polygon with the farthest pole.
+///
+/// Returns `None` if the polygon is empty, or if it has no
+/// interior because its bounding box has no width or height,
+/// or its area is zero.
+/// `tolerance` must be finite and positive, otherwise the
+/// search would never end: `None` is returned for any other
+/// tolerance.
+///
+/// [pole of inaccessibility]: https://en.wikipedia.org/wiki/Pole_of_inaccessibility
+/// [polylabel]: https://github.com/mapbox/polylabel
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::contains::Contains;
+/// use geo::algorithm::pole_of_inaccessibility::PoleOfInaccessibility;
+/// use geo::polygon;
+///
+/// // a U shape, whose centroid lies outside
+/// let poly = polygon![
+///     (x: 0., y: 0.), (x: 6., y: 0.), (x: 6., y: 6.), (x: 4., y: 6.),
+///     (x: 4., y: 2.), (x: 2., y: 2.), (x: 2., y: 6.), (x: 0., y: 6.),
+/// ];
+///
+/// let (label, distance) = poly.pole_of_inaccessibility(0.01).unwrap();
+/// assert!(poly.contains(&label));
+/// approx::assert_relative_eq!(distance, 1.17, epsilon = 0.01);
+/// ```
+pub trait PoleOfInaccessibility<T: GeoFloat> {
+    fn pole_of_inaccessibility(&self, tolerance: T) -> Option<(Point<T>, T)>;
+}
+
+impl<T> PoleOfInaccessibility<T> for Polygon<T>
+where
+    T: GeoFloat + FromPrimitive + Sum,
+{
+    fn pole_of_inaccessibility(&self, tolerance: T) -> Option<(Point<T>, T)> {
+        polylabel(self, self.bounding_rect()?, self.centroid(), tolerance)
+    }
+}
+
+impl<T> PoleOfInaccessibility<T> for MultiPolygon<T>
+where
+    T: GeoFloat + FromPrimitive + Sum,
+{
+    fn pole_of_inaccessibility(&self, tolerance: T) -> Option<(Point<T>, T)> {
+        self.iter()
+            .filter_map(|polygon| polygon.pole_of_inaccessibility(tolerance))
+            .max_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap())
+    }
+}
+
+/// The distance from `point` to the boundary of `polygon`,
+/// negative if it lies outside.
+fn signed_distance<T: GeoFloat>(polygon: &Polygon<T>, point: Point<T>) -> T {
+    if !polygon.contains(&point) {
+        return -point.euclidean_distance(polygon);
+    }
+    polygon
+        .interiors()
+        .iter()
+        .chain(std::iter::once(polygon.exterior()))
+        .map(|ring| point.euclidean_distance(ring))
+        .fold(T::infinity(), |min, d| min.min(d))
+}
+
+/// A square cell of the search grid.
+struct Cell<T: GeoFloat> {
+    center: Point<T>,
+    half_size: T,
+    distance: T,
+    // The largest distance to the boundary in the cell.
+    max_distance: T,
+}
+
+impl<T: GeoFloat> Cell<T> {
+    fn new(polygon: &Polygon<T>, center: Coordinate<T>, half_size: T) -> Self {
+        let center = Point(center);
+        let distance = signed_distance(polygon, center);
+        Cell {
+            center,
+            half_size,
+            distance,
+            max_distance: distance + half_size * T::from(std::f64::consts::SQRT_2).unwrap(),
+        }
+    }
+}
+
+// These impls give us a max-heap on the potential of the cells
+impl<T: GeoFloat> Ord for Cell<T> {
+    fn cmp(&self, other: &Cell<T>) -> Ordering {
+        self.max_distance.partial_cmp(&other.max_distance).unwrap()
+    }
+}
+
+impl<T: GeoFloat> PartialOrd for Cell<T> {
+    fn partial_cmp(&self, other: &Cell<T>) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: GeoFloat> Eq for Cell<T> {}
+
+impl<T: GeoFloat> PartialEq for Cell<T> {
+    fn eq(&self, other: &Cell<T>) -> bool {
+        self.max_distance == other.max_distance
+    }
+}
+
+/// Replace `best` with `point` if it lies inside, farther from the
+/// boundary.
+fn improve<T: GeoFloat>(best: &mut Option<(Point<T>, T)>, point: Point<T>, distance: T) {
+    if distance > best.map_or(T::zero(), |(_, d)| d) {
+        *best = Some((point, distance));
+    }
+}
+
+fn polylabel<T: GeoFloat>(
+    polygon: &Polygon<T>,
+    bounds: Rect<T>,
+    centroid: Option<Point<T>>,
+    tolerance: T,
+) -> Option<(Point<T>, T)> {
+    if !(tolerance.is_finite() && tolerance > T::zero()) {
+        return None;
+    }
+    if bounds.width().min(bounds.height()) == T::zero() || polygon.unsigned_area() == T::zero() {
+        return None;
+    }
+
+    // Cover the bounding box with square cells as large as its
+    // longer side, so that long, thin polygons don't start with a
+    // huge grid.
+    let two = T::one() + T::one();
+    let cell_size = bounds.width().max(bounds.height());
+    let half_size = cell_size / two;
+    let mut cells = BinaryHeap::new();
+    let mut x = bounds.min().x;
+    while x < bounds.max().x {
+        let mut y = bounds.min().y;
+        while y < bounds.max().y {
+            let center = Coordinate {
+                x: x + half_size,
+                y: y + half_size,
+            };
+            cells.push(Cell::new(polygon, center, half_size));
+            y = y + cell_size;
+        }
+        x = x + cell_size;
+    }
+
+    // The centroid, then the centre of the bounding box, are the
+    // first guesses, if they lie inside.
+    let mut best = None;
+    if let Some(centroid) = centroid {
+        improve(&mut best, centroid, signed_distance(polygon, centroid));
+    }
+    let center = Point(bounds.center());
+    improve(&mut best, center, signed_distance(polygon, center));
+
+    // Split the cells which may contain a better point than the
+    // best so far. Until a point inside has been found, every cell
+    // which may contain one is split, whatever the tolerance.
+    while let Some(cell) = cells.pop() {
+        improve(&mut best, cell.center, cell.distance);
+        let prune = match best {
+            Some((_, distance)) => cell.max_distance - distance <= tolerance,
+            None => cell.max_distance <= T::zero(),
+        };
+        if prune {
+            continue;
+        }
+        let half_size = cell.half_size / two;
+        for &(dx, dy) in &[(-1., -1.), (1., -1.), (-1., 1.), (1., 1.)] {
+            let center = Coordinate {
+                x: cell.center.x() + half_size * T::from(dx).unwrap(),
+                y: cell.center.y() + half_size * T::from(dy).unwrap(),
+            };
+            cells.push(Cell::new(polygon, center, half_size));
+        }
+    }
+
+    best
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{polygon, LineString};
+
+    #[test]
+    fn square() {
+        let poly = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)];
+        let (point, distance) = poly.pole_of_inaccessibility(0.001).unwrap();
+        assert_relative_eq!(point, Point::new(2., 2.));
+        assert_relative_eq!(distance, 2.);
+    }
+
+    #[test]
+    fn polygon_with_hole() {
+        // the centroid lies in the hole
+        let poly = polygon![
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [[(x: 2., y: 2.), (x: 8., y: 2.), (x: 8., y: 8.), (x: 2., y: 8.)]],
+        ];
+        let (point, distance) = poly.pole_of_inaccessibility(0.01).unwrap();
+        assert!(poly.contains(&point));
+        // in a corner, away from both the exterior and the hole
+        let best = 2. * 2f64.sqrt() / (1. + 2f64.sqrt());
+        assert!(distance <= best && distance > best - 0.01);
+    }
+
+    #[test]
+    fn tolerance() {
+        let poly = polygon![
+            (x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 1.), (x: 1., y: 1.),
+            (x: 1., y: 10.), (x: 0., y: 10.),
+        ];
+        let (_, coarse) = poly.pole_of_inaccessibility(1.).unwrap();
+        let (point, fine) = poly.pole_of_inaccessibility(0.001).unwrap();
+        assert!(poly.contains(&point));
+        assert!(coarse <= fine);
+        assert!(0.5 - fine < 0.001);
+    }
+
+    #[test]
+    fn coarse_tolerance_stays_inside() {
+        // the centroid and the centre of the bounding box lie outside
+        let l = polygon![
+            (x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 0.5), (x: 0.5, y: 0.5),
+            (x: 0.5, y: 10.), (x: 0., y: 10.),
+        ];
+        let u = polygon![
+            (x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 9.5, y: 10.),
+            (x: 9.5, y: 0.5), (x: 0.5, y: 0.5), (x: 0.5, y: 10.), (x: 0., y: 10.),
+        ];
+        for poly in &[l, u] {
+            for &tolerance in &[1., 5., 100.] {
+                let (point, distance) = poly.pole_of_inaccessibility(tolerance).unwrap();
+                assert!(poly.contains(&point));
+                // at most the circle in the corner, touching the reflex vertex
+                assert!(distance > 0. && distance <= 0.5 * 2f64.sqrt() / (1. + 2f64.sqrt()));
+            }
+        }
+    }
+
+    #[test]
+    fn long_thin_polygon() {
+        let poly = polygon![(x: 0., y: 0.), (x: 1000., y: 0.), (x: 1000., y: 1.), (x: 0., y: 1.)];
+        let (point, distance) = poly.pole_of_inaccessibility(0.01).unwrap();
+        assert!(poly.contains(&point));
+        assert!(distance > 0.49 && distance <= 0.5);
+    }
+
+    #[test]
+    fn multi_polygon() {
+        let mp = MultiPolygon(vec![
+            polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)],
+            polygon![(x: 5., y: 0.), (x: 11., y: 0.), (x: 11., y: 6.), (x: 5., y: 6.)],
+        ]);
+        let (point, distance) = mp.pole_of_inaccessibility(0.001).unwrap();
+        assert_relative_eq!(point, Point::new(8., 3.));
+        assert_relative_eq!(distance, 3.);
+    }
+
+    #[test]
+    fn degenerate() {
+        let empty: Polygon<f64> = Polygon::new(LineString(vec![]), vec![]);
+        assert!(empty.pole_of_inaccessibility(1.).is_none());
+        assert!(MultiPolygon::<f64>(vec![])
+            .pole_of_inaccessibility(1.)
+            .is_none());
+
+        let flat = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 1., y: 0.)];
+        assert!(flat.pole_of_inaccessibility(1.).is_none());
+        let mp = MultiPolygon(vec![
+            flat,
+            polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)],
+        ]);
+        assert_eq!(
+            mp.pole_of_inaccessibility(0.1),
+            Some((Point::new(1., 1.), 1.))
+        );
+    }
+
+    #[test]
+    fn invalid_tolerance() {
+        let poly = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)];
+        for &tolerance in &[0., -1., f64::NAN, f64::INFINITY] {
+            assert!(poly.pole_of_inaccessibility(tolerance).is_none());
+        }
+    }
+}
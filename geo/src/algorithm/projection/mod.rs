@@ -0,0 +1,110 @@
+//! Pure-Rust map projections between WGS84 longitude/latitude and
+//! projected coordinates, which need neither PROJ nor the `use-proj`
+//! feature.
+//!
+//! Each [`Projection`] works on `(x, y)` tuples, so it can be applied
+//! to any geometry with [`TryMapCoords`], or with the [`Project`]
+//! trait.
+//!
+//! # Examples
+//!
+//! ```
+//! use geo::algorithm::map_coords::TryMapCoords;
+//! use geo::algorithm::projection::{Projection, WebMercator};
+//! use geo::line_string;
+//!
+//! let line_string = line_string![(x: 0., y: 0.), (x: 180., y: 0.)];
+//! let projected = line_string
+//!     .try_map_coords(|&(lng, lat)| Ok(WebMercator.forward(lng, lat)?))
+//!     .unwrap();
+//! approx::assert_relative_eq!(projected[1].x, 20_037_508.342789244);
+//! approx::assert_relative_eq!(projected[1].y, 0., epsilon = 1e-6);
+//! ```
+
+use std::error::Error;
+use std::fmt;
+
+use crate::algorithm::map_coords::TryMapCoords;
+
+mod transverse_mercator;
+mod web_mercator;
+
+pub use transverse_mercator::{TransverseMercator, Utm};
+pub use web_mercator::WebMercator;
+
+/// A map projection of the WGS84 ellipsoid.
+pub trait Projection {
+    /// Project a longitude and a latitude, in degrees, to `(x, y)`
+    /// coordinates in meters.
+    fn forward(&self, lng: f64, lat: f64) -> Result<(f64, f64), ProjectionError>;
+
+    /// Find the longitude and the latitude, in degrees, of projected
+    /// `(x, y)` coordinates in meters.
+    fn inverse(&self, x: f64, y: f64) -> Result<(f64, f64), ProjectionError>;
+}
+
+/// Project the coordinates of a geometry, or revert them to longitudes
+/// and latitudes.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::projection::{Project, Utm};
+/// use geo::point;
+///
+/// let p = point!(x: 7.5, y: 51.2);
+/// let utm = Utm::from_lng_lat(p.x(), p.y()).unwrap();
+/// assert_eq!(utm.zone(), 32);
+///
+/// let projected = p.project(&utm).unwrap();
+/// assert_eq!(projected.x().round(), 395_201.);
+/// assert_eq!(projected.y().round(), 5_673_135.);
+/// ```
+pub trait Project: TryMapCoords<f64, f64> {
+    /// Project the longitudes and latitudes of a geometry.
+    fn project<P: Projection>(
+        &self,
+        projection: &P,
+    ) -> Result<Self::Output, Box<dyn Error + Send + Sync>> {
+        self.try_map_coords(|&(lng, lat)| Ok(projection.forward(lng, lat)?))
+    }
+
+    /// Revert the projected coordinates of a geometry to longitudes
+    /// and latitudes.
+    fn unproject<P: Projection>(
+        &self,
+        projection: &P,
+    ) -> Result<Self::Output, Box<dyn Error + Send + Sync>> {
+        self.try_map_coords(|&(x, y)| Ok(projection.inverse(x, y)?))
+    }
+}
+
+impl<G: TryMapCoords<f64, f64>> Project for G {}
+
+/// The error returned when coordinates cannot be projected.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ProjectionError {
+    /// The coordinates lie outside the area the projection covers.
+    OutOfBounds(f64, f64),
+    /// There is no such UTM zone.
+    InvalidZone(u8),
+}
+
+impl fmt::Display for ProjectionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ProjectionError::OutOfBounds(x, y) => {
+                write!(
+                    f,
+                    "coordinates ({}, {}) are out of the projection bounds",
+                    x, y
+                )
+            }
+            ProjectionError::InvalidZone(zone) => {
+                write!(f, "UTM zone {} is not between 1 and 60", zone)
+            }
+        }
+    }
+}
+
+impl Error for ProjectionError {}
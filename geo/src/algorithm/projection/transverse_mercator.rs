@@ -0,0 +1,317 @@
+use super::{Projection, ProjectionError};
+
+/// The equatorial radius of WGS84.
+const WGS84_A: f64 = 6_378_137.;
+/// The flattening of WGS84.
+const WGS84_F: f64 = 1. / 298.257_223_563;
+
+/// A [Transverse Mercator] projection of the WGS84 ellipsoid.
+///
+/// This uses the series of Krüger to the fourth order of the third
+/// flattening, which is accurate to well under a millimeter within a few
+/// thousand kilometers of the central meridian. Longitudes more than 90°
+/// away from the central meridian cannot be projected, and the inverse
+/// projection returns longitudes within [-180, 180].
+///
+/// [Transverse Mercator]: https://en.wikipedia.org/wiki/Transverse_Mercator_projection
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::projection::{Projection, TransverseMercator};
+///
+/// // the British National Grid, on WGS84 rather than Airy 1830
+/// let tm = TransverseMercator {
+///     central_meridian: -2.,
+///     scale_factor: 0.999_601_271_7,
+///     false_easting: 400_000.,
+///     false_northing: -5_527_063.815,
+/// };
+///
+/// let (x, y) = tm.forward(-2., 49.).unwrap();
+/// approx::assert_relative_eq!(x, 400_000.);
+/// let (lng, lat) = tm.inverse(x, y).unwrap();
+/// approx::assert_relative_eq!(lng, -2.);
+/// approx::assert_relative_eq!(lat, 49., epsilon = 1e-9);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TransverseMercator {
+    /// The longitude of the central meridian, in degrees.
+    pub central_meridian: f64,
+    /// The scale factor along the central meridian.
+    pub scale_factor: f64,
+    /// The easting of the central meridian, in meters.
+    pub false_easting: f64,
+    /// The northing of the equator, in meters.
+    pub false_northing: f64,
+}
+
+impl Projection for TransverseMercator {
+    fn forward(&self, lng: f64, lat: f64) -> Result<(f64, f64), ProjectionError> {
+        let lambda = (lng - self.central_meridian).to_radians();
+        if !lng.is_finite() || !(-90. ..=90.).contains(&lat) || lambda.cos() <= 0. {
+            return Err(ProjectionError::OutOfBounds(lng, lat));
+        }
+        let s = Series::wgs84();
+        let sin_phi = lat.to_radians().sin();
+        let e = 2. * s.n.sqrt() / (1. + s.n);
+        let t = (sin_phi.atanh() - e * (e * sin_phi).atanh()).sinh();
+        let xi = t.atan2(lambda.cos());
+        let eta = (lambda.sin() / (1. + t * t).sqrt()).atanh();
+
+        let (mut x, mut y) = (eta, xi);
+        for (j, alpha) in s.alpha.iter().enumerate() {
+            let k = 2. * (j + 1) as f64;
+            x += alpha * (k * xi).cos() * (k * eta).sinh();
+            y += alpha * (k * xi).sin() * (k * eta).cosh();
+        }
+        let scale = self.scale_factor * s.a;
+        Ok((
+            self.false_easting + scale * x,
+            self.false_northing + scale * y,
+        ))
+    }
+
+    fn inverse(&self, x: f64, y: f64) -> Result<(f64, f64), ProjectionError> {
+        if !x.is_finite() || !y.is_finite() {
+            return Err(ProjectionError::OutOfBounds(x, y));
+        }
+        let s = Series::wgs84();
+        let scale = self.scale_factor * s.a;
+        let xi = (y - self.false_northing) / scale;
+        let eta = (x - self.false_easting) / scale;
+
+        let (mut xi_p, mut eta_p) = (xi, eta);
+        for (j, beta) in s.beta.iter().enumerate() {
+            let k = 2. * (j + 1) as f64;
+            xi_p -= beta * (k * xi).sin() * (k * eta).cosh();
+            eta_p -= beta * (k * xi).cos() * (k * eta).sinh();
+        }
+        let chi = (xi_p.sin() / eta_p.cosh()).asin();
+        let mut phi = chi;
+        for (j, delta) in s.delta.iter().enumerate() {
+            phi += delta * (2. * (j + 1) as f64 * chi).sin();
+        }
+        let lambda = eta_p.sinh().atan2(xi_p.cos());
+        // Wrap the longitude into [-180, 180], as the central
+        // meridian may be near the antimeridian.
+        let mut lng = self.central_meridian + lambda.to_degrees();
+        if lng > 180. {
+            lng -= 360.;
+        } else if lng < -180. {
+            lng += 360.;
+        }
+        Ok((lng, phi.to_degrees()))
+    }
+}
+
+/// The coefficients of the series of Krüger.
+struct Series {
+    n: f64,
+    a: f64,
+    alpha: [f64; 4],
+    beta: [f64; 4],
+    delta: [f64; 4],
+}
+
+impl Series {
+    fn wgs84() -> Self {
+        let n = WGS84_F / (2. - WGS84_F);
+        let (n2, n3, n4) = (n * n, n * n * n, n * n * n * n);
+        Series {
+            n,
+            a: WGS84_A / (1. + n) * (1. + n2 / 4. + n4 / 64.),
+            alpha: [
+                n / 2. - 2. * n2 / 3. + 5. * n3 / 16. + 41. * n4 / 180.,
+                13. * n2 / 48. - 3. * n3 / 5. + 557. * n4 / 1440.,
+                61. * n3 / 240. - 103. * n4 / 140.,
+                49561. * n4 / 161_280.,
+            ],
+            beta: [
+                n / 2. - 2. * n2 / 3. + 37. * n3 / 96. - n4 / 360.,
+                n2 / 48. + n3 / 15. - 437. * n4 / 1440.,
+                17. * n3 / 480. - 37. * n4 / 840.,
+                4397. * n4 / 161_280.,
+            ],
+            delta: [
+                2. * n - 2. * n2 / 3. - 2. * n3 + 116. * n4 / 45.,
+                7. * n2 / 3. - 8. * n3 / 5. - 227. * n4 / 45.,
+                56. * n3 / 15. - 136. * n4 / 35.,
+                4279. * n4 / 630.,
+            ],
+        }
+    }
+}
+
+/// A zone of the [Universal Transverse Mercator] coordinate system.
+///
+/// [Universal Transverse Mercator]: https://en.wikipedia.org/wiki/Universal_Transverse_Mercator_coordinate_system
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::projection::{Projection, Utm};
+///
+/// let utm = Utm::new(31, true).unwrap();
+/// assert_eq!(utm.forward(3., 0.).unwrap(), (500_000., 0.));
+///
+/// // Bergen lies in the widened zone 32V
+/// let utm = Utm::from_lng_lat(5.32, 60.39).unwrap();
+/// assert_eq!(utm.zone(), 32);
+/// assert!(utm.is_north());
+///
+/// assert!(Utm::new(61, true).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Utm {
+    zone: u8,
+    north: bool,
+}
+
+impl Utm {
+    /// Create a UTM zone, numbered from 1 to 60, in the northern or the
+    /// southern hemisphere.
+    pub fn new(zone: u8, north: bool) -> Result<Self, ProjectionError> {
+        if !(1..=60).contains(&zone) {
+            return Err(ProjectionError::InvalidZone(zone));
+        }
+        Ok(Utm { zone, north })
+    }
+
+    /// Select the UTM zone of a longitude and a latitude, in degrees,
+    /// including the exceptions around Norway and Svalbard.
+    ///
+    /// UTM does not cover latitudes outside of 80°S to 84°N.
+    pub fn from_lng_lat(lng: f64, lat: f64) -> Result<Self, ProjectionError> {
+        if !(-80. ..=84.).contains(&lat) || !(-180. ..=180.).contains(&lng) {
+            return Err(ProjectionError::OutOfBounds(lng, lat));
+        }
+        let mut zone = (((lng + 180.) / 6.).floor() as u8 + 1).min(60);
+        if (56. ..64.).contains(&lat) && (3. ..12.).contains(&lng) {
+            zone = 32;
+        } else if lat >= 72. && (0. ..42.).contains(&lng) {
+            zone = match lng {
+                lng if lng < 9. => 31,
+                lng if lng < 21. => 33,
+                lng if lng < 33. => 35,
+                _ => 37,
+            };
+        }
+        Utm::new(zone, lat >= 0.)
+    }
+
+    /// The number of the zone, from 1 to 60.
+    pub fn zone(&self) -> u8 {
+        self.zone
+    }
+
+    /// Whether the zone lies in the northern hemisphere.
+    pub fn is_north(&self) -> bool {
+        self.north
+    }
+
+    /// The Transverse Mercator projection of the zone.
+    pub fn transverse_mercator(&self) -> TransverseMercator {
+        TransverseMercator {
+            central_meridian: f64::from(self.zone) * 6. - 183.,
+            scale_factor: 0.9996,
+            false_easting: 500_000.,
+            false_northing: if self.north { 0. } else { 10_000_000. },
+        }
+    }
+}
+
+impl Projection for Utm {
+    fn forward(&self, lng: f64, lat: f64) -> Result<(f64, f64), ProjectionError> {
+        self.transverse_mercator().forward(lng, lat)
+    }
+
+    fn inverse(&self, x: f64, y: f64) -> Result<(f64, f64), ProjectionError> {
+        self.transverse_mercator().inverse(x, y)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn known_values() {
+        let utm = Utm::from_lng_lat(7.5, 51.2).unwrap();
+        assert_eq!(utm, Utm::new(32, true).unwrap());
+        let (x, y) = utm.forward(7.5, 51.2).unwrap();
+        assert_relative_eq!(x, 395_201.310, epsilon = 1e-2);
+        assert_relative_eq!(y, 5_673_135.241, epsilon = 1e-2);
+
+        // Sydney, zone 56H
+        let utm = Utm::from_lng_lat(151.2093, -33.8688).unwrap();
+        assert_eq!(utm, Utm::new(56, false).unwrap());
+        let (x, y) = utm.forward(151.2093, -33.8688).unwrap();
+        assert_relative_eq!(x, 334_368.635, epsilon = 1e-2);
+        assert_relative_eq!(y, 6_250_948.345, epsilon = 1e-2);
+    }
+
+    #[test]
+    fn round_trip() {
+        for zone in 1..=60 {
+            for &north in &[true, false] {
+                let utm = Utm::new(zone, north).unwrap();
+                let central = utm.transverse_mercator().central_meridian;
+                for &(dlng, lat) in &[(0., 0.), (-3., 45.), (2.9, -79.), (10., 83.)] {
+                    let lng = central + dlng;
+                    let (x, y) = utm.forward(lng, lat).unwrap();
+                    let (lng2, lat2) = utm.inverse(x, y).unwrap();
+                    // the same longitude, wrapped into [-180, 180]
+                    assert!((-180. ..=180.).contains(&lng2));
+                    assert_relative_eq!((lng2 - lng + 540.) % 360., 180., epsilon = 1e-9);
+                    assert_relative_eq!(lat, lat2, epsilon = 1e-9);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn round_trip_across_the_antimeridian() {
+        // longitudes on the far side of the antimeridian from the
+        // central meridian come back within [-180, 180]
+        for &(zone, lng) in &[(1, 179.), (1, 180.), (60, -179.), (60, -180.)] {
+            let utm = Utm::new(zone, true).unwrap();
+            let (x, y) = utm.forward(lng, 10.).unwrap();
+            let (lng2, lat2) = utm.inverse(x, y).unwrap();
+            assert!((-180. ..=180.).contains(&lng2));
+            assert_relative_eq!(lng.abs(), lng2.abs(), epsilon = 1e-9);
+            assert_relative_eq!(lat2, 10., epsilon = 1e-9);
+        }
+        let utm = Utm::new(60, true).unwrap();
+        let (x, y) = utm.forward(-179., 10.).unwrap();
+        assert_relative_eq!(utm.inverse(x, y).unwrap().0, -179., epsilon = 1e-9);
+        let utm = Utm::new(1, true).unwrap();
+        let (x, y) = utm.forward(179., 10.).unwrap();
+        assert_relative_eq!(utm.inverse(x, y).unwrap().0, 179., epsilon = 1e-9);
+    }
+
+    #[test]
+    fn zones() {
+        assert_eq!(Utm::from_lng_lat(-180., 0.).unwrap().zone(), 1);
+        assert_eq!(Utm::from_lng_lat(180., 0.).unwrap().zone(), 60);
+        assert_eq!(Utm::from_lng_lat(2., 60.).unwrap().zone(), 31);
+        assert_eq!(Utm::from_lng_lat(3., 60.).unwrap().zone(), 32);
+        assert_eq!(Utm::from_lng_lat(8., 75.).unwrap().zone(), 31);
+        assert_eq!(Utm::from_lng_lat(10., 75.).unwrap().zone(), 33);
+        assert_eq!(Utm::from_lng_lat(40., 75.).unwrap().zone(), 37);
+        assert!(!Utm::from_lng_lat(0., -1.).unwrap().is_north());
+        assert_eq!(
+            Utm::from_lng_lat(0., 85.),
+            Err(ProjectionError::OutOfBounds(0., 85.))
+        );
+        assert_eq!(Utm::new(0, true), Err(ProjectionError::InvalidZone(0)));
+    }
+
+    #[test]
+    fn out_of_bounds() {
+        let utm = Utm::new(31, true).unwrap();
+        assert!(utm.forward(95., 0.).is_err());
+        assert!(utm.forward(0., 91.).is_err());
+        assert!(utm.forward(f64::NAN, 0.).is_err());
+    }
+}
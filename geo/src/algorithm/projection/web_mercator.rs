@@ -0,0 +1,83 @@
+use std::f64::consts::{FRAC_PI_2, FRAC_PI_4};
+
+use super::{Projection, ProjectionError};
+
+/// The radius of the sphere of the Web Mercator projection, that is the
+/// equatorial radius of WGS84.
+const RADIUS: f64 = 6_378_137.;
+
+/// The [Web Mercator] projection of web maps, also known as
+/// EPSG:3857 or "Pseudo-Mercator".
+///
+/// It projects longitudes and latitudes as if they were on a sphere.
+/// The poles cannot be projected; web maps usually stop at about
+/// ±85.0511°, where the map becomes square.
+///
+/// [Web Mercator]: https://en.wikipedia.org/wiki/Web_Mercator_projection
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::projection::{Projection, WebMercator};
+///
+/// let (x, y) = WebMercator.forward(180., 85.0511287798066).unwrap();
+/// approx::assert_relative_eq!(x, 20_037_508.342789244);
+/// approx::assert_relative_eq!(y, 20_037_508.342789244, epsilon = 1e-6);
+///
+/// assert!(WebMercator.forward(0., 90.).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct WebMercator;
+
+impl Projection for WebMercator {
+    fn forward(&self, lng: f64, lat: f64) -> Result<(f64, f64), ProjectionError> {
+        if !lng.is_finite() || !lat.is_finite() || lat.abs() >= 90. {
+            return Err(ProjectionError::OutOfBounds(lng, lat));
+        }
+        let x = RADIUS * lng.to_radians();
+        let y = RADIUS * (FRAC_PI_4 + lat.to_radians() / 2.).tan().ln();
+        Ok((x, y))
+    }
+
+    fn inverse(&self, x: f64, y: f64) -> Result<(f64, f64), ProjectionError> {
+        if !x.is_finite() || !y.is_finite() {
+            return Err(ProjectionError::OutOfBounds(x, y));
+        }
+        let lng = (x / RADIUS).to_degrees();
+        let lat = (2. * (y / RADIUS).exp().atan() - FRAC_PI_2).to_degrees();
+        Ok((lng, lat))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        for &(lng, lat) in &[(0., 0.), (-73.98, 40.75), (151.2, -33.87), (179.9, 85.)] {
+            let (x, y) = WebMercator.forward(lng, lat).unwrap();
+            let (lng2, lat2) = WebMercator.inverse(x, y).unwrap();
+            assert_relative_eq!(lng, lng2, epsilon = 1e-9);
+            assert_relative_eq!(lat, lat2, epsilon = 1e-9);
+        }
+    }
+
+    #[test]
+    fn known_values() {
+        // London, as reported by PROJ
+        let (x, y) = WebMercator.forward(-0.1278, 51.5074).unwrap();
+        assert_relative_eq!(x, -14_226.630_5, epsilon = 1e-3);
+        assert_relative_eq!(y, 6_711_542.475_9, epsilon = 1e-3);
+    }
+
+    #[test]
+    fn out_of_bounds() {
+        assert_eq!(
+            WebMercator.forward(0., -90.),
+            Err(ProjectionError::OutOfBounds(0., -90.))
+        );
+        assert!(WebMercator.forward(f64::NAN, 0.).is_err());
+        assert!(WebMercator.inverse(0., f64::INFINITY).is_err());
+    }
+}
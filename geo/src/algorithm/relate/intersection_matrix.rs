@@ -0,0 +1,311 @@
+use crate::algorithm::coordinate_position::CoordPos;
+use crate::algorithm::dimensions::Dimensions;
+use std::{error, fmt};
+
+/// The [DE-9IM] intersection matrix of two geometries, as
+/// computed by [`Relate`](super::Relate).
+///
+/// Each cell holds the dimension of the intersection of the
+/// interior, boundary, or exterior of the first geometry with
+/// the interior, boundary, or exterior of the second one.
+/// Rows belong to the first geometry, columns to the second.
+///
+/// [DE-9IM]: https://en.wikipedia.org/wiki/DE-9IM
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::coordinate_position::CoordPos;
+/// use geo::algorithm::dimensions::Dimensions;
+/// use geo::algorithm::relate::Relate;
+/// use geo::{line_string, polygon};
+///
+/// let square = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)];
+/// let line = line_string![(x: 1., y: 1.), (x: 3., y: 1.)];
+///
+/// let matrix = line.relate(&square);
+/// assert_eq!(matrix.to_string(), "1010F0212");
+/// assert_eq!(
+///     matrix.get(CoordPos::Inside, CoordPos::Inside),
+///     Dimensions::OneDimensional
+/// );
+/// assert!(matrix.is_crosses());
+/// assert!(matrix.matches("1*T***2**").unwrap());
+/// ```
+#[derive(PartialEq, Eq, Clone, Copy)]
+pub struct IntersectionMatrix([[Dimensions; 3]; 3]);
+
+impl IntersectionMatrix {
+    /// The matrix of two empty geometries: only their exteriors
+    /// intersect.
+    pub(crate) fn empty() -> Self {
+        let mut matrix = IntersectionMatrix([[Dimensions::Empty; 3]; 3]);
+        matrix.set_at_least(
+            CoordPos::Outside,
+            CoordPos::Outside,
+            Dimensions::TwoDimensional,
+        );
+        matrix
+    }
+
+    /// Raise the dimension of a cell to at least `dimensions`.
+    pub(crate) fn set_at_least(&mut self, lhs: CoordPos, rhs: CoordPos, dimensions: Dimensions) {
+        let cell = &mut self.0[index(lhs)][index(rhs)];
+        if *cell < dimensions {
+            *cell = dimensions;
+        }
+    }
+
+    /// The dimension of the intersection of the part of the
+    /// first geometry at `lhs`, with the part of the second
+    /// geometry at `rhs`.
+    pub fn get(&self, lhs: CoordPos, rhs: CoordPos) -> Dimensions {
+        self.0[index(lhs)][index(rhs)]
+    }
+
+    /// The matrix with the roles of both geometries swapped.
+    pub fn transposed(&self) -> Self {
+        let mut transposed = *self;
+        for (i, row) in self.0.iter().enumerate() {
+            for (j, dimensions) in row.iter().enumerate() {
+                transposed.0[j][i] = *dimensions;
+            }
+        }
+        transposed
+    }
+
+    /// Whether the matrix matches a pattern of nine characters,
+    /// given row by row. Each character constrains one cell:
+    ///
+    /// - `T`: the parts intersect (any dimension)
+    /// - `F`: the parts do not intersect
+    /// - `0`, `1`, `2`: the intersection has exactly that dimension
+    /// - `*`: any value
+    ///
+    /// Returns an error if the pattern is malformed.
+    pub fn matches(&self, pattern: &str) -> Result<bool, InvalidPatternError> {
+        let pattern = pattern.as_bytes();
+        if pattern.len() != 9 {
+            return Err(InvalidPatternError);
+        }
+        let mut matches = true;
+        for (dimensions, expected) in self.0.iter().flatten().zip(pattern) {
+            matches &= match expected {
+                b'T' | b't' => *dimensions != Dimensions::Empty,
+                b'F' | b'f' => *dimensions == Dimensions::Empty,
+                b'0' => *dimensions == Dimensions::ZeroDimensional,
+                b'1' => *dimensions == Dimensions::OneDimensional,
+                b'2' => *dimensions == Dimensions::TwoDimensional,
+                b'*' => true,
+                _ => return Err(InvalidPatternError),
+            };
+        }
+        Ok(matches)
+    }
+
+    fn matches_valid(&self, pattern: &str) -> bool {
+        self.matches(pattern)
+            .expect("built-in patterns are well-formed")
+    }
+
+    // The dimensions of the first and of the second geometry.
+    // The interior of a geometry has its full dimension, and it
+    // is covered by the cells of its row (or column).
+    fn dimensions(&self) -> (Dimensions, Dimensions) {
+        let lhs = self.0[0].iter().max().copied().unwrap();
+        let rhs = self.0.iter().map(|row| row[0]).max().unwrap();
+        (lhs, rhs)
+    }
+
+    /// The geometries have no point in common: `FF*FF****`.
+    pub fn is_disjoint(&self) -> bool {
+        self.matches_valid("FF*FF****")
+    }
+
+    /// The geometries have at least one point in common.
+    pub fn is_intersects(&self) -> bool {
+        !self.is_disjoint()
+    }
+
+    /// The first geometry lies within the second one:
+    /// `T*F**F***`.
+    pub fn is_within(&self) -> bool {
+        self.matches_valid("T*F**F***")
+    }
+
+    /// The first geometry contains the second one: `T*****FF*`.
+    pub fn is_contains(&self) -> bool {
+        self.matches_valid("T*****FF*")
+    }
+
+    /// Every point of the second geometry is a point of the
+    /// first one.
+    pub fn is_covers(&self) -> bool {
+        self.is_intersects() && self.matches_valid("******FF*")
+    }
+
+    /// Every point of the first geometry is a point of the
+    /// second one.
+    pub fn is_coveredby(&self) -> bool {
+        self.is_intersects() && self.matches_valid("**F**F***")
+    }
+
+    /// The geometries only have points of their boundaries in
+    /// common.
+    pub fn is_touches(&self) -> bool {
+        self.matches_valid("F***T****")
+            || self.matches_valid("FT*******")
+            || self.matches_valid("F**T*****")
+    }
+
+    /// The geometries have some, but not all, interior points in
+    /// common, and the dimension of the intersection is less
+    /// than that of at least one of them.
+    pub fn is_crosses(&self) -> bool {
+        let (lhs, rhs) = self.dimensions();
+        match (lhs, rhs) {
+            (Dimensions::OneDimensional, Dimensions::OneDimensional) => {
+                self.matches_valid("0********")
+            }
+            _ if lhs < rhs => self.matches_valid("T*T******"),
+            _ if lhs > rhs => self.matches_valid("T*****T**"),
+            _ => false,
+        }
+    }
+
+    /// The geometries have the same dimension, and share some,
+    /// but not all, of their interiors, again of that dimension.
+    pub fn is_overlaps(&self) -> bool {
+        let (lhs, rhs) = self.dimensions();
+        match (lhs, rhs) {
+            (Dimensions::OneDimensional, Dimensions::OneDimensional) => {
+                self.matches_valid("1*T***T**")
+            }
+            (Dimensions::ZeroDimensional, Dimensions::ZeroDimensional)
+            | (Dimensions::TwoDimensional, Dimensions::TwoDimensional) => {
+                self.matches_valid("T*T***T**")
+            }
+            _ => false,
+        }
+    }
+
+    /// The geometries are topologically equal: `T*F**FFF*`.
+    pub fn is_equal_topo(&self) -> bool {
+        self.matches_valid("T*F**FFF*")
+    }
+}
+
+fn index(position: CoordPos) -> usize {
+    match position {
+        CoordPos::Inside => 0,
+        CoordPos::OnBoundary => 1,
+        CoordPos::Outside => 2,
+    }
+}
+
+impl fmt::Display for IntersectionMatrix {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for dimensions in self.0.iter().flatten() {
+            let c = match dimensions {
+                Dimensions::Empty => 'F',
+                Dimensions::ZeroDimensional => '0',
+                Dimensions::OneDimensional => '1',
+                Dimensions::TwoDimensional => '2',
+            };
+            write!(f, "{}", c)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for IntersectionMatrix {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "IntersectionMatrix({})", self)
+    }
+}
+
+/// The pattern given to [`IntersectionMatrix::matches`] is not
+/// made of nine characters from `T`, `F`, `0`, `1`, `2` and `*`.
+#[derive(Eq, PartialEq, Debug)]
+pub struct InvalidPatternError;
+
+impl fmt::Display for InvalidPatternError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "intersection matrix pattern must be nine of 'T', 'F', '0', '1', '2' or '*'"
+        )
+    }
+}
+
+impl error::Error for InvalidPatternError {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn matrix(cells: &str) -> IntersectionMatrix {
+        let mut matrix = IntersectionMatrix([[Dimensions::Empty; 3]; 3]);
+        let positions = [CoordPos::Inside, CoordPos::OnBoundary, CoordPos::Outside];
+        for (idx, c) in cells.chars().enumerate() {
+            let dimensions = match c {
+                '0' => Dimensions::ZeroDimensional,
+                '1' => Dimensions::OneDimensional,
+                '2' => Dimensions::TwoDimensional,
+                _ => continue,
+            };
+            matrix.set_at_least(positions[idx / 3], positions[idx % 3], dimensions);
+        }
+        matrix
+    }
+
+    #[test]
+    fn display_round_trips() {
+        assert_eq!(matrix("212101212").to_string(), "212101212");
+        assert_eq!(matrix("FF2FF1212").to_string(), "FF2FF1212");
+        assert_eq!(IntersectionMatrix::empty().to_string(), "FFFFFFFF2");
+    }
+
+    #[test]
+    fn matches_patterns() {
+        let m = matrix("212101212");
+        assert!(m.matches("T*T***T**").unwrap());
+        assert!(m.matches("2********").unwrap());
+        assert!(!m.matches("1********").unwrap());
+        assert!(!m.matches("****F****").unwrap());
+        assert_eq!(m.matches("T*T"), Err(InvalidPatternError));
+        assert_eq!(m.matches("T*T***X**"), Err(InvalidPatternError));
+    }
+
+    #[test]
+    fn transposed() {
+        assert_eq!(matrix("1020F1102").transposed(), matrix("1010F0212"));
+    }
+
+    #[test]
+    fn named_predicates() {
+        // two overlapping polygons
+        let m = matrix("212101212");
+        assert!(m.is_intersects() && m.is_overlaps());
+        assert!(!m.is_within() && !m.is_contains() && !m.is_touches());
+
+        // a line crossing a polygon
+        let m = matrix("1010F0212");
+        assert!(m.is_crosses() && m.transposed().is_crosses());
+        assert!(!m.is_overlaps());
+
+        // a polygon within another, touching its boundary
+        let m = matrix("2FF11F212");
+        assert!(m.is_within() && m.is_coveredby());
+        assert!(m.transposed().is_contains() && m.transposed().is_covers());
+
+        // polygons sharing an edge
+        let m = matrix("FF2F11212");
+        assert!(m.is_touches() && !m.is_disjoint());
+
+        let m = matrix("FF2FF1212");
+        assert!(m.is_disjoint() && !m.is_touches());
+
+        assert!(matrix("2FFF1FFF2").is_equal_topo());
+    }
+}
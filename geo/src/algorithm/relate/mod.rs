@@ -0,0 +1,263 @@
+pub use intersection_matrix::{IntersectionMatrix, InvalidPatternError};
+
+use crate::{
+    GeoFloat, Geometry, GeometryCollection, Line, LineString, MultiLineString, MultiPoint,
+    MultiPolygon, Point, Polygon, Rect, Triangle,
+};
+use rstar::RTreeNum;
+
+/// Topologically relate two geometries based on
+/// [DE-9IM](https://en.wikipedia.org/wiki/DE-9IM) semantics.
+///
+/// The returned [`IntersectionMatrix`] describes how the
+/// interiors, boundaries and exteriors of both geometries
+/// intersect. It can be matched against a pattern, or queried
+/// with named predicates such as
+/// [`is_within`](IntersectionMatrix::is_within) or
+/// [`is_touches`](IntersectionMatrix::is_touches).
+///
+/// Boundaries follow the OGC rules, as for
+/// [`CoordinatePosition`](crate::algorithm::coordinate_position::CoordinatePosition):
+/// the boundary of a `LineString` is made of its endpoints,
+/// unless it is closed, and that of a `MultiLineString` of the
+/// endpoints shared by an odd number of its elements.
+///
+/// Points, lines and rings with a non-finite coordinate are
+/// ignored.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::relate::Relate;
+/// use geo::{line_string, polygon};
+///
+/// let square = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)];
+/// let neighbour = polygon![(x: 4., y: 1.), (x: 6., y: 1.), (x: 6., y: 3.), (x: 4., y: 3.)];
+/// let road = line_string![(x: -1., y: 2.), (x: 5., y: 2.)];
+///
+/// let matrix = square.relate(&neighbour);
+/// assert_eq!(matrix.to_string(), "FF2F11212");
+/// assert!(matrix.is_touches());
+///
+/// assert!(road.relate(&square).is_crosses());
+/// assert!(!road.relate(&neighbour).is_within());
+/// assert!(square.relate(&road).matches("T********").unwrap());
+/// ```
+pub trait Relate<Rhs = Self> {
+    fn relate(&self, other: &Rhs) -> IntersectionMatrix;
+}
+
+macro_rules! relate_impl {
+    ($($t:ident),* $(,)?) => {
+        relate_impl!(@lhs [$($t),*] [$($t),*]);
+    };
+    (@lhs [$($lhs:ident),*] $rhs:tt) => {
+        $(relate_impl!(@rhs $lhs $rhs);)*
+    };
+    (@rhs $lhs:ident [$($rhs:ident),*]) => {
+        $(
+            impl<T: GeoFloat + RTreeNum> Relate<$rhs<T>> for $lhs<T> {
+                fn relate(&self, other: &$rhs<T>) -> IntersectionMatrix {
+                    relate_operation::relate(self, other)
+                }
+            }
+        )*
+    };
+}
+
+relate_impl![
+    Point,
+    Line,
+    LineString,
+    Polygon,
+    MultiPoint,
+    MultiLineString,
+    MultiPolygon,
+    Rect,
+    Triangle,
+    GeometryCollection,
+    Geometry,
+];
+
+mod intersection_matrix;
+mod relate_operation;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, polygon, Coordinate};
+
+    fn assert_relate<A, B>(a: &A, b: &B, expected: &str)
+    where
+        A: Relate<B>,
+        B: Relate<A>,
+    {
+        let matrix = a.relate(b);
+        assert_eq!(matrix.to_string(), expected);
+        assert_eq!(b.relate(a), matrix.transposed());
+    }
+
+    #[test]
+    fn points() {
+        let p = point!(x: 1., y: 1.);
+        assert_relate(&p, &p, "0FFFFFFF2");
+        assert_relate(&p, &point!(x: 2., y: 1.), "FF0FFF0F2");
+
+        let mp = MultiPoint(vec![p, point!(x: 3., y: 3.)]);
+        assert_relate(&p, &mp, "0FFFFF0F2");
+        assert!(p.relate(&mp).is_within());
+        assert!(mp.relate(&mp).is_equal_topo());
+    }
+
+    #[test]
+    fn point_and_line_string() {
+        let ls = line_string![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.)];
+        assert_relate(&point!(x: 1., y: 0.), &ls, "0FFFFF102");
+        assert_relate(&point!(x: 2., y: 0.), &ls, "0FFFFF102");
+        assert_relate(&point!(x: 0., y: 0.), &ls, "F0FFFF102");
+        assert_relate(&point!(x: 1., y: 1.), &ls, "FF0FFF102");
+
+        // a closed line string has no boundary
+        let ring = line_string![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 0.)];
+        assert_relate(&point!(x: 0., y: 0.), &ring, "0FFFFF1F2");
+    }
+
+    #[test]
+    fn point_and_polygon() {
+        let poly = Rect::new((0., 0.), (2., 2.)).to_polygon();
+        assert_relate(&point!(x: 1., y: 1.), &poly, "0FFFFF212");
+        assert_relate(&point!(x: 0., y: 1.), &poly, "F0FFFF212");
+        assert_relate(&point!(x: 5., y: 1.), &poly, "FF0FFF212");
+    }
+
+    #[test]
+    fn line_strings() {
+        let a = line_string![(x: 0., y: 0.), (x: 2., y: 2.)];
+
+        // crossing
+        let b = line_string![(x: 0., y: 2.), (x: 2., y: 0.)];
+        assert_relate(&a, &b, "0F1FF0102");
+        assert!(a.relate(&b).is_crosses());
+
+        // touching at an endpoint
+        let b = line_string![(x: 2., y: 2.), (x: 3., y: 0.)];
+        assert_relate(&a, &b, "FF1F00102");
+        assert!(a.relate(&b).is_touches());
+
+        // overlapping
+        let b = line_string![(x: 1., y: 1.), (x: 3., y: 3.)];
+        assert_relate(&a, &b, "1010F0102");
+        assert!(a.relate(&b).is_overlaps());
+
+        // the same line, with an extra vertex
+        let b = line_string![(x: 2., y: 2.), (x: 1., y: 1.), (x: 0., y: 0.)];
+        assert_relate(&a, &b, "1FFF0FFF2");
+        assert!(a.relate(&b).is_equal_topo());
+
+        // within
+        let b = line_string![(x: -1., y: -1.), (x: 3., y: 3.)];
+        assert_relate(&a, &b, "1FF0FF102");
+        assert!(a.relate(&b).is_within());
+    }
+
+    #[test]
+    fn line_and_polygon() {
+        let poly = Rect::new((0., 0.), (4., 4.)).to_polygon();
+
+        assert_relate(&Line::new((1., 1.), (3., 3.)), &poly, "1FF0FF212");
+        assert_relate(&Line::new((0., 0.), (4., 0.)), &poly, "F1FF0F212");
+        assert_relate(&Line::new((2., 2.), (6., 2.)), &poly, "1010F0212");
+        assert_relate(&Line::new((-2., 2.), (6., 2.)), &poly, "101FF0212");
+        assert_relate(&Line::new((-2., 0.), (2., 0.)), &poly, "F11F00212");
+        assert!(Line::new((0., 0.), (4., 0.)).relate(&poly).is_touches());
+        assert!(poly.relate(&Line::new((1., 1.), (3., 3.))).is_contains());
+        assert!(Line::new((-2., 2.), (6., 2.)).relate(&poly).is_crosses());
+    }
+
+    #[test]
+    fn polygons() {
+        let a = Rect::new((0., 0.), (4., 4.)).to_polygon();
+
+        assert_relate(&a, &a, "2FFF1FFF2");
+        let overlapping = Rect::new((2., 2.), (6., 6.)).to_polygon();
+        let inside = Rect::new((1., 1.), (3., 3.)).to_polygon();
+        let in_a_corner = Rect::new((0., 0.), (2., 2.)).to_polygon();
+        assert_relate(&a, &overlapping, "212101212");
+        assert_relate(&a, &Rect::new((4., 0.), (8., 4.)).to_polygon(), "FF2F11212");
+        assert_relate(&a, &Rect::new((4., 4.), (8., 8.)).to_polygon(), "FF2F01212");
+        assert_relate(&a, &Rect::new((5., 5.), (6., 6.)).to_polygon(), "FF2FF1212");
+        assert_relate(&a, &inside, "212FF1FF2");
+        assert_relate(&a, &in_a_corner, "212F11FF2");
+
+        assert!(inside.relate(&a).is_within());
+        assert!(in_a_corner.relate(&a).is_coveredby());
+        assert!(a.relate(&overlapping).is_overlaps());
+    }
+
+    #[test]
+    fn polygon_with_hole() {
+        let a = polygon![
+            exterior: [(x: 0., y: 0.), (x: 6., y: 0.), (x: 6., y: 6.), (x: 0., y: 6.)],
+            interiors: [[(x: 2., y: 2.), (x: 4., y: 2.), (x: 4., y: 4.), (x: 2., y: 4.)]],
+        ];
+
+        // filling the hole exactly
+        assert_relate(&a, &Rect::new((2., 2.), (4., 4.)).to_polygon(), "FF2F112F2");
+        // in the hole, without touching it
+        assert_relate(&a, &point!(x: 3., y: 3.), "FF2FF10F2");
+        // spanning the hole
+        assert_relate(&a, &Rect::new((1., 1.), (5., 5.)).to_polygon(), "2121F12F2");
+    }
+
+    #[test]
+    fn multi_polygon_sharing_an_edge() {
+        // the shared edge lies in the interior of the multi polygon
+        let mp = MultiPolygon(vec![
+            Rect::new((0., 0.), (2., 2.)).to_polygon(),
+            Rect::new((2., 0.), (4., 2.)).to_polygon(),
+        ]);
+        let rect = Rect::new(Coordinate { x: 0., y: 0. }, Coordinate { x: 4., y: 2. });
+        assert_relate(&mp, &rect, "2FFF1FFF2");
+        assert_relate(&Line::new((2., 0.5), (2., 1.5)), &mp, "1FF0FF212");
+    }
+
+    #[test]
+    fn empty_geometries() {
+        let empty = MultiPoint::<f64>(vec![]);
+        assert_relate(&empty, &empty, "FFFFFFFF2");
+        let square = Rect::new((0., 0.), (1., 1.)).to_polygon();
+        assert_relate(&empty, &square, "FFFFFF212");
+        assert!(empty.relate(&square).is_disjoint());
+    }
+
+    #[test]
+    fn geometry_collections() {
+        let collection = GeometryCollection(vec![
+            Geometry::Point(point!(x: 10., y: 10.)),
+            Geometry::Polygon(Rect::new((0., 0.), (2., 2.)).to_polygon()),
+        ]);
+        let geometry = Geometry::Polygon(Rect::new((1., 1.), (3., 3.)).to_polygon());
+        assert_relate(&collection, &geometry, "212101212");
+        assert_relate(
+            &collection,
+            &Geometry::Point(point!(x: 10., y: 10.)),
+            "0F2FF1FF2",
+        );
+    }
+
+    #[test]
+    fn non_finite_coords() {
+        let square = Rect::new((0., 0.), (4., 4.)).to_polygon();
+        let nan_vertex = polygon![(x: 1., y: 1.), (x: f64::NAN, y: 2.), (x: 2., y: 2.)];
+        assert_relate(&square, &nan_vertex, "FF2FF1FF2");
+        let nan_hole = Polygon::new(
+            square.exterior().clone(),
+            vec![line_string![(x: 1., y: 1.), (x: 2., y: f64::INFINITY), (x: 2., y: 2.)]],
+        );
+        assert_relate(&square, &nan_hole, "2FFF1FFF2");
+        let points = MultiPoint(vec![point!(x: 1., y: 1.), point!(x: f64::NAN, y: 1.)]);
+        assert_relate(&points, &square, "0FFFFF212");
+        let line = line_string![(x: -1., y: 2.), (x: f64::NAN, y: 2.), (x: 5., y: 2.)];
+        assert_relate(&line, &square, "FFFFFF212");
+    }
+}
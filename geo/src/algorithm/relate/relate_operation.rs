@@ -0,0 +1,447 @@
+use super::IntersectionMatrix;
+use crate::algorithm::coordinate_position::{CoordPos, CoordinatePosition};
+use crate::algorithm::dimensions::Dimensions;
+use crate::algorithm::noding::node_segments;
+use crate::algorithm::winding_order::{Winding, WindingOrder};
+use crate::utils::lex_cmp;
+use crate::{
+    Coordinate, GeoFloat, Geometry, GeometryCollection, Line, LineString, MultiLineString,
+    MultiPoint, MultiPolygon, Point, Polygon, Rect, Triangle,
+};
+use rstar::RTreeNum;
+use std::cmp::Ordering;
+
+/// Compute the intersection matrix of two geometries.
+///
+/// The linework of both geometries is noded together, which
+/// splits the plane into cells: the vertices of the noded
+/// linework, the open edges between them, and the faces on
+/// either side of each edge. Every cell lies entirely within
+/// one part (interior, boundary or exterior) of each geometry,
+/// so classifying one point of each cell, and recording the
+/// cell's dimension in the matching entry, fills the matrix.
+pub(crate) fn relate<T, A, B>(a: &A, b: &B) -> IntersectionMatrix
+where
+    T: GeoFloat + RTreeNum,
+    A: AddComponents<T>,
+    B: AddComponents<T>,
+{
+    let mut components = [Components::new(), Components::new()];
+    a.add_components(&mut components[0]);
+    b.add_components(&mut components[1]);
+    let [a, b] = &components;
+
+    let mut matrix = IntersectionMatrix::empty();
+
+    // Isolated points
+    for point in a.points.iter().chain(&b.points) {
+        matrix.set_at_least(
+            a.position(point),
+            b.position(point),
+            Dimensions::ZeroDimensional,
+        );
+    }
+
+    let mut segments = vec![];
+    for (operand, components) in components.iter().enumerate() {
+        components.push_segments(operand, &mut segments);
+    }
+
+    // Edges, and the faces on either side of them
+    let mut vertices: Vec<(Coordinate<T>, [Option<CoordPos>; 2])> = vec![];
+    for_each_edge(&node_segments(&segments), |line, labels| {
+        let mid = (line.start + line.end) / (T::one() + T::one());
+        let mut on_edge = [CoordPos::Outside, CoordPos::Outside];
+        let mut left = [CoordPos::Outside, CoordPos::Outside];
+        let mut right = [CoordPos::Outside, CoordPos::Outside];
+        for (operand, label) in labels.iter().enumerate() {
+            let components = &components[operand];
+            on_edge[operand] = if label.ring_count != 0 {
+                CoordPos::OnBoundary
+            } else if label.on_linework {
+                CoordPos::Inside
+            } else {
+                components.area_position(&mid)
+            };
+            let (l, r) = match label.ring_count.cmp(&0) {
+                Ordering::Greater => (CoordPos::Inside, CoordPos::Outside),
+                Ordering::Less => (CoordPos::Outside, CoordPos::Inside),
+                // Rings running both ways along the edge separate
+                // adjacent polygons, which cover both its sides.
+                Ordering::Equal if label.on_ring => (CoordPos::Inside, CoordPos::Inside),
+                Ordering::Equal => {
+                    let pos = components.area_position(&mid);
+                    (pos, pos)
+                }
+            };
+            left[operand] = l;
+            right[operand] = r;
+        }
+        let [on_a, on_b] = on_edge;
+        let [left_a, left_b] = left;
+        let [right_a, right_b] = right;
+        matrix.set_at_least(left_a, left_b, Dimensions::TwoDimensional);
+        matrix.set_at_least(right_a, right_b, Dimensions::TwoDimensional);
+
+        // Remember how the edge lies on each geometry's linework,
+        // for the positions of computed vertices below.
+        let incident = [
+            Some(on_a).filter(|_| labels[0].on_linework),
+            Some(on_b).filter(|_| labels[1].on_linework),
+        ];
+        vertices.push((line.start, incident));
+        vertices.push((line.end, incident));
+        matrix.set_at_least(on_a, on_b, Dimensions::OneDimensional);
+    });
+
+    // Vertices
+    vertices.sort_by(|(p, _), (q, _)| lex_cmp(p, q));
+    let original_coords = [a.sorted_coords(), b.sorted_coords()];
+    let mut iter = vertices.into_iter().peekable();
+    while let Some((vertex, mut incident)) = iter.next() {
+        while let Some((_, other)) = iter.next_if(|(next, _)| *next == vertex) {
+            for (incident, other) in incident.iter_mut().zip(other) {
+                *incident = merge_incident(incident.take(), other);
+            }
+        }
+        let mut position = [CoordPos::Outside, CoordPos::Outside];
+        for operand in 0..2 {
+            let components = &components[operand];
+            // A vertex on the boundary of any incident edge is on the
+            // boundary of the geometry. Other vertices not found in the
+            // input were computed where edges cross, and lie within the
+            // edges incident to them.
+            let is_original = original_coords[operand]
+                .binary_search_by(|c| lex_cmp(c, &vertex))
+                .is_ok();
+            position[operand] = match incident[operand].take() {
+                Some(CoordPos::OnBoundary) => CoordPos::OnBoundary,
+                Some(incident) if !is_original => incident,
+                _ => components.position(&vertex),
+            };
+        }
+        let [pos_a, pos_b] = position;
+        matrix.set_at_least(pos_a, pos_b, Dimensions::ZeroDimensional);
+    }
+
+    matrix
+}
+
+// Where a vertex lies on several edges of the same geometry,
+// being on the boundary of any of them puts it on the
+// boundary of the geometry.
+fn merge_incident(lhs: Option<CoordPos>, rhs: Option<CoordPos>) -> Option<CoordPos> {
+    match (lhs, rhs) {
+        (Some(CoordPos::OnBoundary), _) | (_, Some(CoordPos::OnBoundary)) => {
+            Some(CoordPos::OnBoundary)
+        }
+        (Some(pos), _) | (None, Some(pos)) => Some(pos),
+        (None, None) => None,
+    }
+}
+
+// How a noded segment lies on the linework of a geometry: as
+// (part of) a line, or on a ring with the polygon's interior
+// to its left (`+1`) or right (`-1`).
+#[derive(Debug, Clone, Copy)]
+struct SegmentLabel {
+    operand: usize,
+    ring_sign: i32,
+}
+
+// How an edge lies on the linework of one geometry, after
+// merging all the geometry's segments running along it.
+#[derive(Debug, Clone, Copy, Default)]
+struct EdgeLabel {
+    on_linework: bool,
+    on_ring: bool,
+    ring_count: i32,
+}
+
+// Merge identical noded segments into edges, directed from
+// their lexicographically lesser to their greater endpoint,
+// and call `f` with the edge's label for each geometry.
+fn for_each_edge<T, F>(noded: &[(Line<T>, SegmentLabel)], mut f: F)
+where
+    T: GeoFloat,
+    F: FnMut(Line<T>, [EdgeLabel; 2]),
+{
+    let mut directed: Vec<(Line<T>, SegmentLabel)> = noded
+        .iter()
+        .map(|&(line, label)| {
+            if lex_cmp(&line.start, &line.end) == Ordering::Greater {
+                let label = SegmentLabel {
+                    ring_sign: -label.ring_sign,
+                    ..label
+                };
+                (Line::new(line.end, line.start), label)
+            } else {
+                (line, label)
+            }
+        })
+        .collect();
+    directed.sort_by(|(p, _), (q, _)| {
+        lex_cmp(&p.start, &q.start).then_with(|| lex_cmp(&p.end, &q.end))
+    });
+
+    let mut iter = directed.into_iter().peekable();
+    while let Some((line, label)) = iter.next() {
+        let mut labels = [EdgeLabel::default(); 2];
+        add_label(&mut labels, label);
+        while let Some((_, label)) = iter.next_if(|(next, _)| *next == line) {
+            add_label(&mut labels, label);
+        }
+        f(line, labels);
+    }
+}
+
+fn add_label(labels: &mut [EdgeLabel; 2], label: SegmentLabel) {
+    let edge_label = &mut labels[label.operand];
+    edge_label.on_linework = true;
+    edge_label.on_ring |= label.ring_sign != 0;
+    edge_label.ring_count += label.ring_sign;
+}
+
+/// The points, lines and polygons making up a geometry,
+/// leaving out those with a non-finite coordinate.
+pub(crate) struct Components<T: GeoFloat> {
+    points: Vec<Coordinate<T>>,
+    line_strings: Vec<LineString<T>>,
+    polygons: Vec<Polygon<T>>,
+}
+
+impl<T: GeoFloat> Components<T> {
+    fn new() -> Self {
+        Components {
+            points: vec![],
+            line_strings: vec![],
+            polygons: vec![],
+        }
+    }
+
+    fn add_point(&mut self, coord: Coordinate<T>) {
+        if is_finite(&coord) {
+            self.points.push(coord);
+        }
+    }
+
+    fn add_line_string(&mut self, line_string: &LineString<T>) {
+        if !line_string.0.iter().all(is_finite) {
+            return;
+        }
+        match line_string.0.first() {
+            None => {}
+            Some(first) if line_string.0.iter().all(|c| c == first) => self.points.push(*first),
+            Some(_) => self.line_strings.push(line_string.clone()),
+        }
+    }
+
+    fn add_polygon(&mut self, polygon: &Polygon<T>) {
+        if !polygon.exterior().0.iter().all(is_finite) {
+            return;
+        }
+        // Polygons without area are only made of their linework.
+        if polygon.exterior().winding_order().is_none() {
+            self.add_line_string(polygon.exterior());
+            return;
+        }
+        let interiors = polygon
+            .interiors()
+            .iter()
+            .filter(|interior| {
+                interior.0.iter().all(is_finite) && interior.winding_order().is_some()
+            })
+            .cloned()
+            .collect();
+        self.polygons
+            .push(Polygon::new(polygon.exterior().clone(), interiors));
+    }
+
+    fn push_segments(&self, operand: usize, segments: &mut Vec<(Line<T>, SegmentLabel)>) {
+        for line_string in &self.line_strings {
+            let label = SegmentLabel {
+                operand,
+                ring_sign: 0,
+            };
+            segments.extend(line_string.lines().map(|line| (line, label)));
+        }
+        for polygon in &self.polygons {
+            push_ring_segments(
+                polygon.exterior(),
+                WindingOrder::CounterClockwise,
+                operand,
+                segments,
+            );
+            for interior in polygon.interiors() {
+                push_ring_segments(interior, WindingOrder::Clockwise, operand, segments);
+            }
+        }
+    }
+
+    fn sorted_coords(&self) -> Vec<Coordinate<T>> {
+        let mut coords: Vec<Coordinate<T>> = self
+            .points
+            .iter()
+            .copied()
+            .chain(self.line_strings.iter().flat_map(|ls| ls.0.iter().copied()))
+            .chain(self.polygons.iter().flat_map(|polygon| {
+                polygon
+                    .exterior()
+                    .0
+                    .iter()
+                    .chain(polygon.interiors().iter().flat_map(|ring| ring.0.iter()))
+                    .copied()
+            }))
+            .collect();
+        coords.sort_by(lex_cmp);
+        coords.dedup();
+        coords
+    }
+
+    // The position of `coord` relative to the whole geometry,
+    // following the rules of `CoordinatePosition`.
+    fn position(&self, coord: &Coordinate<T>) -> CoordPos {
+        let mut is_inside = self.points.contains(coord);
+        let mut boundary_count = 0;
+        for line_string in &self.line_strings {
+            line_string.calculate_coordinate_position(coord, &mut is_inside, &mut boundary_count);
+        }
+        for polygon in &self.polygons {
+            polygon.calculate_coordinate_position(coord, &mut is_inside, &mut boundary_count);
+        }
+        resolve_position(is_inside, boundary_count)
+    }
+
+    // The position of `coord` relative to the polygons of the
+    // geometry only. Used for points off the geometry's linework.
+    fn area_position(&self, coord: &Coordinate<T>) -> CoordPos {
+        let mut is_inside = false;
+        let mut boundary_count = 0;
+        for polygon in &self.polygons {
+            polygon.calculate_coordinate_position(coord, &mut is_inside, &mut boundary_count);
+        }
+        match resolve_position(is_inside, boundary_count) {
+            CoordPos::Inside => CoordPos::Inside,
+            _ => CoordPos::Outside,
+        }
+    }
+}
+
+fn is_finite<T: GeoFloat>(coord: &Coordinate<T>) -> bool {
+    coord.x.is_finite() && coord.y.is_finite()
+}
+
+fn resolve_position(is_inside: bool, boundary_count: usize) -> CoordPos {
+    if boundary_count % 2 == 1 {
+        CoordPos::OnBoundary
+    } else if is_inside {
+        CoordPos::Inside
+    } else {
+        CoordPos::Outside
+    }
+}
+
+fn push_ring_segments<T: GeoFloat>(
+    ring: &LineString<T>,
+    interior_on_left: WindingOrder,
+    operand: usize,
+    segments: &mut Vec<(Line<T>, SegmentLabel)>,
+) {
+    let ring_sign = match ring.winding_order() {
+        Some(winding_order) if winding_order == interior_on_left => 1,
+        Some(_) => -1,
+        None => return,
+    };
+    let label = SegmentLabel { operand, ring_sign };
+    segments.extend(ring.lines().map(|line| (line, label)));
+}
+
+/// Geometries which can be broken down into `Components`.
+pub(crate) trait AddComponents<T: GeoFloat> {
+    fn add_components(&self, components: &mut Components<T>);
+}
+
+impl<T: GeoFloat> AddComponents<T> for Point<T> {
+    fn add_components(&self, components: &mut Components<T>) {
+        components.add_point(self.0);
+    }
+}
+
+impl<T: GeoFloat> AddComponents<T> for Line<T> {
+    fn add_components(&self, components: &mut Components<T>) {
+        components.add_line_string(&LineString(vec![self.start, self.end]));
+    }
+}
+
+impl<T: GeoFloat> AddComponents<T> for LineString<T> {
+    fn add_components(&self, components: &mut Components<T>) {
+        components.add_line_string(self);
+    }
+}
+
+impl<T: GeoFloat> AddComponents<T> for Polygon<T> {
+    fn add_components(&self, components: &mut Components<T>) {
+        components.add_polygon(self);
+    }
+}
+
+impl<T: GeoFloat> AddComponents<T> for MultiPoint<T> {
+    fn add_components(&self, components: &mut Components<T>) {
+        for point in self {
+            components.add_point(point.0);
+        }
+    }
+}
+
+impl<T: GeoFloat> AddComponents<T> for MultiLineString<T> {
+    fn add_components(&self, components: &mut Components<T>) {
+        for line_string in self {
+            components.add_line_string(line_string);
+        }
+    }
+}
+
+impl<T: GeoFloat> AddComponents<T> for MultiPolygon<T> {
+    fn add_components(&self, components: &mut Components<T>) {
+        for polygon in self {
+            components.add_polygon(polygon);
+        }
+    }
+}
+
+impl<T: GeoFloat> AddComponents<T> for Rect<T> {
+    fn add_components(&self, components: &mut Components<T>) {
+        components.add_polygon(&self.to_polygon());
+    }
+}
+
+impl<T: GeoFloat> AddComponents<T> for Triangle<T> {
+    fn add_components(&self, components: &mut Components<T>) {
+        components.add_polygon(&self.to_polygon());
+    }
+}
+
+impl<T: GeoFloat> AddComponents<T> for GeometryCollection<T> {
+    fn add_components(&self, components: &mut Components<T>) {
+        for geometry in self {
+            geometry.add_components(components);
+        }
+    }
+}
+
+impl<T: GeoFloat> AddComponents<T> for Geometry<T> {
+    fn add_components(&self, components: &mut Components<T>) {
+        match self {
+            Geometry::Point(g) => g.add_components(components),
+            Geometry::Line(g) => g.add_components(components),
+            Geometry::LineString(g) => g.add_components(components),
+            Geometry::Polygon(g) => g.add_components(components),
+            Geometry::MultiPoint(g) => g.add_components(components),
+            Geometry::MultiLineString(g) => g.add_components(components),
+            Geometry::MultiPolygon(g) => g.add_components(components),
+            Geometry::GeometryCollection(g) => g.add_components(components),
+            Geometry::Rect(g) => g.add_components(components),
+            Geometry::Triangle(g) => g.add_components(components),
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use super::RhumbCalculations;
+use crate::{CoordFloat, Point};
+use num_traits::FromPrimitive;
+
+/// Returns the constant bearing of the [rhumb line] to another Point, in degrees.
+///
+/// [rhumb line]: https://en.wikipedia.org/wiki/Rhumb_line
+pub trait RhumbBearing<T: CoordFloat> {
+    /// Returns the bearing of the rhumb line to another Point in degrees, where North is 0° and
+    /// East is 90°.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// #
+    /// use geo::algorithm::rhumb::RhumbBearing;
+    /// use geo::Point;
+    ///
+    /// let p_1 = Point::<f64>::new(9.177789688110352, 48.776781529534965);
+    /// let p_2 = Point::<f64>::new(9.274348757829898, 48.84037308229984);
+    /// let bearing = p_1.rhumb_bearing(p_2);
+    /// assert_relative_eq!(bearing, 45., epsilon = 1.0e-6);
+    /// ```
+    fn rhumb_bearing(&self, point: Point<T>) -> T;
+}
+
+impl<T> RhumbBearing<T> for Point<T>
+where
+    T: CoordFloat + FromPrimitive,
+{
+    fn rhumb_bearing(&self, point: Point<T>) -> T {
+        RhumbCalculations::new(self, &point).theta().to_degrees()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::algorithm::rhumb::{RhumbBearing, RhumbDestination};
+    use crate::point;
+
+    #[test]
+    fn returns_the_proper_bearing_to_another_point() {
+        let p_1 = point!(x: 9.177789688110352f64, y: 48.776781529534965);
+        let p_2 = p_1.rhumb_destination(45., 10000.);
+        assert_relative_eq!(p_1.rhumb_bearing(p_2), 45., epsilon = 1.0e-6);
+
+        let p_3 = point!(x: 9., y: 47.);
+        let p_4 = point!(x: 9., y: 48.);
+        assert_relative_eq!(p_3.rhumb_bearing(p_4), 0., epsilon = 1.0e-6);
+    }
+
+    #[test]
+    fn across_the_antimeridian() {
+        let p_1 = point!(x: 179.5f64, y: 0.);
+        let p_2 = point!(x: -179.5, y: 0.);
+        assert_relative_eq!(p_1.rhumb_bearing(p_2), 90., epsilon = 1.0e-6);
+        assert_relative_eq!(p_2.rhumb_bearing(p_1), -90., epsilon = 1.0e-6);
+    }
+
+    #[test]
+    fn to_the_poles() {
+        let p = point!(x: 20f64, y: 10.);
+        assert_relative_eq!(p.rhumb_bearing(point!(x: -150., y: 90.)), 0.);
+        assert_relative_eq!(p.rhumb_bearing(point!(x: 100., y: -90.)), 180.);
+    }
+}
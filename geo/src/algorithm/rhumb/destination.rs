@@ -0,0 +1,89 @@
+use super::destination;
+use crate::{CoordFloat, Point};
+use num_traits::FromPrimitive;
+
+/// Returns a new Point using the distance to the existing Point and a constant bearing for the
+/// direction, along a [rhumb line].
+///
+/// A rhumb line which reaches a pole stops there.
+///
+/// *Note*: this implementation uses a mean earth radius of 6371.088 km, based on the [recommendation of
+/// the IUGG](ftp://athena.fsv.cvut.cz/ZFG/grs80-Moritz.pdf)
+///
+/// [rhumb line]: https://en.wikipedia.org/wiki/Rhumb_line
+pub trait RhumbDestination<T: CoordFloat> {
+    /// Returns a new Point using distance to the existing Point and a constant bearing for the
+    /// direction
+    ///
+    /// # Units
+    ///
+    /// - `bearing`: degrees
+    /// - `distance`: meters
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// #
+    /// use geo::algorithm::rhumb::RhumbDestination;
+    /// use geo::Point;
+    ///
+    /// let p_1 = Point::<f64>::new(9.177789688110352, 48.776781529534965);
+    /// let p_2 = p_1.rhumb_destination(45., 10000.);
+    /// assert_relative_eq!(p_2, Point::new(9.274348757829898, 48.84037308229984), epsilon = 1.0e-6);
+    /// ```
+    fn rhumb_destination(&self, bearing: T, distance: T) -> Point<T>;
+}
+
+impl<T> RhumbDestination<T> for Point<T>
+where
+    T: CoordFloat + FromPrimitive,
+{
+    fn rhumb_destination(&self, bearing: T, distance: T) -> Point<T> {
+        destination(self, bearing.to_radians(), distance)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::algorithm::rhumb::{RhumbDestination, RhumbDistance};
+    use crate::Point;
+
+    #[test]
+    fn returns_a_new_point() {
+        let p_1 = Point::<f64>::new(9.177789688110352, 48.776781529534965);
+        let p_2 = p_1.rhumb_destination(45., 10000.);
+        assert_relative_eq!(p_1.rhumb_distance(&p_2), 10000., epsilon = 1.0e-6);
+    }
+
+    #[test]
+    fn known_value() {
+        // from http://www.movable-type.co.uk/scripts/latlong.html
+        let p_1 = Point::<f64>::new(1. + 20. / 60. + 17. / 3600., 51. + 7. / 60. + 32. / 3600.);
+        let bearing = 116. + 38. / 60. + 10. / 3600.;
+        let p_2 = p_1.rhumb_destination(bearing, 40_230.);
+        let expected = Point::new(1. + 51. / 60. + 9. / 3600., 50. + 57. / 60. + 48. / 3600.);
+        assert_relative_eq!(p_2, expected, epsilon = 1.0e-3);
+    }
+
+    #[test]
+    fn across_the_antimeridian() {
+        let p_1 = Point::<f64>::new(179.5, 0.);
+        let p_2 = p_1.rhumb_destination(90., 111_195.08023353283);
+        assert_relative_eq!(p_2, Point::new(-179.5, 0.), epsilon = 1.0e-6);
+        let p_3 = p_2.rhumb_destination(-90., 111_195.08023353283);
+        assert_relative_eq!(p_3, p_1, epsilon = 1.0e-6);
+    }
+
+    #[test]
+    fn stops_at_the_pole() {
+        let p_1 = Point::<f64>::new(10., 80.);
+        let p_2 = p_1.rhumb_destination(30., 5_000_000.);
+        assert_eq!(p_2.lat(), 90.);
+        let p_3 = p_1.rhumb_destination(180., 20_000_000.);
+        assert_eq!(p_3.lat(), -90.);
+
+        let p_4 = Point::<f64>::new(10., 90.).rhumb_destination(180., 111_195.08023353283);
+        assert_relative_eq!(p_4, Point::new(10., 89.), epsilon = 1.0e-6);
+    }
+}
@@ -0,0 +1,106 @@
+use super::RhumbCalculations;
+use crate::{CoordFloat, Point, MEAN_EARTH_RADIUS};
+use num_traits::FromPrimitive;
+
+/// Determine the distance between two geometries along a [rhumb line].
+///
+/// [rhumb line]: https://en.wikipedia.org/wiki/Rhumb_line
+///
+/// *Note*: this implementation uses a mean earth radius of 6371.088 km, based on the [recommendation of
+/// the IUGG](ftp://athena.fsv.cvut.cz/ZFG/grs80-Moritz.pdf)
+pub trait RhumbDistance<T, Rhs = Self> {
+    /// Determine the distance between two geometries along a [rhumb line].
+    ///
+    /// # Units
+    ///
+    /// - return value: meters
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::rhumb::RhumbDistance;
+    /// use geo::point;
+    ///
+    /// // New York City
+    /// let p1 = point!(x: -74.006f64, y: 40.7128f64);
+    ///
+    /// // London
+    /// let p2 = point!(x: -0.1278f64, y: 51.5074f64);
+    ///
+    /// let distance = p1.rhumb_distance(&p2);
+    ///
+    /// assert_eq!(
+    ///     5_794_129., // meters
+    ///     distance.round()
+    /// );
+    /// ```
+    ///
+    /// [rhumb line]: https://en.wikipedia.org/wiki/Rhumb_line
+    fn rhumb_distance(&self, rhs: &Rhs) -> T;
+}
+
+impl<T> RhumbDistance<T, Point<T>> for Point<T>
+where
+    T: CoordFloat + FromPrimitive,
+{
+    fn rhumb_distance(&self, rhs: &Point<T>) -> T {
+        RhumbCalculations::new(self, rhs).delta() * T::from(MEAN_EARTH_RADIUS).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::algorithm::haversine_distance::HaversineDistance;
+    use crate::algorithm::rhumb::RhumbDistance;
+    use crate::Point;
+
+    #[test]
+    fn along_the_equator_and_meridians() {
+        // rhumb lines and great circles coincide there
+        let a = Point::<f64>::new(0., 0.);
+        let b = Point::<f64>::new(1., 0.);
+        assert_relative_eq!(
+            a.rhumb_distance(&b),
+            a.haversine_distance(&b),
+            epsilon = 1.0e-6
+        );
+        let c = Point::<f64>::new(10., -20.);
+        let d = Point::<f64>::new(10., 60.);
+        assert_relative_eq!(
+            c.rhumb_distance(&d),
+            c.haversine_distance(&d),
+            epsilon = 1.0e-6
+        );
+    }
+
+    #[test]
+    fn known_value() {
+        // from http://www.movable-type.co.uk/scripts/latlong.html
+        let a = Point::<f64>::new(-(4. + 8. / 60. + 2. / 3600.), 50. + 21. / 60. + 59. / 3600.);
+        let b = Point::<f64>::new(
+            -(71. + 2. / 60. + 27. / 3600.),
+            42. + 21. / 60. + 4. / 3600.,
+        );
+        assert_relative_eq!(a.rhumb_distance(&b) / 1000., 5198., epsilon = 1.);
+    }
+
+    #[test]
+    fn across_the_antimeridian() {
+        let a = Point::<f64>::new(179., 10.);
+        let b = Point::<f64>::new(-179., 10.);
+        let c = Point::<f64>::new(1., 10.);
+        let d = Point::<f64>::new(3., 10.);
+        assert_relative_eq!(a.rhumb_distance(&b), c.rhumb_distance(&d), epsilon = 1.0e-6);
+    }
+
+    #[test]
+    fn to_a_pole() {
+        let a = Point::<f64>::new(30., 80.);
+        let b = Point::<f64>::new(-100., 90.);
+        assert_relative_eq!(
+            a.rhumb_distance(&b),
+            a.haversine_distance(&Point::new(30., 90.)),
+            epsilon = 1.0e-3
+        );
+    }
+}
@@ -0,0 +1,128 @@
+use super::RhumbCalculations;
+use crate::{CoordFloat, Point, MEAN_EARTH_RADIUS};
+use num_traits::FromPrimitive;
+
+/// Returns a new Point along a [rhumb line] between two existing points.
+///
+/// [rhumb line]: https://en.wikipedia.org/wiki/Rhumb_line
+pub trait RhumbIntermediate<T: CoordFloat> {
+    /// Returns a new Point along a rhumb line between two existing points.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// #
+    /// use geo::algorithm::rhumb::RhumbIntermediate;
+    /// use geo::Point;
+    ///
+    /// let p1 = Point::<f64>::new(10.0, 20.0);
+    /// let p2 = Point::<f64>::new(125.0, 25.0);
+    /// let i50 = p1.rhumb_intermediate(&p2, 0.5);
+    /// // halfway along a rhumb line is also halfway in latitude
+    /// assert_relative_eq!(i50.x(), 66.98, epsilon = 0.01);
+    /// assert_relative_eq!(i50.y(), 22.5, epsilon = 1.0e-6);
+    /// ```
+    fn rhumb_intermediate(&self, other: &Point<T>, f: T) -> Point<T>;
+
+    /// Returns the points along a rhumb line between two existing points, no more than
+    /// `max_dist` meters apart.
+    fn rhumb_intermediate_fill(
+        &self,
+        other: &Point<T>,
+        max_dist: T,
+        include_ends: bool,
+    ) -> Vec<Point<T>>;
+}
+
+impl<T> RhumbIntermediate<T> for Point<T>
+where
+    T: CoordFloat + FromPrimitive,
+{
+    fn rhumb_intermediate(&self, other: &Point<T>, f: T) -> Point<T> {
+        RhumbCalculations::new(self, other).intermediate(f)
+    }
+
+    fn rhumb_intermediate_fill(
+        &self,
+        other: &Point<T>,
+        max_dist: T,
+        include_ends: bool,
+    ) -> Vec<Point<T>> {
+        let calculations = RhumbCalculations::new(self, other);
+        let total_distance = calculations.delta() * T::from(MEAN_EARTH_RADIUS).unwrap();
+
+        if total_distance <= max_dist {
+            if include_ends {
+                return vec![*self, *other];
+            } else {
+                return vec![];
+            }
+        }
+
+        let number_of_points = (total_distance / max_dist).ceil();
+
+        let mut points = if include_ends { vec![*self] } else { vec![] };
+
+        for i in 1..number_of_points.to_usize().unwrap_or(1) {
+            let current_step = T::from(i).unwrap() / number_of_points;
+            points.push(calculations.intermediate(current_step));
+        }
+
+        if include_ends {
+            points.push(*other);
+        }
+
+        points
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::rhumb::{RhumbBearing, RhumbDistance};
+
+    #[test]
+    fn f_is_zero_or_one_test() {
+        let p1 = Point::<f64>::new(10.0, 20.0);
+        let p2 = Point::<f64>::new(15.0, 25.0);
+        let i0 = p1.rhumb_intermediate(&p2, 0.0);
+        let i100 = p1.rhumb_intermediate(&p2, 1.0);
+        assert_relative_eq!(i0, p1, epsilon = 1.0e-6);
+        assert_relative_eq!(i100, p2, epsilon = 1.0e-6);
+    }
+
+    #[test]
+    fn keeps_a_constant_bearing() {
+        let p1 = Point::<f64>::new(170.0, -20.0);
+        let p2 = Point::<f64>::new(-160.0, 30.0);
+        let bearing = p1.rhumb_bearing(p2);
+        let i30 = p1.rhumb_intermediate(&p2, 0.3);
+        assert_relative_eq!(p1.rhumb_bearing(i30), bearing, epsilon = 1.0e-6);
+        assert_relative_eq!(i30.rhumb_bearing(p2), bearing, epsilon = 1.0e-6);
+        assert_relative_eq!(
+            p1.rhumb_distance(&i30),
+            0.3 * p1.rhumb_distance(&p2),
+            epsilon = 1.0e-3
+        );
+    }
+
+    #[test]
+    fn fill() {
+        let p1 = Point::<f64>::new(0.0, 0.0);
+        let p2 = Point::<f64>::new(1.0, 1.0);
+        let distance = p1.rhumb_distance(&p2);
+
+        let points = p1.rhumb_intermediate_fill(&p2, distance / 4., true);
+        assert_eq!(points.len(), 5);
+        assert_eq!(points[0], p1);
+        assert_eq!(points[4], p2);
+        assert_relative_eq!(points[2], p1.rhumb_intermediate(&p2, 0.5), epsilon = 1.0e-9);
+
+        assert_eq!(p1.rhumb_intermediate_fill(&p2, distance, false), vec![]);
+        assert_eq!(
+            p1.rhumb_intermediate_fill(&p2, distance, true),
+            vec![p1, p2]
+        );
+    }
+}
@@ -0,0 +1,150 @@
+//! Distances, bearings, destinations and intermediate points along
+//! [rhumb lines], also known as loxodromes: routes which cross every
+//! meridian at the same angle, so that they keep a constant bearing.
+//!
+//! Rhumb lines take the shortest way around the antimeridian. A
+//! rhumb line which reaches a pole stops there.
+//!
+//! *Note*: this implementation uses a mean earth radius of 6371.088 km, based on the [recommendation of
+//! the IUGG](ftp://athena.fsv.cvut.cz/ZFG/grs80-Moritz.pdf)
+//!
+//! [rhumb lines]: https://en.wikipedia.org/wiki/Rhumb_line
+
+use crate::{CoordFloat, Point, MEAN_EARTH_RADIUS};
+use num_traits::FromPrimitive;
+
+mod bearing;
+mod destination;
+mod distance;
+mod intermediate;
+
+pub use bearing::RhumbBearing;
+pub use destination::RhumbDestination;
+pub use distance::RhumbDistance;
+pub use intermediate::RhumbIntermediate;
+
+/// The rhumb line between two points, in radians.
+pub(crate) struct RhumbCalculations<T: CoordFloat + FromPrimitive> {
+    from: Point<T>,
+    phi1: T,
+    delta_lambda: T,
+    delta_phi: T,
+    delta_psi: T,
+}
+
+impl<T: CoordFloat + FromPrimitive> RhumbCalculations<T> {
+    pub(crate) fn new(from: &Point<T>, to: &Point<T>) -> Self {
+        let pi = T::from(std::f64::consts::PI).unwrap();
+        let two_pi = pi + pi;
+        let phi1 = from.y().to_radians();
+        let phi2 = to.y().to_radians();
+
+        // Take the shortest way around the antimeridian.
+        let mut delta_lambda = (to.x() - from.x()).to_radians();
+        if delta_lambda > pi {
+            delta_lambda = delta_lambda - two_pi;
+        } else if delta_lambda < -pi {
+            delta_lambda = delta_lambda + two_pi;
+        }
+
+        // Both points may lie at the same pole.
+        let delta_psi = if phi1 == phi2 {
+            T::zero()
+        } else {
+            projected_latitude(phi2) - projected_latitude(phi1)
+        };
+
+        RhumbCalculations {
+            from: *from,
+            phi1,
+            delta_lambda,
+            delta_phi: phi2 - phi1,
+            delta_psi,
+        }
+    }
+
+    /// The angular distance along the rhumb line.
+    pub(crate) fn delta(&self) -> T {
+        let q = stretch(self.delta_phi, self.delta_psi, self.phi1);
+        (self.delta_phi.powi(2) + (q * self.delta_lambda).powi(2)).sqrt()
+    }
+
+    /// The bearing of the rhumb line, in radians.
+    pub(crate) fn theta(&self) -> T {
+        self.delta_lambda.atan2(self.delta_psi)
+    }
+
+    /// The point at `fraction` of the way along the rhumb line.
+    pub(crate) fn intermediate(&self, fraction: T) -> Point<T> {
+        let radius = T::from(MEAN_EARTH_RADIUS).unwrap();
+        destination(&self.from, self.theta(), fraction * self.delta() * radius)
+    }
+}
+
+/// The destination from `from` after `distance` meters at the
+/// constant bearing `theta`, in radians.
+pub(crate) fn destination<T: CoordFloat + FromPrimitive>(
+    from: &Point<T>,
+    theta: T,
+    distance: T,
+) -> Point<T> {
+    let half_pi = T::from(std::f64::consts::FRAC_PI_2).unwrap();
+    let delta = distance / T::from(MEAN_EARTH_RADIUS).unwrap();
+    let phi1 = from.y().to_radians();
+    let lambda1 = from.x().to_radians();
+
+    let delta_phi = delta * theta.cos();
+    let phi2 = phi1 + delta_phi;
+    if phi2.abs() >= half_pi {
+        // The rhumb line spirals into the pole, where longitude is
+        // meaningless: stop there.
+        return Point::new(from.x(), half_pi.copysign(phi2).to_degrees());
+    }
+
+    let delta_psi = projected_latitude(phi2) - projected_latitude(phi1);
+    let q = stretch(delta_phi, delta_psi, phi1);
+    // Leaving a pole, the longitude does not change.
+    let lambda2 = if q == T::zero() {
+        lambda1
+    } else {
+        lambda1 + delta * theta.sin() / q
+    };
+    Point::new(normalize_longitude(lambda2.to_degrees()), phi2.to_degrees())
+}
+
+/// The latitude on the Mercator projection, in which rhumb lines
+/// are straight. The poles lie at infinity.
+fn projected_latitude<T: CoordFloat + FromPrimitive>(phi: T) -> T {
+    let half_pi = T::from(std::f64::consts::FRAC_PI_2).unwrap();
+    if phi.abs() >= half_pi {
+        return T::infinity().copysign(phi);
+    }
+    let two = T::one() + T::one();
+    (half_pi / two + phi / two).tan().ln()
+}
+
+/// The ratio between the latitude and the projected latitude
+/// differences, which is ill-conditioned along a parallel.
+fn stretch<T: CoordFloat + FromPrimitive>(delta_phi: T, delta_psi: T, phi1: T) -> T {
+    if delta_psi.abs() > T::from(1e-12).unwrap() {
+        delta_phi / delta_psi
+    } else {
+        phi1.cos()
+    }
+}
+
+/// Wrap a longitude into `[-180, 180]`.
+fn normalize_longitude<T: CoordFloat + FromPrimitive>(lng: T) -> T {
+    let half_turn = T::from(180.).unwrap();
+    let turn = half_turn + half_turn;
+    if lng.abs() <= half_turn {
+        lng
+    } else {
+        let wrapped = (lng + half_turn) % turn;
+        if wrapped < T::zero() {
+            wrapped + half_turn
+        } else {
+            wrapped - half_turn
+        }
+    }
+}
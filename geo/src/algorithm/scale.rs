@@ -0,0 +1,135 @@
+use crate::algorithm::affine_ops::{AffineOps, AffineTransform};
+use crate::algorithm::bounding_rect::BoundingRect;
+use crate::{CoordFloat, Coordinate, Rect};
+
+/// Scale a geometry, by default around the centre of its
+/// bounding rectangle.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::scale::Scale;
+/// use geo::{line_string, Coordinate};
+///
+/// let ls = line_string![(x: 0., y: 0.), (x: 10., y: 10.)];
+///
+/// assert_eq!(ls.scale(2.), line_string![(x: -5., y: -5.), (x: 15., y: 15.)]);
+/// assert_eq!(
+///     ls.scale_xy(2., 1.),
+///     line_string![(x: -5., y: 0.), (x: 15., y: 10.)]
+/// );
+/// assert_eq!(
+///     ls.scale_around_point(2., 2., Coordinate { x: 0., y: 0. }),
+///     line_string![(x: 0., y: 0.), (x: 20., y: 20.)]
+/// );
+/// ```
+pub trait Scale<T: CoordFloat> {
+    /// Scale by `scale_factor` in both directions.
+    fn scale(&self, scale_factor: T) -> Self;
+
+    /// Scale by `scale_factor` in both directions, in place.
+    fn scale_mut(&mut self, scale_factor: T);
+
+    /// Scale by `x_factor` horizontally and `y_factor`
+    /// vertically.
+    fn scale_xy(&self, x_factor: T, y_factor: T) -> Self;
+
+    /// Scale by `x_factor` horizontally and `y_factor`
+    /// vertically, in place.
+    fn scale_xy_mut(&mut self, x_factor: T, y_factor: T);
+
+    /// Scale by `x_factor` horizontally and `y_factor`
+    /// vertically, around `origin`.
+    fn scale_around_point(
+        &self,
+        x_factor: T,
+        y_factor: T,
+        origin: impl Into<Coordinate<T>>,
+    ) -> Self;
+
+    /// Scale by `x_factor` horizontally and `y_factor`
+    /// vertically, around `origin`, in place.
+    fn scale_around_point_mut(
+        &mut self,
+        x_factor: T,
+        y_factor: T,
+        origin: impl Into<Coordinate<T>>,
+    );
+}
+
+impl<T, G> Scale<T> for G
+where
+    T: CoordFloat,
+    G: AffineOps<T> + BoundingRect<T>,
+    G::Output: Into<Option<Rect<T>>>,
+{
+    fn scale(&self, scale_factor: T) -> Self {
+        self.scale_xy(scale_factor, scale_factor)
+    }
+
+    fn scale_mut(&mut self, scale_factor: T) {
+        self.scale_xy_mut(scale_factor, scale_factor)
+    }
+
+    fn scale_xy(&self, x_factor: T, y_factor: T) -> Self {
+        let origin = match self.bounding_rect().into() {
+            Some(rect) => rect.center(),
+            None => return self.affine_transform(&AffineTransform::identity()),
+        };
+        self.scale_around_point(x_factor, y_factor, origin)
+    }
+
+    fn scale_xy_mut(&mut self, x_factor: T, y_factor: T) {
+        if let Some(rect) = self.bounding_rect().into() {
+            self.scale_around_point_mut(x_factor, y_factor, rect.center())
+        }
+    }
+
+    fn scale_around_point(
+        &self,
+        x_factor: T,
+        y_factor: T,
+        origin: impl Into<Coordinate<T>>,
+    ) -> Self {
+        self.affine_transform(&AffineTransform::scale(x_factor, y_factor, origin))
+    }
+
+    fn scale_around_point_mut(
+        &mut self,
+        x_factor: T,
+        y_factor: T,
+        origin: impl Into<Coordinate<T>>,
+    ) {
+        self.affine_transform_mut(&AffineTransform::scale(x_factor, y_factor, origin))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{point, polygon, Geometry, MultiPolygon};
+
+    #[test]
+    fn scale_polygon() {
+        let mut poly = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)];
+        poly.scale_mut(0.5);
+        assert_eq!(
+            poly,
+            polygon![(x: 0.5, y: 0.5), (x: 1.5, y: 0.5), (x: 1.5, y: 1.5), (x: 0.5, y: 1.5)]
+        );
+    }
+
+    #[test]
+    fn scale_point_keeps_it_in_place() {
+        let p = point!(x: 3., y: 4.);
+        assert_eq!(p.scale(10.), p);
+    }
+
+    #[test]
+    fn scale_empty_geometry() {
+        let mut empty = Geometry::MultiPolygon(MultiPolygon::<f64>(vec![]));
+        assert_eq!(empty.scale(2.), empty);
+        empty.scale_xy_mut(2., 3.);
+        assert_eq!(empty, Geometry::MultiPolygon(MultiPolygon(vec![])));
+    }
+}
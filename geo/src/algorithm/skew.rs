@@ -0,0 +1,131 @@
+use crate::algorithm::affine_ops::{AffineOps, AffineTransform};
+use crate::algorithm::bounding_rect::BoundingRect;
+use crate::{CoordFloat, Coordinate, Rect};
+
+/// Skew (shear) a geometry, by default around the centre of
+/// its bounding rectangle. The angles are given in degrees.
+///
+/// # Examples
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use geo::algorithm::skew::Skew;
+/// use geo::line_string;
+///
+/// let ls = line_string![(x: 0., y: 0.), (x: 0., y: 10.)];
+///
+/// assert_relative_eq!(
+///     ls.skew_xy(45., 0.),
+///     line_string![(x: -5., y: 0.), (x: 5., y: 10.)],
+///     epsilon = 1e-10
+/// );
+/// ```
+pub trait Skew<T: CoordFloat> {
+    /// Skew by `degrees` along both axes.
+    fn skew(&self, degrees: T) -> Self;
+
+    /// Skew by `degrees` along both axes, in place.
+    fn skew_mut(&mut self, degrees: T);
+
+    /// Skew by `degrees_x` along the x axis and `degrees_y`
+    /// along the y axis.
+    fn skew_xy(&self, degrees_x: T, degrees_y: T) -> Self;
+
+    /// Skew by `degrees_x` along the x axis and `degrees_y`
+    /// along the y axis, in place.
+    fn skew_xy_mut(&mut self, degrees_x: T, degrees_y: T);
+
+    /// Skew by `degrees_x` along the x axis and `degrees_y`
+    /// along the y axis, around `origin`.
+    fn skew_around_point(
+        &self,
+        degrees_x: T,
+        degrees_y: T,
+        origin: impl Into<Coordinate<T>>,
+    ) -> Self;
+
+    /// Skew by `degrees_x` along the x axis and `degrees_y`
+    /// along the y axis, around `origin`, in place.
+    fn skew_around_point_mut(
+        &mut self,
+        degrees_x: T,
+        degrees_y: T,
+        origin: impl Into<Coordinate<T>>,
+    );
+}
+
+impl<T, G> Skew<T> for G
+where
+    T: CoordFloat,
+    G: AffineOps<T> + BoundingRect<T>,
+    G::Output: Into<Option<Rect<T>>>,
+{
+    fn skew(&self, degrees: T) -> Self {
+        self.skew_xy(degrees, degrees)
+    }
+
+    fn skew_mut(&mut self, degrees: T) {
+        self.skew_xy_mut(degrees, degrees)
+    }
+
+    fn skew_xy(&self, degrees_x: T, degrees_y: T) -> Self {
+        let origin = match self.bounding_rect().into() {
+            Some(rect) => rect.center(),
+            None => return self.affine_transform(&AffineTransform::identity()),
+        };
+        self.skew_around_point(degrees_x, degrees_y, origin)
+    }
+
+    fn skew_xy_mut(&mut self, degrees_x: T, degrees_y: T) {
+        if let Some(rect) = self.bounding_rect().into() {
+            self.skew_around_point_mut(degrees_x, degrees_y, rect.center())
+        }
+    }
+
+    fn skew_around_point(
+        &self,
+        degrees_x: T,
+        degrees_y: T,
+        origin: impl Into<Coordinate<T>>,
+    ) -> Self {
+        self.affine_transform(&AffineTransform::skew(degrees_x, degrees_y, origin))
+    }
+
+    fn skew_around_point_mut(
+        &mut self,
+        degrees_x: T,
+        degrees_y: T,
+        origin: impl Into<Coordinate<T>>,
+    ) {
+        self.affine_transform_mut(&AffineTransform::skew(degrees_x, degrees_y, origin))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point};
+
+    #[test]
+    fn skew_line_string_around_point() {
+        let mut ls = line_string![(x: 0., y: 0.), (x: 0., y: 2.)];
+        ls.skew_around_point_mut(0., 45., point!(x: 1., y: 0.));
+        assert_relative_eq!(
+            ls,
+            line_string![(x: 0., y: -1.), (x: 0., y: 1.)],
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn skew_both_axes() {
+        let ls = line_string![(x: -1., y: -1.), (x: 1., y: 1.)];
+        let skewed = ls.skew(30.);
+        let tan = 30f64.to_radians().tan();
+        assert_relative_eq!(
+            skewed,
+            line_string![(x: -1. - tan, y: -1. - tan), (x: 1. + tan, y: 1. + tan)],
+            epsilon = 1e-12
+        );
+    }
+}
@@ -0,0 +1,340 @@
+use crate::algorithm::coordinate_position::CoordPos;
+use crate::{CoordFloat, Coordinate, LineString, MultiPolygon, Point, Polygon};
+
+/// Determine whether a `Coordinate` lies inside, outside, or on the
+/// boundary of a polygon on the sphere, whose edges are great-circle
+/// arcs between longitude/latitude coordinates.
+///
+/// On the sphere, a ring splits the surface into two regions. If the
+/// ring does not go around a pole, its interior is the region without
+/// the poles, whatever its orientation. If it goes around a pole, as
+/// the boundary of a polar cap does, its interior is the region on its
+/// left: it holds the north pole if the ring runs eastwards, and the
+/// south pole if it runs westwards.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::coordinate_position::CoordPos;
+/// use geo::algorithm::spherical_contains::SphericalCoordinatePosition;
+/// use geo::{polygon, Coordinate};
+///
+/// // the Arctic, north of 70°N, running eastwards
+/// let arctic = polygon![
+///     (x: 0., y: 70.), (x: 90., y: 70.), (x: 180., y: 70.), (x: -90., y: 70.),
+/// ];
+///
+/// let north_pole = Coordinate { x: 0., y: 90. };
+/// assert_eq!(arctic.spherical_coordinate_position(&north_pole), CoordPos::Inside);
+/// // the great-circle edges bulge towards the pole
+/// let svalbard = Coordinate { x: 45., y: 71. };
+/// assert_eq!(arctic.spherical_coordinate_position(&svalbard), CoordPos::Outside);
+/// let vertex = Coordinate { x: 90., y: 70. };
+/// assert_eq!(arctic.spherical_coordinate_position(&vertex), CoordPos::OnBoundary);
+/// ```
+pub trait SphericalCoordinatePosition {
+    type Scalar: CoordFloat;
+
+    fn spherical_coordinate_position(&self, coord: &Coordinate<Self::Scalar>) -> CoordPos;
+}
+
+/// Check if a polygon on the sphere, whose edges are great-circle arcs,
+/// contains a `Point`, as defined by [`SphericalCoordinatePosition`].
+///
+/// Points on the boundary are not contained.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::contains::Contains;
+/// use geo::algorithm::spherical_contains::SphericalContains;
+/// use geo::{point, polygon};
+///
+/// let polygon = polygon![(x: -100., y: 50.), (x: 0., y: 50.), (x: 0., y: 60.), (x: -100., y: 60.)];
+///
+/// // the top edge follows a great circle, which bulges north
+/// let p = point!(x: -50., y: 62.);
+/// assert!(polygon.spherical_contains(&p));
+/// assert!(!polygon.contains(&p));
+/// ```
+pub trait SphericalContains<Rhs = Self> {
+    fn spherical_contains(&self, rhs: &Rhs) -> bool;
+}
+
+impl<T: CoordFloat> SphericalCoordinatePosition for Polygon<T> {
+    type Scalar = T;
+
+    fn spherical_coordinate_position(&self, coord: &Coordinate<T>) -> CoordPos {
+        match ring_position(self.exterior(), *coord) {
+            CoordPos::Inside => {}
+            position => return position,
+        }
+        for interior in self.interiors() {
+            match ring_position(interior, *coord) {
+                CoordPos::Outside => {}
+                CoordPos::Inside => return CoordPos::Outside,
+                CoordPos::OnBoundary => return CoordPos::OnBoundary,
+            }
+        }
+        CoordPos::Inside
+    }
+}
+
+impl<T: CoordFloat> SphericalCoordinatePosition for MultiPolygon<T> {
+    type Scalar = T;
+
+    fn spherical_coordinate_position(&self, coord: &Coordinate<T>) -> CoordPos {
+        let mut position = CoordPos::Outside;
+        for polygon in self {
+            match polygon.spherical_coordinate_position(coord) {
+                CoordPos::Inside => return CoordPos::Inside,
+                CoordPos::OnBoundary => position = CoordPos::OnBoundary,
+                CoordPos::Outside => {}
+            }
+        }
+        position
+    }
+}
+
+macro_rules! spherical_contains_impl {
+    ($type:ident) => {
+        impl<T: CoordFloat> SphericalContains<Coordinate<T>> for $type<T> {
+            fn spherical_contains(&self, rhs: &Coordinate<T>) -> bool {
+                self.spherical_coordinate_position(rhs) == CoordPos::Inside
+            }
+        }
+
+        impl<T: CoordFloat> SphericalContains<Point<T>> for $type<T> {
+            fn spherical_contains(&self, rhs: &Point<T>) -> bool {
+                self.spherical_contains(&rhs.0)
+            }
+        }
+    };
+}
+
+spherical_contains_impl!(Polygon);
+spherical_contains_impl!(MultiPolygon);
+
+/// A point on the unit sphere.
+#[derive(Clone, Copy)]
+struct Vector<T> {
+    x: T,
+    y: T,
+    z: T,
+}
+
+impl<T: CoordFloat> Vector<T> {
+    fn from_coord(coord: Coordinate<T>) -> Self {
+        let (lat_sin, lat_cos) = coord.y.to_radians().sin_cos();
+        let (lng_sin, lng_cos) = coord.x.to_radians().sin_cos();
+        Vector {
+            x: lat_cos * lng_cos,
+            y: lat_cos * lng_sin,
+            z: lat_sin,
+        }
+    }
+
+    fn dot(self, other: Self) -> T {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    fn cross(self, other: Self) -> Self {
+        Vector {
+            x: self.y * other.z - self.z * other.y,
+            y: self.z * other.x - self.x * other.z,
+            z: self.x * other.y - self.y * other.x,
+        }
+    }
+
+    fn norm(self) -> T {
+        self.dot(self).sqrt()
+    }
+}
+
+/// Wrap a difference of longitudes into `(-180, 180]`.
+fn wrap_longitude<T: CoordFloat>(lng: T) -> T {
+    let half_turn = T::from(180).unwrap();
+    let turn = half_turn + half_turn;
+    let wrapped = lng - turn * ((lng + half_turn) / turn).floor();
+    if wrapped == -half_turn {
+        half_turn
+    } else {
+        wrapped
+    }
+}
+
+/// The position of `coord` relative to the region enclosed by `ring`.
+fn ring_position<T: CoordFloat>(ring: &LineString<T>, coord: Coordinate<T>) -> CoordPos {
+    let tolerance = T::epsilon() * T::from(1024).unwrap();
+    let half_turn = T::from(180).unwrap();
+    let quarter_turn = T::from(90).unwrap();
+    let p = Vector::from_coord(coord);
+
+    // Count the crossings of the meridian arc from `coord` to one of
+    // the poles, and add up the longitudes the ring runs through to
+    // know whether that pole is inside. Edges ending at a pole have
+    // no meaningful longitude, so the arc goes to the other pole if
+    // the ring touches the north pole.
+    let north = !ring.0.iter().any(|c| c.y >= quarter_turn);
+    let mut crossings = 0;
+    let mut winding = T::zero();
+    for line in ring.lines() {
+        let (a, b) = (Vector::from_coord(line.start), Vector::from_coord(line.end));
+        let normal = a.cross(b);
+        let norm = normal.norm();
+        if norm <= tolerance {
+            // A degenerate edge, which may still touch `coord`.
+            if p.cross(a).norm() <= tolerance && p.dot(a) > T::zero() {
+                return CoordPos::OnBoundary;
+            }
+            continue;
+        }
+        if normal.dot(p).abs() <= tolerance * norm
+            && a.cross(p).dot(normal) >= -tolerance * norm
+            && p.cross(b).dot(normal) >= -tolerance * norm
+        {
+            return CoordPos::OnBoundary;
+        }
+
+        if line.start.y.abs() >= quarter_turn || line.end.y.abs() >= quarter_turn {
+            continue;
+        }
+        let delta = wrap_longitude(line.end.x - line.start.x);
+        winding = winding + delta;
+
+        let start = wrap_longitude(line.start.x - coord.x);
+        let end = start + delta;
+        if (start < T::zero()) == (end < T::zero()) {
+            continue;
+        }
+        // The crossing lies on the meridian plane of `coord`, on its
+        // side of the polar axis.
+        let (lng_sin, lng_cos) = coord.x.to_radians().sin_cos();
+        let meridian = Vector {
+            x: -lng_sin,
+            y: lng_cos,
+            z: T::zero(),
+        };
+        let mut crossing = meridian.cross(normal);
+        if crossing.x * lng_cos + crossing.y * lng_sin < T::zero() {
+            crossing = Vector {
+                x: -crossing.x,
+                y: -crossing.y,
+                z: -crossing.z,
+            };
+        }
+        if (north && crossing.z > p.z * crossing.norm())
+            || (!north && crossing.z < p.z * crossing.norm())
+        {
+            crossings += 1;
+        }
+    }
+
+    // The ring runs eastwards around the north pole, or westwards
+    // around the south pole.
+    let pole_inside = if north {
+        winding > half_turn
+    } else {
+        winding < -half_turn
+    };
+    if pole_inside == (crossings % 2 == 0) {
+        CoordPos::Inside
+    } else {
+        CoordPos::Outside
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{point, polygon};
+
+    fn position(polygon: &Polygon<f64>, x: f64, y: f64) -> CoordPos {
+        polygon.spherical_coordinate_position(&Coordinate { x, y })
+    }
+
+    #[test]
+    fn small_polygon() {
+        let square = polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)];
+        let mut cw = square.clone();
+        cw.exterior_mut(|ring| ring.0.reverse());
+        for polygon in &[square, cw] {
+            assert_eq!(position(polygon, 5., 5.), CoordPos::Inside);
+            assert_eq!(position(polygon, 15., 5.), CoordPos::Outside);
+            assert_eq!(position(polygon, -175., -5.), CoordPos::Outside);
+            assert_eq!(position(polygon, 0., 90.), CoordPos::Outside);
+            assert_eq!(position(polygon, 5., 0.), CoordPos::OnBoundary);
+            assert_eq!(position(polygon, 10., 10.), CoordPos::OnBoundary);
+            // the top edge bulges north of the parallel
+            assert_eq!(position(polygon, 5., 10.03), CoordPos::Inside);
+        }
+    }
+
+    #[test]
+    fn polar_caps() {
+        let arctic = polygon![
+            (x: 0., y: 70.), (x: 90., y: 70.), (x: 180., y: 70.), (x: -90., y: 70.),
+        ];
+        assert_eq!(position(&arctic, 0., 90.), CoordPos::Inside);
+        assert_eq!(position(&arctic, 45., 89.), CoordPos::Inside);
+        assert_eq!(position(&arctic, 45., 60.), CoordPos::Outside);
+        assert_eq!(position(&arctic, 0., -90.), CoordPos::Outside);
+        assert_eq!(position(&arctic, 180., 70.), CoordPos::OnBoundary);
+
+        // running westwards, the ring encloses the rest of the earth
+        let mut rest = arctic.clone();
+        rest.exterior_mut(|ring| ring.0.reverse());
+        assert_eq!(position(&rest, 0., 90.), CoordPos::Outside);
+        assert_eq!(position(&rest, 45., 60.), CoordPos::Inside);
+        assert_eq!(position(&rest, 0., -90.), CoordPos::Inside);
+
+        let antarctic = polygon![
+            (x: 0., y: -70.), (x: -90., y: -70.), (x: 180., y: -70.), (x: 90., y: -70.),
+        ];
+        assert_eq!(position(&antarctic, 10., -90.), CoordPos::Inside);
+        assert_eq!(position(&antarctic, 10., -80.), CoordPos::Inside);
+        assert_eq!(position(&antarctic, 10., -60.), CoordPos::Outside);
+        assert_eq!(position(&antarctic, 10., 90.), CoordPos::Outside);
+    }
+
+    #[test]
+    fn ring_through_a_pole() {
+        let polygon = polygon![(x: 0., y: 60.), (x: 90., y: 60.), (x: 0., y: 90.)];
+        assert_eq!(position(&polygon, 45., 80.), CoordPos::Inside);
+        assert_eq!(position(&polygon, -45., 80.), CoordPos::Outside);
+        assert_eq!(position(&polygon, 120., 90.), CoordPos::OnBoundary);
+    }
+
+    #[test]
+    fn across_the_antimeridian() {
+        let polygon = polygon![
+            (x: 170., y: -10.), (x: -170., y: -10.), (x: -170., y: 10.), (x: 170., y: 10.),
+        ];
+        assert_eq!(position(&polygon, 180., 0.), CoordPos::Inside);
+        assert_eq!(position(&polygon, -175., 5.), CoordPos::Inside);
+        assert_eq!(position(&polygon, 0., 0.), CoordPos::Outside);
+    }
+
+    #[test]
+    fn holes_and_multi_polygons() {
+        let polygon = polygon![
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [[(x: 2., y: 2.), (x: 4., y: 2.), (x: 4., y: 4.), (x: 2., y: 4.)]],
+        ];
+        assert_eq!(position(&polygon, 3., 3.), CoordPos::Outside);
+        assert_eq!(position(&polygon, 2., 3.), CoordPos::OnBoundary);
+        assert!(polygon.spherical_contains(&point!(x: 6., y: 6.)));
+        assert!(!polygon.spherical_contains(&point!(x: 0., y: 5.)));
+
+        let mp = MultiPolygon(vec![
+            polygon,
+            polygon![(x: 20., y: 0.), (x: 30., y: 0.), (x: 30., y: 10.)],
+        ]);
+        assert!(mp.spherical_contains(&point!(x: 28., y: 2.)));
+        assert!(!mp.spherical_contains(&point!(x: 3., y: 3.)));
+        assert_eq!(
+            mp.spherical_coordinate_position(&Coordinate { x: 30., y: 0. }),
+            CoordPos::OnBoundary
+        );
+    }
+}
@@ -0,0 +1,411 @@
+use crate::algorithm::closest_point::ClosestPoint;
+use crate::algorithm::euclidean_distance::EuclideanDistance;
+use crate::algorithm::euclidean_length::EuclideanLength;
+use crate::algorithm::line_intersection::{line_intersection, LineIntersection};
+use crate::algorithm::noding::{for_each_candidate_pair, sort_along};
+use crate::{
+    Closest, Coordinate, GeoFloat, Geometry, Line, LineString, MultiLineString, MultiPoint, Polygon,
+};
+use rstar::RTreeNum;
+
+/// Split a `LineString` into pieces at points, at fractions of its length, or
+/// where it meets another geometry.
+///
+/// The vertices of the input are kept exactly, and new vertices are only added
+/// where the pieces meet, so that joining the pieces end to end gives back the
+/// input, with the split points added. Splitting at either end of a
+/// `LineString` has no effect.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::split::Split;
+/// use geo::{line_string, polygon, Geometry, MultiLineString};
+///
+/// let road = line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.)];
+/// let park = polygon![(x: 5., y: -5.), (x: 15., y: -5.), (x: 15., y: 5.), (x: 5., y: 5.)];
+///
+/// assert_eq!(
+///     road.split_by(&Geometry::Polygon(park)),
+///     MultiLineString(vec![
+///         line_string![(x: 0., y: 0.), (x: 5., y: 0.)],
+///         line_string![(x: 5., y: 0.), (x: 10., y: 0.), (x: 10., y: 5.)],
+///         line_string![(x: 10., y: 5.), (x: 10., y: 10.)],
+///     ])
+/// );
+/// ```
+pub trait Split<T: GeoFloat> {
+    /// Split at the points of the geometry closest to each of `points`.
+    /// Points with a non-finite coordinate are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::split::Split;
+    /// use geo::{line_string, point, MultiLineString, MultiPoint};
+    ///
+    /// let line_string = line_string![(x: 0., y: 0.), (x: 10., y: 0.)];
+    /// let junctions = MultiPoint(vec![point!(x: 4., y: 1.)]);
+    ///
+    /// assert_eq!(
+    ///     line_string.split_at_points(&junctions),
+    ///     MultiLineString(vec![
+    ///         line_string![(x: 0., y: 0.), (x: 4., y: 0.)],
+    ///         line_string![(x: 4., y: 0.), (x: 10., y: 0.)],
+    ///     ])
+    /// );
+    /// ```
+    fn split_at_points(&self, points: &MultiPoint<T>) -> MultiLineString<T>;
+
+    /// Split at each of `fractions` of the Euclidean length of each
+    /// `LineString`. Fractions which are NaN, or not strictly between zero
+    /// and one, are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::split::Split;
+    /// use geo::{line_string, MultiLineString};
+    ///
+    /// let line_string = line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.)];
+    ///
+    /// assert_eq!(
+    ///     line_string.split_at_fractions(&[0.5, 0.25]),
+    ///     MultiLineString(vec![
+    ///         line_string![(x: 0., y: 0.), (x: 5., y: 0.)],
+    ///         line_string![(x: 5., y: 0.), (x: 10., y: 0.)],
+    ///         line_string![(x: 10., y: 0.), (x: 10., y: 10.)],
+    ///     ])
+    /// );
+    /// ```
+    fn split_at_fractions(&self, fractions: &[T]) -> MultiLineString<T>;
+
+    /// Split wherever the geometry meets `blade`. Where they overlap, the
+    /// geometry is split at both ends of the overlap.
+    fn split_by(&self, blade: &Geometry<T>) -> MultiLineString<T>;
+}
+
+impl<T: GeoFloat + RTreeNum> Split<T> for LineString<T> {
+    fn split_at_points(&self, points: &MultiPoint<T>) -> MultiLineString<T> {
+        let lines: Vec<Line<T>> = self.lines().collect();
+        let cuts = points
+            .iter()
+            .filter(|point| point.x().is_finite() && point.y().is_finite())
+            .filter_map(|point| {
+                let mut closest: Option<(T, usize, Coordinate<T>)> = None;
+                for (i, line) in lines.iter().enumerate() {
+                    let coord = match line.closest_point(point) {
+                        Closest::Intersection(p) | Closest::SinglePoint(p) => p.0,
+                        Closest::Indeterminate => line.start,
+                    };
+                    let distance = coord.euclidean_distance(&point.0);
+                    if !matches!(closest, Some((min, _, _)) if min <= distance) {
+                        closest = Some((distance, i, coord));
+                    }
+                }
+                closest.map(|(_, i, coord)| (i, coord))
+            })
+            .collect();
+        split_line_string(self, cuts)
+    }
+
+    fn split_at_fractions(&self, fractions: &[T]) -> MultiLineString<T> {
+        let lengths: Vec<T> = self.lines().map(|line| line.euclidean_length()).collect();
+        let total = lengths
+            .iter()
+            .fold(T::zero(), |total, &length| total + length);
+        let cuts = fractions
+            .iter()
+            .filter(|&&fraction| fraction > T::zero() && fraction < T::one())
+            .filter_map(|&fraction| {
+                let distance = fraction * total;
+                let mut offset = T::zero();
+                for (i, (line, &length)) in self.lines().zip(&lengths).enumerate() {
+                    if offset + length >= distance {
+                        let t = (distance - offset) / length;
+                        let coord = if t >= T::one() {
+                            line.end
+                        } else {
+                            line.start + line.delta() * t.max(T::zero())
+                        };
+                        return Some((i, coord));
+                    }
+                    offset = offset + length;
+                }
+                None
+            })
+            .collect();
+        split_line_string(self, cuts)
+    }
+
+    fn split_by(&self, blade: &Geometry<T>) -> MultiLineString<T> {
+        let mut lines: Vec<Line<T>> = self.lines().collect();
+        let count = lines.len();
+        push_blade_segments(blade, &mut lines);
+
+        let mut cuts = vec![];
+        for_each_candidate_pair(&lines, |i, j| {
+            if i >= count || j < count {
+                return;
+            }
+            match line_intersection(lines[i], lines[j]) {
+                Some(LineIntersection::SinglePoint { intersection, .. }) => {
+                    cuts.push((i, intersection))
+                }
+                Some(LineIntersection::Collinear {
+                    intersection: overlap,
+                }) => {
+                    cuts.push((i, overlap.start));
+                    cuts.push((i, overlap.end));
+                }
+                None => {}
+            }
+        });
+        split_line_string(self, cuts)
+    }
+}
+
+impl<T: GeoFloat + RTreeNum> Split<T> for MultiLineString<T> {
+    fn split_at_points(&self, points: &MultiPoint<T>) -> MultiLineString<T> {
+        self.iter()
+            .flat_map(|line_string| line_string.split_at_points(points))
+            .collect()
+    }
+
+    fn split_at_fractions(&self, fractions: &[T]) -> MultiLineString<T> {
+        self.iter()
+            .flat_map(|line_string| line_string.split_at_fractions(fractions))
+            .collect()
+    }
+
+    fn split_by(&self, blade: &Geometry<T>) -> MultiLineString<T> {
+        self.iter()
+            .flat_map(|line_string| line_string.split_by(blade))
+            .collect()
+    }
+}
+
+/// Split `line_string` at each cut, given as the index of the segment
+/// it lies on and its coordinate.
+pub(crate) fn split_line_string<T: GeoFloat>(
+    line_string: &LineString<T>,
+    cuts: Vec<(usize, Coordinate<T>)>,
+) -> MultiLineString<T> {
+    let coords = &line_string.0;
+    if coords.len() < 2 {
+        return MultiLineString(
+            Some(line_string.clone())
+                .filter(|ls| !ls.0.is_empty())
+                .into_iter()
+                .collect(),
+        );
+    }
+
+    // Cuts at the ends of a segment become cuts at a vertex, and the
+    // rest are sorted along their segment.
+    let mut vertex_cuts = vec![false; coords.len()];
+    let mut segment_cuts: Vec<Vec<Coordinate<T>>> = vec![vec![]; coords.len() - 1];
+    for (i, c) in cuts {
+        if c == coords[i] {
+            vertex_cuts[i] = true;
+        } else if c == coords[i + 1] {
+            vertex_cuts[i + 1] = true;
+        } else {
+            segment_cuts[i].push(c);
+        }
+    }
+
+    let mut pieces = vec![];
+    let mut piece = vec![coords[0]];
+    let mut finish = |piece: &mut Vec<Coordinate<T>>, c: Coordinate<T>| {
+        // Never split off a piece of zero length.
+        if piece.iter().any(|&p| p != c) {
+            pieces.push(LineString(std::mem::replace(piece, vec![c])));
+        }
+    };
+    for (i, mut interior) in segment_cuts.into_iter().enumerate() {
+        sort_along(Line::new(coords[i], coords[i + 1]), &mut interior);
+        for c in interior {
+            piece.push(c);
+            finish(&mut piece, c);
+        }
+        piece.push(coords[i + 1]);
+        if vertex_cuts[i + 1] && i + 2 < coords.len() {
+            finish(&mut piece, coords[i + 1]);
+        }
+    }
+    pieces.push(LineString(piece));
+    MultiLineString(pieces)
+}
+
+fn push_blade_segments<T: GeoFloat>(blade: &Geometry<T>, segments: &mut Vec<Line<T>>) {
+    let push_polygon = |polygon: &Polygon<T>, segments: &mut Vec<Line<T>>| {
+        segments.extend(polygon.exterior().lines());
+        for interior in polygon.interiors() {
+            segments.extend(interior.lines());
+        }
+    };
+    match blade {
+        Geometry::Point(p) => segments.push(Line::new(p.0, p.0)),
+        Geometry::Line(line) => segments.push(*line),
+        Geometry::LineString(ls) => segments.extend(ls.lines()),
+        Geometry::Polygon(polygon) => push_polygon(polygon, segments),
+        Geometry::MultiPoint(mp) => segments.extend(mp.iter().map(|p| Line::new(p.0, p.0))),
+        Geometry::MultiLineString(mls) => segments.extend(mls.iter().flat_map(|ls| ls.lines())),
+        Geometry::MultiPolygon(mp) => {
+            for polygon in mp {
+                push_polygon(polygon, segments);
+            }
+        }
+        Geometry::GeometryCollection(gc) => {
+            for geometry in gc {
+                push_blade_segments(geometry, segments);
+            }
+        }
+        Geometry::Rect(rect) => push_polygon(&rect.to_polygon(), segments),
+        Geometry::Triangle(triangle) => push_polygon(&triangle.to_polygon(), segments),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, Line, Rect};
+
+    // Join the pieces end to end.
+    fn join(pieces: &MultiLineString<f64>) -> LineString<f64> {
+        let mut coords: Vec<Coordinate<f64>> = vec![];
+        for piece in pieces {
+            assert_eq!(coords.last().unwrap_or(&piece[0]), &piece[0]);
+            let skip = if coords.is_empty() { 0 } else { 1 };
+            coords.extend(piece.0.iter().skip(skip));
+        }
+        LineString(coords)
+    }
+
+    #[test]
+    fn split_at_points() {
+        let ls = line_string![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)];
+        let points = MultiPoint(vec![
+            point!(x: 3., y: 1.),
+            point!(x: 2., y: 0.),
+            point!(x: -1., y: -1.),
+            point!(x: 1., y: 2.1),
+        ]);
+        let pieces = ls.split_at_points(&points);
+        assert_eq!(
+            pieces,
+            MultiLineString(vec![
+                line_string![(x: 0., y: 0.), (x: 2., y: 0.)],
+                line_string![(x: 2., y: 0.), (x: 2., y: 1.)],
+                line_string![(x: 2., y: 1.), (x: 2., y: 2.), (x: 1., y: 2.)],
+                line_string![(x: 1., y: 2.), (x: 0., y: 2.)],
+            ])
+        );
+        assert_eq!(
+            join(&pieces),
+            line_string![
+                (x: 0., y: 0.),
+                (x: 2., y: 0.),
+                (x: 2., y: 1.),
+                (x: 2., y: 2.),
+                (x: 1., y: 2.),
+                (x: 0., y: 2.)
+            ]
+        );
+    }
+
+    #[test]
+    fn split_at_non_finite_points() {
+        let ls = line_string![(x: 0., y: 0.), (x: 10., y: 0.)];
+        let points = MultiPoint(vec![
+            point!(x: f64::NAN, y: 1.),
+            point!(x: 4., y: f64::INFINITY),
+        ]);
+        assert_eq!(
+            ls.split_at_points(&points),
+            MultiLineString(vec![ls.clone()])
+        );
+    }
+
+    #[test]
+    fn split_at_fractions() {
+        let ls = line_string![(x: 0., y: 0.), (x: 4., y: 0.)];
+        assert_eq!(
+            ls.split_at_fractions(&[0.75, f64::NAN, 0.25, 0.25, 0., 1.5]),
+            MultiLineString(vec![
+                line_string![(x: 0., y: 0.), (x: 1., y: 0.)],
+                line_string![(x: 1., y: 0.), (x: 3., y: 0.)],
+                line_string![(x: 3., y: 0.), (x: 4., y: 0.)],
+            ])
+        );
+        assert_eq!(ls.split_at_fractions(&[]), MultiLineString(vec![ls]));
+    }
+
+    #[test]
+    fn split_by_lines() {
+        let ls = line_string![(x: 0., y: 0.), (x: 10., y: 0.)];
+        let blade = Geometry::MultiLineString(MultiLineString(vec![
+            line_string![(x: 2., y: -1.), (x: 2., y: 1.)],
+            // overlapping
+            line_string![(x: 5., y: 0.), (x: 7., y: 0.)],
+            // touching the end
+            line_string![(x: 10., y: 0.), (x: 10., y: 1.)],
+        ]));
+        assert_eq!(
+            ls.split_by(&blade),
+            MultiLineString(vec![
+                line_string![(x: 0., y: 0.), (x: 2., y: 0.)],
+                line_string![(x: 2., y: 0.), (x: 5., y: 0.)],
+                line_string![(x: 5., y: 0.), (x: 7., y: 0.)],
+                line_string![(x: 7., y: 0.), (x: 10., y: 0.)],
+            ])
+        );
+        assert_eq!(
+            ls.split_by(&Geometry::Line(Line::new((0., 1.), (10., 1.)))),
+            MultiLineString(vec![ls])
+        );
+    }
+
+    #[test]
+    fn split_by_rect() {
+        let ls = line_string![(x: 0., y: 0.), (x: 0.3, y: 0.7), (x: 1.9, y: 1.3), (x: 3., y: 3.)];
+        let rect = Rect::new((0.5, 0.5), (1.5, 2.5));
+        let pieces = ls.split_by(&Geometry::Rect(rect));
+        assert_eq!(pieces.0.len(), 3);
+        let joined = join(&pieces);
+        assert_eq!(joined.0.len(), ls.0.len() + 2);
+        assert!(ls.0.iter().all(|c| joined.0.contains(c)));
+    }
+
+    #[test]
+    fn split_by_point() {
+        let ls = line_string![(x: 0., y: 0.), (x: 1., y: 1.), (x: 2., y: 0.)];
+        let pieces = ls.split_by(&Geometry::Point(point!(x: 1., y: 1.)));
+        assert_eq!(
+            pieces,
+            MultiLineString(vec![
+                line_string![(x: 0., y: 0.), (x: 1., y: 1.)],
+                line_string![(x: 1., y: 1.), (x: 2., y: 0.)],
+            ])
+        );
+        assert_eq!(join(&pieces), ls);
+        // not on the line string
+        assert_eq!(
+            ls.split_by(&Geometry::Point(point!(x: 1., y: 0.))),
+            MultiLineString(vec![ls])
+        );
+    }
+
+    #[test]
+    fn multi_line_string() {
+        let mls = MultiLineString(vec![
+            line_string![(x: 0., y: 0.), (x: 2., y: 0.)],
+            line_string![(x: 1., y: -1.), (x: 1., y: 1.)],
+        ]);
+        let blade = Geometry::Line(Line::new((0., 0.5), (2., -0.5)));
+        assert_eq!(mls.split_by(&blade).0.len(), 4);
+        let empty: LineString<f64> = line_string![];
+        assert_eq!(empty.split_at_fractions(&[0.5]), MultiLineString(vec![]));
+    }
+}
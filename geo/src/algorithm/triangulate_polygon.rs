@@ -0,0 +1,422 @@
+use crate::algorithm::winding_order::Winding;
+use crate::kernels::{Kernel, Orientation};
+use crate::utils::lex_cmp;
+use crate::{Coordinate, GeoFloat, LineString, MultiPolygon, Polygon, Triangle};
+
+/// Split a polygon into triangles, with the [ear clipping]
+/// method.
+///
+/// The triangles cover the polygon exactly, interior rings
+/// included, and use no vertex besides those of its rings. They
+/// are oriented counter-clockwise, whatever the orientation of
+/// the rings. Invalid polygons, whose rings intersect, give
+/// overlapping or missing triangles, and interior rings with a
+/// non-finite coordinate are ignored.
+///
+/// [ear clipping]: https://en.wikipedia.org/wiki/Polygon_triangulation#Ear_clipping_method
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::triangulate_polygon::TriangulatePolygon;
+/// use geo::algorithm::area::Area;
+/// use geo::polygon;
+///
+/// let square = polygon![
+///     exterior: [(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)],
+///     interiors: [[(x: 1., y: 1.), (x: 3., y: 1.), (x: 3., y: 3.), (x: 1., y: 3.)]],
+/// ];
+///
+/// let triangles = square.triangulate();
+/// assert_eq!(triangles.len(), 8);
+/// let area: f64 = triangles.iter().map(|t| t.unsigned_area()).sum();
+/// assert_eq!(area, 12.);
+///
+/// // indices refer to the coordinates of `coords_iter`, with the
+/// // closing coordinate of each ring
+/// let indices = square.triangulate_indices();
+/// assert_eq!(indices.len(), 3 * 8);
+/// assert!(indices.iter().all(|&i| i != 4 && i < 9));
+/// ```
+pub trait TriangulatePolygon {
+    type Scalar: GeoFloat;
+
+    /// Return the triangles covering the polygon.
+    fn triangulate(&self) -> Vec<Triangle<Self::Scalar>>;
+
+    /// Return the triangles covering the polygon as a flat buffer
+    /// of indices, three per triangle, into the coordinates of the
+    /// polygon in the order of
+    /// [`coords_iter`](crate::algorithm::coords_iter::CoordsIter::coords_iter).
+    fn triangulate_indices(&self) -> Vec<usize>;
+}
+
+impl<T: GeoFloat> TriangulatePolygon for Polygon<T> {
+    type Scalar = T;
+
+    fn triangulate(&self) -> Vec<Triangle<T>> {
+        let coords = polygon_coords(self);
+        to_triangles(&coords, &self.triangulate_indices())
+    }
+
+    fn triangulate_indices(&self) -> Vec<usize> {
+        let mut indices = vec![];
+        earcut(self, 0, &mut indices);
+        indices
+    }
+}
+
+impl<T: GeoFloat> TriangulatePolygon for MultiPolygon<T> {
+    type Scalar = T;
+
+    fn triangulate(&self) -> Vec<Triangle<T>> {
+        let coords: Vec<_> = self.iter().flat_map(polygon_coords).collect();
+        to_triangles(&coords, &self.triangulate_indices())
+    }
+
+    fn triangulate_indices(&self) -> Vec<usize> {
+        let mut indices = vec![];
+        let mut offset = 0;
+        for polygon in self.iter() {
+            earcut(polygon, offset, &mut indices);
+            offset += polygon_coords(polygon).len();
+        }
+        indices
+    }
+}
+
+fn polygon_coords<T: GeoFloat>(polygon: &Polygon<T>) -> Vec<Coordinate<T>> {
+    std::iter::once(polygon.exterior())
+        .chain(polygon.interiors())
+        .flat_map(|ring| ring.0.iter().copied())
+        .collect()
+}
+
+fn to_triangles<T: GeoFloat>(coords: &[Coordinate<T>], indices: &[usize]) -> Vec<Triangle<T>> {
+    indices
+        .chunks(3)
+        .map(|t| Triangle(coords[t[0]], coords[t[1]], coords[t[2]]))
+        .collect()
+}
+
+/// The vertices of a ring, as indices into the coordinates of
+/// its polygon, without repeated points nor the closing one, and
+/// in the given orientation.
+fn ring_vertices<T: GeoFloat>(
+    ring: &LineString<T>,
+    offset: usize,
+    ccw: bool,
+) -> Vec<(usize, Coordinate<T>)> {
+    let mut vertices: Vec<(usize, Coordinate<T>)> = vec![];
+    for (i, &coord) in ring.0.iter().enumerate() {
+        if vertices.last().map(|v| v.1) != Some(coord) {
+            vertices.push((offset + i, coord));
+        }
+    }
+    while vertices.len() > 1 && vertices.first().map(|v| v.1) == vertices.last().map(|v| v.1) {
+        vertices.pop();
+    }
+    if (ccw && ring.is_cw()) || (!ccw && ring.is_ccw()) {
+        vertices.reverse();
+    }
+    vertices
+}
+
+/// Triangulate `polygon`, whose coordinates start at `offset`,
+/// pushing the indices of the triangles to `out`.
+fn earcut<T: GeoFloat>(polygon: &Polygon<T>, offset: usize, out: &mut Vec<usize>) {
+    let mut outline = ring_vertices(polygon.exterior(), offset, true);
+    if outline.len() < 3 {
+        return;
+    }
+
+    // Start each hole at its leftmost vertex. Holes with a
+    // non-finite coordinate can't be bridged, and are skipped.
+    let mut offset = offset + polygon.exterior().0.len();
+    let mut holes = vec![];
+    for interior in polygon.interiors() {
+        let hole_offset = offset;
+        offset += interior.0.len();
+        if !interior
+            .0
+            .iter()
+            .all(|c| c.x.is_finite() && c.y.is_finite())
+        {
+            continue;
+        }
+        let mut hole = ring_vertices(interior, hole_offset, false);
+        if hole.len() < 3 {
+            continue;
+        }
+        let start = (0..hole.len())
+            .min_by(|&i, &j| lex_cmp(&hole[i].1, &hole[j].1))
+            .unwrap();
+        hole.rotate_left(start);
+        holes.push(hole);
+    }
+
+    // Bridge the holes to the outline from left to right, so that
+    // a bridge only ever crosses the holes already merged.
+    holes.sort_by(|a, b| lex_cmp(&a[0].1, &b[0].1));
+    for hole in holes {
+        if let Some(bridge) = find_bridge(&outline, hole[0].1) {
+            let mut merged = Vec::with_capacity(outline.len() + hole.len() + 2);
+            merged.extend_from_slice(&outline[..=bridge]);
+            merged.extend_from_slice(&hole);
+            merged.push(hole[0]);
+            merged.extend_from_slice(&outline[bridge..]);
+            outline = merged;
+        }
+    }
+
+    clip_ears(&outline, out);
+}
+
+/// Find a vertex of the outline visible from `m`, the leftmost
+/// vertex of a hole, following David Eberly's "Triangulation by
+/// Ear Clipping".
+fn find_bridge<T: GeoFloat>(outline: &[(usize, Coordinate<T>)], m: Coordinate<T>) -> Option<usize> {
+    let n = outline.len();
+
+    // Cast a ray to the left of `m`, and find the nearest edge.
+    let mut nearest: Option<(T, usize)> = None;
+    for i in 0..n {
+        let (a, b) = (outline[i].1, outline[(i + 1) % n].1);
+        if a.y == b.y || m.y < a.y.min(b.y) || m.y > a.y.max(b.y) {
+            continue;
+        }
+        let x = a.x + (m.y - a.y) * (b.x - a.x) / (b.y - a.y);
+        if x <= m.x && !matches!(nearest, Some((qx, _)) if qx >= x) {
+            let p = if a.x < b.x { i } else { (i + 1) % n };
+            if x == m.x {
+                return Some(p);
+            }
+            nearest = Some((x, p));
+        }
+    }
+    let (qx, mut bridge) = nearest?;
+
+    // The endpoint of that edge may be hidden by other vertices of
+    // the outline: take instead the one inside the triangle formed
+    // by `m`, the hit and the endpoint, closest in angle to the ray.
+    let p = outline[bridge].1;
+    let hit = Coordinate { x: qx, y: m.y };
+    let (a, c) = if m.y < p.y { (m, hit) } else { (hit, m) };
+    let mut best = (T::infinity(), T::infinity());
+    for (i, &(_, v)) in outline.iter().enumerate() {
+        if v.x >= m.x || v.x < p.x || !in_triangle(a, p, c, v) {
+            continue;
+        }
+        let tan = (m.y - v.y).abs() / (m.x - v.x);
+        let dist = m.x - v.x;
+        if (tan, dist) < best && is_visible(outline, m, v) {
+            best = (tan, dist);
+            bridge = i;
+        }
+    }
+    Some(bridge)
+}
+
+/// Whether the segment from `m` to `v` crosses no edge of the
+/// outline.
+fn is_visible<T: GeoFloat>(
+    outline: &[(usize, Coordinate<T>)],
+    m: Coordinate<T>,
+    v: Coordinate<T>,
+) -> bool {
+    let n = outline.len();
+    (0..n).all(|i| {
+        let (a, b) = (outline[i].1, outline[(i + 1) % n].1);
+        if a == v || b == v {
+            return true;
+        }
+        let (o1, o2) = (T::Ker::orient2d(m, v, a), T::Ker::orient2d(m, v, b));
+        let (o3, o4) = (T::Ker::orient2d(a, b, m), T::Ker::orient2d(a, b, v));
+        !(o1 != o2 && o3 != o4 && o1 != Orientation::Collinear && o2 != Orientation::Collinear)
+    })
+}
+
+/// Whether `p` lies inside or on the boundary of the
+/// counter-clockwise triangle `a`, `b`, `c`.
+fn in_triangle<T: GeoFloat>(
+    a: Coordinate<T>,
+    b: Coordinate<T>,
+    c: Coordinate<T>,
+    p: Coordinate<T>,
+) -> bool {
+    T::Ker::orient2d(a, b, p) != Orientation::Clockwise
+        && T::Ker::orient2d(b, c, p) != Orientation::Clockwise
+        && T::Ker::orient2d(c, a, p) != Orientation::Clockwise
+}
+
+/// Clip the ears of the counter-clockwise `outline`, pushing the
+/// indices of each one to `out`.
+fn clip_ears<T: GeoFloat>(outline: &[(usize, Coordinate<T>)], out: &mut Vec<usize>) {
+    let n = outline.len();
+    let mut prev: Vec<usize> = (0..n).map(|i| (i + n - 1) % n).collect();
+    let mut next: Vec<usize> = (0..n).map(|i| (i + 1) % n).collect();
+    let mut remaining = n;
+
+    // The vertex `i` is an ear if no other vertex lies in the
+    // triangle it forms with its neighbours. When there is none,
+    // the outline is degenerate: first drop the collinear
+    // vertices, then clip vertices regardless.
+    let is_ear = |i: usize, prev: &[usize], next: &[usize]| {
+        let (a, b, c) = (outline[prev[i]].1, outline[i].1, outline[next[i]].1);
+        if T::Ker::orient2d(a, b, c) != Orientation::CounterClockwise {
+            return false;
+        }
+        let mut j = next[next[i]];
+        while j != prev[i] {
+            let p = outline[j].1;
+            if p != a && p != b && p != c && in_triangle(a, b, c, p) {
+                return false;
+            }
+            j = next[j];
+        }
+        true
+    };
+
+    let (mut i, mut stalled, mut pass) = (0, 0, 0);
+    while remaining > 2 {
+        let (a, b, c) = (outline[prev[i]].1, outline[i].1, outline[next[i]].1);
+        let orientation = T::Ker::orient2d(a, b, c);
+        let clip = match pass {
+            0 => is_ear(i, &prev, &next),
+            1 => orientation == Orientation::Collinear,
+            _ => true,
+        };
+        if clip {
+            if orientation == Orientation::CounterClockwise {
+                out.extend([outline[prev[i]].0, outline[i].0, outline[next[i]].0].iter());
+            }
+            let (p, q) = (prev[i], next[i]);
+            next[p] = q;
+            prev[q] = p;
+            remaining -= 1;
+            i = q;
+            stalled = 0;
+            pass = 0;
+        } else {
+            i = next[i];
+            stalled += 1;
+            if stalled >= remaining {
+                stalled = 0;
+                pass += 1;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::area::Area;
+    use crate::algorithm::coords_iter::CoordsIter;
+    use crate::polygon;
+
+    fn assert_triangulation<G>(geometry: &G, area: f64, count: usize)
+    where
+        G: TriangulatePolygon<Scalar = f64> + for<'a> CoordsIter<'a, Scalar = f64>,
+    {
+        let triangles = geometry.triangulate();
+        assert_eq!(triangles.len(), count);
+        let mut total = 0.;
+        for triangle in &triangles {
+            assert!(triangle.signed_area() > 0.);
+            total += triangle.signed_area();
+        }
+        assert_relative_eq!(total, area);
+
+        let coords: Vec<_> = geometry.coords_iter().collect();
+        let indices = geometry.triangulate_indices();
+        assert_eq!(to_triangles(&coords, &indices), triangles);
+    }
+
+    #[test]
+    fn convex() {
+        let poly = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)];
+        assert_triangulation(&poly, 4., 2);
+        assert_eq!(poly.triangulate_indices(), vec![3, 0, 1, 3, 1, 2]);
+    }
+
+    #[test]
+    fn orientation_does_not_matter() {
+        let ccw = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 2., y: 1.), (x: 0., y: 4.)];
+        let cw = polygon![(x: 0., y: 4.), (x: 2., y: 1.), (x: 4., y: 4.), (x: 4., y: 0.), (x: 0., y: 0.)];
+        assert_triangulation(&ccw, 10., 3);
+        assert_triangulation(&cw, 10., 3);
+    }
+
+    #[test]
+    fn concave() {
+        // a comb with three teeth
+        let poly = polygon![
+            (x: 0., y: 0.), (x: 5., y: 0.), (x: 5., y: 3.), (x: 4., y: 3.), (x: 4., y: 1.),
+            (x: 3., y: 1.), (x: 3., y: 3.), (x: 2., y: 3.), (x: 2., y: 1.), (x: 1., y: 1.),
+            (x: 1., y: 3.), (x: 0., y: 3.),
+        ];
+        assert_triangulation(&poly, 11., 10);
+    }
+
+    #[test]
+    fn repeated_and_collinear_points() {
+        let poly = polygon![
+            (x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 0.), (x: 2., y: 0.),
+            (x: 2., y: 2.), (x: 1., y: 2.), (x: 0., y: 2.), (x: 0., y: 1.),
+        ];
+        assert_triangulation(&poly, 4., 5);
+    }
+
+    #[test]
+    fn holes() {
+        let poly = polygon![
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [
+                [(x: 6., y: 2.), (x: 8., y: 2.), (x: 8., y: 8.), (x: 6., y: 8.)],
+                [(x: 1., y: 1.), (x: 1., y: 3.), (x: 3., y: 3.), (x: 3., y: 1.)],
+                [(x: 2., y: 5.), (x: 4., y: 5.), (x: 3., y: 7.)],
+            ],
+        ];
+        // 15 vertices, with two more per hole
+        assert_triangulation(&poly, 100. - 12. - 4. - 2., 19);
+    }
+
+    #[test]
+    fn hole_touching_the_exterior() {
+        let poly = polygon![
+            exterior: [(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)],
+            interiors: [[(x: 0., y: 2.), (x: 2., y: 1.), (x: 2., y: 3.)]],
+        ];
+        assert_triangulation(&poly, 14., 6);
+    }
+
+    #[test]
+    fn non_finite_hole() {
+        let poly = polygon![
+            exterior: [(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)],
+            interiors: [
+                [(x: 1., y: 1.), (x: f64::NAN, y: 1.), (x: 2., y: 2.)],
+                [(x: 2., y: 1.), (x: 3., y: 1.), (x: 3., y: 2.)],
+            ],
+        ];
+        // as if the first hole wasn't there
+        assert_triangulation(&poly, 15.5, 7);
+    }
+
+    #[test]
+    fn multi_polygon() {
+        let mp = MultiPolygon(vec![
+            polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 0., y: 1.)],
+            polygon![(x: 2., y: 0.), (x: 3., y: 0.), (x: 3., y: 1.), (x: 2., y: 1.)],
+        ]);
+        assert_triangulation(&mp, 1.5, 3);
+        assert!(mp.triangulate_indices().iter().all(|&i| i != 3 && i != 8));
+    }
+
+    #[test]
+    fn empty() {
+        let poly: Polygon<f64> = Polygon::new(LineString(vec![]), vec![]);
+        assert!(poly.triangulate().is_empty());
+    }
+}
@@ -0,0 +1,651 @@
+use crate::algorithm::bounding_rect::BoundingRect;
+use crate::algorithm::coordinate_position::{coord_pos_relative_to_ring, CoordPos};
+use crate::algorithm::dimensions::Dimensions;
+use crate::algorithm::intersects::Intersects;
+use crate::algorithm::line_intersection::{line_intersection, LineIntersection};
+use crate::algorithm::noding::for_each_candidate_pair;
+use crate::algorithm::relate::Relate;
+use crate::utils::lex_cmp;
+use crate::{
+    Coordinate, GeoFloat, Geometry, GeometryCollection, Line, LineString, MultiLineString,
+    MultiPoint, MultiPolygon, Point, Polygon, Rect, Triangle,
+};
+use rstar::RTreeNum;
+use std::{error, fmt};
+
+/// A reason for a geometry to be invalid, following the rules
+/// of the OGC Simple Feature Access specification, along with
+/// the coordinates involved.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ValidationError<T: GeoFloat> {
+    /// A coordinate is NaN or infinite.
+    NonFiniteCoord(Coordinate<T>),
+    /// A line string or ring has fewer distinct points than it
+    /// needs: 2 for a line string, 3 for a ring.
+    TooFewPoints { found: usize, minimum: usize },
+    /// The coordinate immediately follows itself.
+    RepeatedPoint(Coordinate<T>),
+    /// A ring crosses or touches itself at the coordinate.
+    SelfIntersection(Coordinate<T>),
+    /// Two rings of a polygon cross, share a segment, or touch
+    /// in more than one point, at the coordinate.
+    IntersectingRings(Coordinate<T>),
+    /// A hole of a polygon lies (partly) outside of its
+    /// exterior; the coordinate is a vertex of the hole outside
+    /// the exterior.
+    HoleOutsideShell(Coordinate<T>),
+    /// A hole of a polygon lies inside another hole; the
+    /// coordinate is a vertex of the inner hole.
+    NestedHoles(Coordinate<T>),
+    /// The interiors of two polygons of a `MultiPolygon`
+    /// intersect, or their boundaries share a segment. The
+    /// indices of the polygons are given.
+    OverlappingPolygons { first: usize, second: usize },
+}
+
+impl<T: GeoFloat> fmt::Display for ValidationError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ValidationError::NonFiniteCoord(c) => {
+                write!(f, "non-finite coordinate ({:?}, {:?})", c.x, c.y)
+            }
+            ValidationError::TooFewPoints { found, minimum } => write!(
+                f,
+                "too few points: found {} distinct points, need at least {}",
+                found, minimum
+            ),
+            ValidationError::RepeatedPoint(c) => {
+                write!(f, "repeated point ({:?}, {:?})", c.x, c.y)
+            }
+            ValidationError::SelfIntersection(c) => {
+                write!(f, "ring self-intersection at ({:?}, {:?})", c.x, c.y)
+            }
+            ValidationError::IntersectingRings(c) => {
+                write!(f, "rings intersect at ({:?}, {:?})", c.x, c.y)
+            }
+            ValidationError::HoleOutsideShell(c) => {
+                write!(f, "hole lies outside shell at ({:?}, {:?})", c.x, c.y)
+            }
+            ValidationError::NestedHoles(c) => {
+                write!(f, "hole lies inside another hole at ({:?}, {:?})", c.x, c.y)
+            }
+            ValidationError::OverlappingPolygons { first, second } => write!(
+                f,
+                "polygons {} and {} of the multi polygon overlap",
+                first, second
+            ),
+        }
+    }
+}
+
+impl<T: GeoFloat> error::Error for ValidationError<T> {}
+
+/// Check whether a geometry is valid, according to the OGC
+/// Simple Feature Access specification, and report why not.
+///
+/// Most algorithms assume valid input, and may silently give
+/// wrong results otherwise. Empty geometries are valid.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::validation::{Validation, ValidationError};
+/// use geo::{polygon, Coordinate};
+///
+/// let square = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)];
+/// assert!(square.is_valid());
+///
+/// let bow_tie = polygon![(x: 0., y: 0.), (x: 2., y: 2.), (x: 2., y: 0.), (x: 0., y: 2.)];
+/// assert_eq!(
+///     bow_tie.validate(),
+///     Err(vec![ValidationError::SelfIntersection(Coordinate { x: 1., y: 1. })])
+/// );
+/// ```
+pub trait Validation {
+    type Scalar: GeoFloat;
+
+    /// Whether the geometry is valid.
+    fn is_valid(&self) -> bool {
+        self.validate().is_ok()
+    }
+
+    /// Report all reasons for the geometry to be invalid.
+    fn validate(&self) -> Result<(), Vec<ValidationError<Self::Scalar>>> {
+        let mut errors = vec![];
+        self.collect_validation_errors(&mut errors);
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    // impls of this trait must push every reason for `self` to
+    // be invalid onto `errors`.
+    fn collect_validation_errors(&self, errors: &mut Vec<ValidationError<Self::Scalar>>);
+}
+
+impl<T: GeoFloat> Validation for Point<T> {
+    type Scalar = T;
+
+    fn collect_validation_errors(&self, errors: &mut Vec<ValidationError<T>>) {
+        check_finite(std::iter::once(&self.0), errors);
+    }
+}
+
+impl<T: GeoFloat> Validation for MultiPoint<T> {
+    type Scalar = T;
+
+    fn collect_validation_errors(&self, errors: &mut Vec<ValidationError<T>>) {
+        check_finite(self.iter().map(|point| &point.0), errors);
+    }
+}
+
+impl<T: GeoFloat> Validation for Line<T> {
+    type Scalar = T;
+
+    fn collect_validation_errors(&self, errors: &mut Vec<ValidationError<T>>) {
+        check_finite([self.start, self.end].iter(), errors);
+        if self.start == self.end {
+            errors.push(ValidationError::TooFewPoints {
+                found: 1,
+                minimum: 2,
+            });
+        }
+    }
+}
+
+impl<T: GeoFloat> Validation for LineString<T> {
+    type Scalar = T;
+
+    fn collect_validation_errors(&self, errors: &mut Vec<ValidationError<T>>) {
+        check_points(self, 2, errors);
+    }
+}
+
+impl<T: GeoFloat> Validation for MultiLineString<T> {
+    type Scalar = T;
+
+    fn collect_validation_errors(&self, errors: &mut Vec<ValidationError<T>>) {
+        for line_string in self {
+            line_string.collect_validation_errors(errors);
+        }
+    }
+}
+
+impl<T: GeoFloat + RTreeNum> Validation for Polygon<T> {
+    type Scalar = T;
+
+    fn collect_validation_errors(&self, errors: &mut Vec<ValidationError<T>>) {
+        if self.exterior().0.is_empty() {
+            return;
+        }
+        let count = errors.len();
+        for ring in std::iter::once(self.exterior()).chain(self.interiors()) {
+            check_points(ring, 3, errors);
+        }
+        // The topology of the rings can only be checked once
+        // their points are fine.
+        if errors[count..].iter().any(|error| {
+            matches!(
+                error,
+                ValidationError::NonFiniteCoord(_) | ValidationError::TooFewPoints { .. }
+            )
+        }) {
+            return;
+        }
+        let count = errors.len();
+        check_ring_intersections(self, errors);
+        if errors.len() == count {
+            check_hole_positions(self, errors);
+        }
+    }
+}
+
+impl<T: GeoFloat + RTreeNum> Validation for MultiPolygon<T> {
+    type Scalar = T;
+
+    fn collect_validation_errors(&self, errors: &mut Vec<ValidationError<T>>) {
+        let count = errors.len();
+        for polygon in self {
+            polygon.collect_validation_errors(errors);
+        }
+        if errors.len() > count {
+            return;
+        }
+
+        let rects: Vec<_> = self.iter().map(|polygon| polygon.bounding_rect()).collect();
+        for (first, polygon) in self.iter().enumerate() {
+            for second in first + 1..self.0.len() {
+                let overlaps = match (rects[first], rects[second]) {
+                    (Some(a), Some(b)) => a.intersects(&b),
+                    _ => false,
+                };
+                if !overlaps {
+                    continue;
+                }
+                // Polygons may only touch in points.
+                let matrix = polygon.relate(&self.0[second]);
+                if matrix.get(CoordPos::Inside, CoordPos::Inside) != Dimensions::Empty
+                    || matrix.get(CoordPos::OnBoundary, CoordPos::OnBoundary)
+                        == Dimensions::OneDimensional
+                {
+                    errors.push(ValidationError::OverlappingPolygons { first, second });
+                }
+            }
+        }
+    }
+}
+
+impl<T: GeoFloat> Validation for Rect<T> {
+    type Scalar = T;
+
+    fn collect_validation_errors(&self, errors: &mut Vec<ValidationError<T>>) {
+        check_finite([self.min(), self.max()].iter(), errors);
+    }
+}
+
+impl<T: GeoFloat> Validation for Triangle<T> {
+    type Scalar = T;
+
+    fn collect_validation_errors(&self, errors: &mut Vec<ValidationError<T>>) {
+        check_finite([self.0, self.1, self.2].iter(), errors);
+    }
+}
+
+impl<T: GeoFloat + RTreeNum> Validation for GeometryCollection<T> {
+    type Scalar = T;
+
+    fn collect_validation_errors(&self, errors: &mut Vec<ValidationError<T>>) {
+        for geometry in self {
+            geometry.collect_validation_errors(errors);
+        }
+    }
+}
+
+impl<T: GeoFloat + RTreeNum> Validation for Geometry<T> {
+    type Scalar = T;
+
+    fn collect_validation_errors(&self, errors: &mut Vec<ValidationError<T>>) {
+        match self {
+            Geometry::Point(g) => g.collect_validation_errors(errors),
+            Geometry::Line(g) => g.collect_validation_errors(errors),
+            Geometry::LineString(g) => g.collect_validation_errors(errors),
+            Geometry::Polygon(g) => g.collect_validation_errors(errors),
+            Geometry::MultiPoint(g) => g.collect_validation_errors(errors),
+            Geometry::MultiLineString(g) => g.collect_validation_errors(errors),
+            Geometry::MultiPolygon(g) => g.collect_validation_errors(errors),
+            Geometry::GeometryCollection(g) => g.collect_validation_errors(errors),
+            Geometry::Rect(g) => g.collect_validation_errors(errors),
+            Geometry::Triangle(g) => g.collect_validation_errors(errors),
+        }
+    }
+}
+
+fn check_finite<'a, T: GeoFloat + 'a>(
+    coords: impl Iterator<Item = &'a Coordinate<T>>,
+    errors: &mut Vec<ValidationError<T>>,
+) {
+    for coord in coords {
+        if !(coord.x.is_finite() && coord.y.is_finite()) {
+            errors.push(ValidationError::NonFiniteCoord(*coord));
+        }
+    }
+}
+
+// Check the points of a non-empty line string, or of a ring
+// (whose last point repeats its first).
+fn check_points<T: GeoFloat>(
+    line_string: &LineString<T>,
+    minimum: usize,
+    errors: &mut Vec<ValidationError<T>>,
+) {
+    if line_string.0.is_empty() {
+        return;
+    }
+    check_finite(line_string.0.iter(), errors);
+    let mut distinct = 1;
+    for pair in line_string.0.windows(2) {
+        if pair[0] == pair[1] {
+            errors.push(ValidationError::RepeatedPoint(pair[0]));
+        } else {
+            distinct += 1;
+        }
+    }
+    // The closing point of a ring is not distinct.
+    if line_string.is_closed() && distinct > 1 {
+        distinct -= 1;
+    }
+    if distinct < minimum {
+        errors.push(ValidationError::TooFewPoints {
+            found: distinct,
+            minimum,
+        });
+    }
+}
+
+// Check that no ring intersects itself, and that rings only
+// touch each other in single points.
+fn check_ring_intersections<T: GeoFloat + RTreeNum>(
+    polygon: &Polygon<T>,
+    errors: &mut Vec<ValidationError<T>>,
+) {
+    let rings: Vec<&LineString<T>> = std::iter::once(polygon.exterior())
+        .chain(polygon.interiors())
+        .collect();
+
+    // Every non-degenerate segment, with its ring, its index in
+    // the ring, and the number of segments of the ring.
+    let mut segments = vec![];
+    let mut lines = vec![];
+    for (ring_idx, ring) in rings.iter().enumerate() {
+        let ring_lines: Vec<Line<T>> = ring.lines().filter(|l| l.start != l.end).collect();
+        for (idx, line) in ring_lines.iter().enumerate() {
+            segments.push((ring_idx, idx, ring_lines.len()));
+            lines.push(*line);
+        }
+    }
+
+    let mut self_intersections = vec![];
+    let mut touches: Vec<(usize, usize, Coordinate<T>)> = vec![];
+    let mut crossings = vec![];
+    for_each_candidate_pair(&lines, |i, j| {
+        let (ring_i, idx_i, len) = segments[i];
+        let (ring_j, idx_j, _) = segments[j];
+        let intersection = match line_intersection(lines[i], lines[j]) {
+            Some(intersection) => intersection,
+            None => return,
+        };
+        if ring_i == ring_j {
+            let (lo, hi) = (idx_i.min(idx_j), idx_i.max(idx_j));
+            let adjacent = hi == lo + 1 || (lo == 0 && hi == len - 1);
+            match intersection {
+                // Consecutive segments share their common vertex.
+                LineIntersection::SinglePoint { .. } if adjacent => {}
+                LineIntersection::SinglePoint { intersection, .. } => {
+                    self_intersections.push(intersection)
+                }
+                LineIntersection::Collinear { intersection } => {
+                    self_intersections.push(intersection.start)
+                }
+            }
+        } else {
+            match intersection {
+                LineIntersection::SinglePoint {
+                    intersection,
+                    is_proper: false,
+                } => touches.push((ring_i.min(ring_j), ring_i.max(ring_j), intersection)),
+                LineIntersection::SinglePoint { intersection, .. } => crossings.push(intersection),
+                LineIntersection::Collinear { intersection } => crossings.push(intersection.start),
+            }
+        }
+    });
+
+    // Rings touching in several points split the interior.
+    touches.sort_by(|a, b| {
+        (a.0, a.1)
+            .cmp(&(b.0, b.1))
+            .then_with(|| lex_cmp(&a.2, &b.2))
+    });
+    touches.dedup();
+    for pair in touches.windows(2) {
+        if (pair[0].0, pair[0].1) == (pair[1].0, pair[1].1) {
+            crossings.push(pair[1].2);
+        }
+    }
+
+    for coords in [&mut self_intersections, &mut crossings].iter_mut() {
+        coords.sort_by(lex_cmp);
+        coords.dedup();
+    }
+    errors.extend(
+        self_intersections
+            .into_iter()
+            .map(ValidationError::SelfIntersection),
+    );
+    errors.extend(
+        crossings
+            .into_iter()
+            .map(ValidationError::IntersectingRings),
+    );
+}
+
+// Check that each hole lies within the exterior, and outside of
+// the other holes. Rings are known not to cross, so one vertex
+// off the other ring decides.
+fn check_hole_positions<T: GeoFloat>(polygon: &Polygon<T>, errors: &mut Vec<ValidationError<T>>) {
+    let first_off = |ring: &LineString<T>, other: &LineString<T>| {
+        ring.0
+            .iter()
+            .map(|c| (*c, coord_pos_relative_to_ring(*c, other)))
+            .find(|(_, pos)| *pos != CoordPos::OnBoundary)
+    };
+    for (idx, hole) in polygon.interiors().iter().enumerate() {
+        if let Some((c, CoordPos::Outside)) = first_off(hole, polygon.exterior()) {
+            errors.push(ValidationError::HoleOutsideShell(c));
+            continue;
+        }
+        for (other_idx, other) in polygon.interiors().iter().enumerate() {
+            if other_idx != idx {
+                if let Some((c, CoordPos::Inside)) = first_off(hole, other) {
+                    errors.push(ValidationError::NestedHoles(c));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, polygon};
+
+    #[test]
+    fn valid_geometries() {
+        assert!(point!(x: 1., y: 2.).is_valid());
+        assert!(
+            line_string![(x: 0., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.), (x: 1., y: 0.)].is_valid()
+        );
+        assert!(LineString::<f64>(vec![]).is_valid());
+        assert!(polygon![
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [
+                [(x: 2., y: 2.), (x: 4., y: 2.), (x: 4., y: 4.), (x: 2., y: 4.)],
+                [(x: 5., y: 5.), (x: 7., y: 5.), (x: 7., y: 7.), (x: 5., y: 7.)],
+            ],
+        ]
+        .is_valid());
+        // holes may touch the exterior, and each other, in one point
+        assert!(polygon![
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [
+                [(x: 0., y: 5.), (x: 2., y: 4.), (x: 2., y: 6.)],
+                [(x: 2., y: 6.), (x: 3., y: 6.), (x: 3., y: 7.), (x: 2., y: 7.)],
+            ],
+        ]
+        .is_valid());
+        assert!(MultiPolygon(vec![
+            polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)],
+            polygon![(x: 1., y: 1.), (x: 2., y: 1.), (x: 2., y: 2.), (x: 1., y: 2.)],
+        ])
+        .is_valid());
+    }
+
+    #[test]
+    fn non_finite_coords() {
+        let p = point!(x: f64::NAN, y: 0.);
+        assert_eq!(p.validate().unwrap_err().len(), 1);
+        let ls = line_string![(x: 0., y: 0.), (x: f64::INFINITY, y: 1.)];
+        assert_eq!(
+            ls.validate(),
+            Err(vec![ValidationError::NonFiniteCoord(Coordinate {
+                x: f64::INFINITY,
+                y: 1.
+            })])
+        );
+    }
+
+    #[test]
+    fn too_few_and_repeated_points() {
+        let ls = line_string![(x: 0., y: 0.), (x: 0., y: 0.)];
+        assert_eq!(
+            ls.validate(),
+            Err(vec![
+                ValidationError::RepeatedPoint(Coordinate { x: 0., y: 0. }),
+                ValidationError::TooFewPoints {
+                    found: 1,
+                    minimum: 2
+                },
+            ])
+        );
+
+        let poly = polygon![(x: 0., y: 0.), (x: 1., y: 0.)];
+        assert_eq!(
+            poly.validate(),
+            Err(vec![ValidationError::TooFewPoints {
+                found: 2,
+                minimum: 3
+            }])
+        );
+
+        let poly = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.)];
+        assert_eq!(
+            poly.validate(),
+            Err(vec![ValidationError::RepeatedPoint(Coordinate {
+                x: 1.,
+                y: 0.
+            })])
+        );
+    }
+
+    #[test]
+    fn self_intersecting_rings() {
+        // a ring touching itself
+        let poly = polygon![
+            (x: 0., y: 0.),
+            (x: 4., y: 0.),
+            (x: 4., y: 4.),
+            (x: 2., y: 0.),
+            (x: 0., y: 4.),
+        ];
+        assert_eq!(
+            poly.validate(),
+            Err(vec![ValidationError::SelfIntersection(Coordinate {
+                x: 2.,
+                y: 0.
+            })])
+        );
+
+        // a spike doubling back along itself
+        let poly = polygon![
+            (x: 0., y: 0.),
+            (x: 4., y: 0.),
+            (x: 6., y: 0.),
+            (x: 4., y: 0.),
+            (x: 4., y: 4.),
+        ];
+        assert_eq!(
+            poly.validate(),
+            Err(vec![ValidationError::SelfIntersection(Coordinate {
+                x: 4.,
+                y: 0.
+            })])
+        );
+    }
+
+    #[test]
+    fn intersecting_rings() {
+        let poly = polygon![
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [[(x: 8., y: 8.), (x: 12., y: 8.), (x: 12., y: 12.), (x: 8., y: 12.)]],
+        ];
+        assert_eq!(
+            poly.validate(),
+            Err(vec![
+                ValidationError::IntersectingRings(Coordinate { x: 8., y: 10. }),
+                ValidationError::IntersectingRings(Coordinate { x: 10., y: 8. }),
+            ])
+        );
+
+        // holes sharing a segment
+        let poly = polygon![
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [
+                [(x: 2., y: 2.), (x: 4., y: 2.), (x: 4., y: 4.), (x: 2., y: 4.)],
+                [(x: 4., y: 2.), (x: 6., y: 2.), (x: 6., y: 4.), (x: 4., y: 4.)],
+            ],
+        ];
+        assert_eq!(
+            poly.validate(),
+            Err(vec![
+                ValidationError::IntersectingRings(Coordinate { x: 4., y: 2. }),
+                ValidationError::IntersectingRings(Coordinate { x: 4., y: 4. }),
+            ])
+        );
+    }
+
+    #[test]
+    fn holes_outside_shell_or_nested() {
+        let poly = polygon![
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [[(x: 20., y: 20.), (x: 21., y: 20.), (x: 21., y: 21.), (x: 20., y: 21.)]],
+        ];
+        assert_eq!(
+            poly.validate(),
+            Err(vec![ValidationError::HoleOutsideShell(Coordinate {
+                x: 20.,
+                y: 20.
+            })])
+        );
+
+        let poly = polygon![
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [
+                [(x: 2., y: 2.), (x: 8., y: 2.), (x: 8., y: 8.), (x: 2., y: 8.)],
+                [(x: 3., y: 3.), (x: 4., y: 3.), (x: 4., y: 4.), (x: 3., y: 4.)],
+            ],
+        ];
+        assert_eq!(
+            poly.validate(),
+            Err(vec![ValidationError::NestedHoles(Coordinate {
+                x: 3.,
+                y: 3.
+            })])
+        );
+    }
+
+    #[test]
+    fn overlapping_polygons() {
+        let mp = MultiPolygon(vec![
+            polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)],
+            polygon![(x: 5., y: 5.), (x: 6., y: 5.), (x: 6., y: 6.), (x: 5., y: 6.)],
+            polygon![(x: 1., y: 1.), (x: 3., y: 1.), (x: 3., y: 3.), (x: 1., y: 3.)],
+        ]);
+        assert_eq!(
+            mp.validate(),
+            Err(vec![ValidationError::OverlappingPolygons {
+                first: 0,
+                second: 2
+            }])
+        );
+
+        let mp = MultiPolygon(vec![
+            polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)],
+            polygon![(x: 2., y: 0.), (x: 4., y: 0.), (x: 4., y: 2.), (x: 2., y: 2.)],
+        ]);
+        assert!(!mp.is_valid());
+    }
+
+    #[test]
+    fn collections_report_all_errors() {
+        let collection = GeometryCollection(vec![
+            Geometry::Point(point!(x: f64::NAN, y: 0.)),
+            Geometry::Line(Line::new((1., 1.), (1., 1.))),
+        ]);
+        assert_eq!(collection.validate().unwrap_err().len(), 2);
+        assert_eq!(
+            collection.validate().unwrap_err()[1].to_string(),
+            "too few points: found 1 distinct points, need at least 2"
+        );
+    }
+}
@@ -0,0 +1,261 @@
+use crate::algorithm::bool_ops::BooleanOps;
+use crate::algorithm::bounding_rect::BoundingRect;
+use crate::algorithm::delaunay_triangulation::triangulate;
+use crate::utils::lex_cmp;
+use crate::{Coordinate, GeoFloat, LineString, MultiPoint, MultiPolygon, Polygon, Rect};
+use rstar::RTreeNum;
+
+/// Compute the [Voronoi diagram] of a set of points: the cell
+/// of each point is the area closer to it than to any other
+/// point.
+///
+/// The cells are returned in the order of the input points,
+/// clipped to the given bounds. Duplicate points get the same
+/// cell, and non-finite points an empty one.
+///
+/// [Voronoi diagram]: https://en.wikipedia.org/wiki/Voronoi_diagram
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::area::Area;
+/// use geo::algorithm::voronoi::Voronoi;
+/// use geo::{Coordinate, MultiPoint, Rect};
+///
+/// let depots: MultiPoint<f64> = vec![(1., 1.), (3., 1.), (2., 3.)].into();
+/// let bounds = Rect::new(Coordinate { x: 0., y: 0. }, Coordinate { x: 4., y: 4. });
+///
+/// let cells = depots.voronoi_cells(&bounds);
+/// assert_eq!(cells.len(), 3);
+/// assert_eq!(cells[0].unsigned_area(), cells[1].unsigned_area());
+/// let total: f64 = cells.iter().map(|cell| cell.unsigned_area()).sum();
+/// assert_eq!(total, 16.);
+/// ```
+pub trait Voronoi<T: GeoFloat> {
+    /// Return the cell of each point, clipped to `bounds`.
+    fn voronoi_cells(&self, bounds: &Rect<T>) -> Vec<Polygon<T>>;
+
+    /// Return the cell of each point, clipped to `bounds`. A
+    /// concave `bounds` may split a cell into several parts.
+    fn voronoi_cells_in_polygon(&self, bounds: &Polygon<T>) -> Vec<MultiPolygon<T>>;
+}
+
+impl<T: GeoFloat + RTreeNum> Voronoi<T> for MultiPoint<T> {
+    fn voronoi_cells(&self, bounds: &Rect<T>) -> Vec<Polygon<T>> {
+        let coords: Vec<_> = self.iter().map(|p| p.0).collect();
+        let (min, max) = (bounds.min(), bounds.max());
+        let rect = vec![
+            min,
+            Coordinate { x: max.x, y: min.y },
+            max,
+            Coordinate { x: min.x, y: max.y },
+        ];
+
+        // The cell of a point is bounded by the bisectors between
+        // it and its neighbours in the Delaunay triangulation. If
+        // the points are collinear, there is none: use the points
+        // next to it along their line instead.
+        let canonical = canonical_indices(&coords);
+        let mut neighbours = vec![vec![]; coords.len()];
+        let triangles = triangulate(&coords);
+        for [a, b, c] in triangles.iter().copied() {
+            for &(i, j) in &[(a, b), (b, c), (c, a)] {
+                neighbours[i].push(j);
+                neighbours[j].push(i);
+            }
+        }
+        if triangles.is_empty() {
+            let mut distinct: Vec<_> = (0..coords.len())
+                .filter(|&i| canonical[i] == Some(i))
+                .collect();
+            distinct.sort_by(|&i, &j| lex_cmp(&coords[i], &coords[j]));
+            for pair in distinct.windows(2) {
+                neighbours[pair[0]].push(pair[1]);
+                neighbours[pair[1]].push(pair[0]);
+            }
+        }
+
+        let mut cells: Vec<Polygon<T>> = Vec::with_capacity(coords.len());
+        for i in 0..coords.len() {
+            let cell = match canonical[i] {
+                None => vec![],
+                Some(c) if c < i => {
+                    cells.push(cells[c].clone());
+                    continue;
+                }
+                Some(_) => neighbours[i].iter().fold(rect.clone(), |cell, &j| {
+                    clip_to_half_plane(&cell, coords[i], coords[j])
+                }),
+            };
+            let mut ring = LineString(cell);
+            ring.close();
+            cells.push(Polygon::new(ring, vec![]));
+        }
+        cells
+    }
+
+    fn voronoi_cells_in_polygon(&self, bounds: &Polygon<T>) -> Vec<MultiPolygon<T>> {
+        let rect = match bounds.bounding_rect() {
+            Some(rect) => rect,
+            None => return vec![MultiPolygon(vec![]); self.0.len()],
+        };
+        self.voronoi_cells(&rect)
+            .iter()
+            .map(|cell| {
+                if cell.exterior().0.is_empty() {
+                    MultiPolygon(vec![])
+                } else {
+                    cell.intersection(bounds)
+                }
+            })
+            .collect()
+    }
+}
+
+/// The index of the first occurrence of each coordinate, or
+/// `None` if it is not finite.
+fn canonical_indices<T: GeoFloat>(coords: &[Coordinate<T>]) -> Vec<Option<usize>> {
+    let mut order: Vec<usize> = (0..coords.len())
+        .filter(|&i| coords[i].x.is_finite() && coords[i].y.is_finite())
+        .collect();
+    order.sort_by(|&i, &j| lex_cmp(&coords[i], &coords[j]).then(i.cmp(&j)));
+
+    let mut canonical = vec![None; coords.len()];
+    let mut first = None;
+    for i in order {
+        first = match first {
+            Some(f) if coords[f] == coords[i] => Some(f),
+            _ => Some(i),
+        };
+        canonical[i] = first;
+    }
+    canonical
+}
+
+/// Clip the convex `cell` to the half-plane closer to `p` than
+/// to `q`.
+fn clip_to_half_plane<T: GeoFloat>(
+    cell: &[Coordinate<T>],
+    p: Coordinate<T>,
+    q: Coordinate<T>,
+) -> Vec<Coordinate<T>> {
+    let two = T::one() + T::one();
+    let mid = Coordinate {
+        x: (p.x + q.x) / two,
+        y: (p.y + q.y) / two,
+    };
+    let dir = q - p;
+    // Positive on the side of `q`.
+    let side = |c: Coordinate<T>| (c.x - mid.x) * dir.x + (c.y - mid.y) * dir.y;
+
+    let mut clipped = vec![];
+    for (k, &a) in cell.iter().enumerate() {
+        let b = cell[(k + 1) % cell.len()];
+        let (sa, sb) = (side(a), side(b));
+        if sa <= T::zero() {
+            clipped.push(a);
+        }
+        if (sa < T::zero() && sb > T::zero()) || (sa > T::zero() && sb < T::zero()) {
+            let t = sa / (sa - sb);
+            clipped.push(Coordinate {
+                x: a.x + (b.x - a.x) * t,
+                y: a.y + (b.y - a.y) * t,
+            });
+        }
+    }
+    clipped
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::area::Area;
+    use crate::algorithm::contains::Contains;
+    use crate::{point, polygon};
+
+    #[test]
+    fn single_point() {
+        let cells =
+            MultiPoint(vec![point!(x: 3., y: 3.)]).voronoi_cells(&Rect::new((0., 0.), (10., 10.)));
+        assert_eq!(cells.len(), 1);
+        assert_relative_eq!(cells[0].unsigned_area(), 100.);
+    }
+
+    #[test]
+    fn two_points() {
+        let mp: MultiPoint<f64> = vec![(2., 5.), (6., 5.)].into();
+        let cells = mp.voronoi_cells(&Rect::new((0., 0.), (10., 10.)));
+        assert_relative_eq!(cells[0].unsigned_area(), 40.);
+        assert_relative_eq!(cells[1].unsigned_area(), 60.);
+    }
+
+    #[test]
+    fn collinear_points() {
+        let mp: MultiPoint<f64> = vec![(1., 5.), (9., 5.), (5., 5.)].into();
+        let cells = mp.voronoi_cells(&Rect::new((0., 0.), (10., 10.)));
+        assert_relative_eq!(cells[0].unsigned_area(), 30.);
+        assert_relative_eq!(cells[1].unsigned_area(), 30.);
+        assert_relative_eq!(cells[2].unsigned_area(), 40.);
+
+        // along a diagonal, with a duplicate
+        let mp: MultiPoint<f64> = vec![(7., 7.), (1., 1.), (3., 3.), (5., 5.), (3., 3.)].into();
+        let cells = mp.voronoi_cells(&Rect::new((0., 0.), (10., 10.)));
+        let total: f64 = cells[..4].iter().map(|cell| cell.unsigned_area()).sum();
+        assert_relative_eq!(total, 100., epsilon = 1e-10);
+        assert_eq!(cells[2], cells[4]);
+        for (point, cell) in mp.iter().zip(&cells) {
+            assert!(cell.contains(point));
+        }
+    }
+
+    #[test]
+    fn grid() {
+        let coords: Vec<_> = (0..25)
+            .map(|i| ((i % 5) as f64 * 2. + 1., (i / 5) as f64 * 2. + 1.))
+            .collect();
+        let mp: MultiPoint<f64> = coords.into();
+        let cells = mp.voronoi_cells(&Rect::new((0., 0.), (10., 10.)));
+        assert_eq!(cells.len(), 25);
+        for (point, cell) in mp.iter().zip(&cells) {
+            assert_relative_eq!(cell.unsigned_area(), 4.);
+            assert!(cell.contains(point));
+        }
+    }
+
+    #[test]
+    fn cells_contain_their_points() {
+        let mp: MultiPoint<f64> =
+            vec![(1., 1.), (8., 2.), (4., 4.), (2., 9.), (7., 7.), (5., 1.)].into();
+        let cells = mp.voronoi_cells(&Rect::new((0., 0.), (10., 10.)));
+        let total: f64 = cells.iter().map(|cell| cell.unsigned_area()).sum();
+        assert_relative_eq!(total, 100.);
+        for (point, cell) in mp.iter().zip(&cells) {
+            assert!(cell.contains(point));
+        }
+    }
+
+    #[test]
+    fn duplicate_and_non_finite_points() {
+        let mp: MultiPoint<f64> = vec![(2., 5.), (6., 5.), (2., 5.), (f64::NAN, 1.)].into();
+        let cells = mp.voronoi_cells(&Rect::new((0., 0.), (10., 10.)));
+        assert_eq!(cells.len(), 4);
+        assert_eq!(cells[0], cells[2]);
+        assert_relative_eq!(cells[1].unsigned_area(), 60.);
+        assert!(cells[3].exterior().0.is_empty());
+    }
+
+    #[test]
+    fn clipped_to_polygon() {
+        // an L shape, which cuts the cell of the second point in two
+        let bounds = polygon![
+            (x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 2.), (x: 2., y: 2.),
+            (x: 2., y: 10.), (x: 0., y: 10.),
+        ];
+        let mp: MultiPoint<f64> = vec![(1., 1.), (6., 6.)].into();
+        let cells = mp.voronoi_cells_in_polygon(&bounds);
+        assert_eq!(cells[0].0.len(), 1);
+        assert_eq!(cells[1].0.len(), 2);
+        let total: f64 = cells.iter().map(|cell| cell.unsigned_area()).sum();
+        assert_relative_eq!(total, 36.);
+    }
+}
@@ -90,38 +90,79 @@ const EARTH_FLATTENING: f64 =
 /// A prelude which re-exports the traits for manipulating objects in this
 /// crate. Typically imported with `use geo::prelude::*`.
 pub mod prelude {
+    pub use crate::algorithm::affine_ops::AffineOps;
+    pub use crate::algorithm::along_track_distance::{
+        AlongTrackDistance, GeodesicAlongTrackDistance,
+    };
+    pub use crate::algorithm::antimeridian::{SplitAntimeridian, WrappedBoundingRect};
     pub use crate::algorithm::area::Area;
     pub use crate::algorithm::bearing::Bearing;
+    pub use crate::algorithm::bool_ops::BooleanOps;
     pub use crate::algorithm::bounding_rect::BoundingRect;
+    pub use crate::algorithm::buffer::Buffer;
     pub use crate::algorithm::centroid::Centroid;
     pub use crate::algorithm::chamberlain_duquette_area::ChamberlainDuquetteArea;
     pub use crate::algorithm::closest_point::ClosestPoint;
     pub use crate::algorithm::contains::Contains;
     pub use crate::algorithm::convex_hull::ConvexHull;
+    pub use crate::algorithm::cross_track_distance::{
+        CrossTrackDistance, GeodesicCrossTrackDistance,
+    };
+    pub use crate::algorithm::delaunay_triangulation::DelaunayTriangulation;
+    pub use crate::algorithm::densify::Densify;
+    pub use crate::algorithm::densify_geodesic::DensifyGeodesic;
+    pub use crate::algorithm::densify_haversine::DensifyHaversine;
     pub use crate::algorithm::dimensions::HasDimensions;
     pub use crate::algorithm::euclidean_distance::EuclideanDistance;
     pub use crate::algorithm::euclidean_length::EuclideanLength;
     pub use crate::algorithm::extremes::Extremes;
     pub use crate::algorithm::frechet_distance::FrechetDistance;
+    pub use crate::algorithm::geodesic_area::GeodesicArea;
+    pub use crate::algorithm::geodesic_bearing::GeodesicBearing;
+    pub use crate::algorithm::geodesic_destination::GeodesicDestination;
     pub use crate::algorithm::geodesic_distance::GeodesicDistance;
-    pub use crate::algorithm::geodesic_length::GeodesicLength;
     pub use crate::algorithm::geodesic_intermediate::GeodesicIntermediate;
+    pub use crate::algorithm::geodesic_length::GeodesicLength;
+    pub use crate::algorithm::hausdorff_distance::HausdorffDistance;
     pub use crate::algorithm::haversine_destination::HaversineDestination;
     pub use crate::algorithm::haversine_distance::HaversineDistance;
     pub use crate::algorithm::haversine_intermediate::HaversineIntermediate;
     pub use crate::algorithm::haversine_length::HaversineLength;
     pub use crate::algorithm::intersects::Intersects;
     pub use crate::algorithm::is_convex::IsConvex;
+    pub use crate::algorithm::line_merge::LineMerge;
+    pub use crate::algorithm::line_substring::{
+        EuclideanLineSubstring, HaversineLineSubstring, LineSubstring,
+    };
+    pub use crate::algorithm::make_valid::MakeValid;
     pub use crate::algorithm::map_coords::MapCoords;
+    pub use crate::algorithm::minimum_bounding_circle::MinimumBoundingCircle;
+    pub use crate::algorithm::minimum_rotated_rect::MinimumRotatedRect;
+    pub use crate::algorithm::node::Node;
     pub use crate::algorithm::orient::Orient;
+    pub use crate::algorithm::pole_of_inaccessibility::PoleOfInaccessibility;
     #[cfg(feature = "use-proj")]
     pub use crate::algorithm::proj::Proj;
+    pub use crate::algorithm::projection::Project;
+    pub use crate::algorithm::relate::Relate;
+    pub use crate::algorithm::rhumb::{
+        RhumbBearing, RhumbDestination, RhumbDistance, RhumbIntermediate,
+    };
     pub use crate::algorithm::rotate::{Rotate, RotatePoint};
+    pub use crate::algorithm::scale::Scale;
     pub use crate::algorithm::simplify::Simplify;
     pub use crate::algorithm::simplifyvw::SimplifyVW;
+    pub use crate::algorithm::skew::Skew;
+    pub use crate::algorithm::spherical_contains::{
+        SphericalContains, SphericalCoordinatePosition,
+    };
+    pub use crate::algorithm::split::Split;
     pub use crate::algorithm::translate::Translate;
+    pub use crate::algorithm::triangulate_polygon::TriangulatePolygon;
+    pub use crate::algorithm::validation::Validation;
     pub use crate::algorithm::vincenty_distance::VincentyDistance;
     pub use crate::algorithm::vincenty_length::VincentyLength;
+    pub use crate::algorithm::voronoi::Voronoi;
 }
 
 /// A common numeric trait used for geo algorithms.